@@ -1,12 +1,18 @@
 use core::hash::{Hash, Hasher};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+use rs_internal_state::{DWords, GenericStateHasher, Sha160BitsState, Sha256BitsState, ShaExtensions};
+use rs_n_bit_words::NBitWord;
 use rs_shield::{
-    Sha1Hasher, Sha224Hasher, Sha256Hasher, Sha384Hasher, Sha3_224Hasher, Sha3_256Hasher, Sha3_384Hasher,
-    Sha3_512Hasher, Sha512Hasher, Sha512_224Hasher, Sha512_256Hasher, Shake128Hasher, Shake256Hasher,
+    BatchBackend, HasherContext, Kt128, Sha1Hasher, Sha224Hasher, Sha256Batch, Sha256Hasher, Sha384Hasher,
+    Sha3_224Hasher, Sha3_256Hasher, Sha3_384Hasher, Sha3_512Hasher, Sha512Batch, Sha512Hasher, Sha512_224Hasher,
+    Sha512_256Hasher, Shake128Hasher, Shake256Hasher,
 };
 
 const FUNCTIONS_BENCH_COMPARISON: &str = "Compare different SHA functions execution time";
+const COMPRESSION_BENCH_COMPARISON: &str = "Compare scalar and SHA extensions block compression";
+const TREE_BENCH_COMPARISON: &str = "Compare serial and parallel KT128 on 16 MiB";
+const BATCH_BENCH_COMPARISON: &str = "Compare SHA-256 and SHA-512 batch backends on 1024 Merkle-tree nodes";
 
 fn compare_sha_impls(c: &mut Criterion) {
     let mut b_group = c.benchmark_group(FUNCTIONS_BENCH_COMPARISON);
@@ -118,5 +124,136 @@ fn compare_sha_impls(c: &mut Criterion) {
     b_group.finish();
 }
 
-criterion_group!(benches, compare_sha_impls,);
+fn compare_batch_backends(c: &mut Criterion) {
+    let mut b_group = c.benchmark_group(BATCH_BENCH_COMPARISON);
+    let nodes: Vec<[u8; 64]> = (0..1024).map(|i| [i as u8; 64]).collect();
+    let messages: Vec<&[u8]> = nodes.iter().map(|node| &node[..]).collect();
+    let mut digests = vec![Default::default(); nodes.len()];
+
+    b_group.bench_function("Sha256Hasher", |b| {
+        b.iter(|| {
+            for node in black_box(&nodes) {
+                let mut sha256hasher = Sha256Hasher::default();
+                sha256hasher.write(node);
+                let _ = HasherContext::finish(&mut sha256hasher);
+            }
+        })
+    });
+
+    for backend in [BatchBackend::Portable, BatchBackend::Sse41, BatchBackend::Avx2] {
+        if let Some(batch) = Sha256Batch::with_backend(backend) {
+            b_group.bench_function(format!("Sha256Batch {backend:?}"), |b| {
+                b.iter(|| batch.digest(black_box(&messages), &mut digests))
+            });
+        }
+    }
+
+    let mut digests = vec![Default::default(); nodes.len()];
+    b_group.bench_function("Sha512Hasher", |b| {
+        b.iter(|| {
+            for node in black_box(&nodes) {
+                let mut sha512hasher = Sha512Hasher::default();
+                sha512hasher.write(node);
+                black_box(HasherContext::finish(&mut sha512hasher));
+            }
+        })
+    });
+
+    for backend in [BatchBackend::Portable, BatchBackend::Sse41, BatchBackend::Avx2] {
+        if let Some(batch) = Sha512Batch::with_backend(backend) {
+            b_group.bench_function(format!("Sha512Batch {backend:?}"), |b| {
+                b.iter(|| batch.digest(black_box(&messages), &mut digests))
+            });
+        }
+    }
+
+    b_group.finish();
+}
+
+fn compare_compression_impls(c: &mut Criterion) {
+    let mut b_group = c.benchmark_group(COMPRESSION_BENCH_COMPARISON);
+    let block = [0xA5u8; 64];
+
+    b_group.bench_function("SHA-1 scalar", |b| {
+        b.iter(|| {
+            let [a, b, c, d, e] = black_box([0x67452301u32, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0]);
+            let mut state = Sha160BitsState(
+                NBitWord::from(a),
+                NBitWord::from(b),
+                NBitWord::from(c),
+                NBitWord::from(d),
+                NBitWord::from(e),
+                DWords::<u32>::from(black_box(&block)),
+            );
+            state.block_00_15();
+            state.block_16_31();
+            state.block_32_47();
+            state.block_48_63();
+            state.block_64_79();
+            state
+        })
+    });
+
+    b_group.bench_function("SHA-256 scalar", |b| {
+        b.iter(|| {
+            let [a, b, c, d, e, f, g, h] = black_box([0x6A09E667u32; 8]);
+            let mut state = Sha256BitsState(
+                NBitWord::from(a),
+                NBitWord::from(b),
+                NBitWord::from(c),
+                NBitWord::from(d),
+                NBitWord::from(e),
+                NBitWord::from(f),
+                NBitWord::from(g),
+                NBitWord::from(h),
+                DWords::<u32>::from(black_box(&block)),
+            );
+            state.block_00_15();
+            state.block_16_31();
+            state.block_32_47();
+            state.block_48_63();
+            state
+        })
+    });
+
+    if let Some(sha_extensions) = ShaExtensions::detect() {
+        b_group.bench_function("SHA-1 SHA extensions", |b| {
+            let mut state = [0x67452301u32, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+            b.iter(|| sha_extensions.sha1_compress(black_box(&mut state), black_box(&block)))
+        });
+
+        b_group.bench_function("SHA-256 SHA extensions", |b| {
+            let mut state = [0x6A09E667u32; 8];
+            b.iter(|| sha_extensions.sha256_compress(black_box(&mut state), black_box(&block)))
+        });
+    }
+
+    b_group.finish();
+}
+
+fn compare_tree_hashing(c: &mut Criterion) {
+    let mut b_group = c.benchmark_group(TREE_BENCH_COMPARISON);
+    b_group.sample_size(10);
+    let artifact: Vec<u8> = (0..16 << 20).map(|i| i as u8).collect();
+
+    b_group.bench_function("KT128 write", |b| {
+        b.iter(|| {
+            let mut kt128 = Kt128::<32>::default();
+            kt128.write(black_box(&artifact));
+            HasherContext::finish(&mut kt128)
+        })
+    });
+
+    b_group.bench_function("KT128 write_parallel", |b| {
+        b.iter(|| {
+            let mut kt128 = Kt128::<32>::default();
+            kt128.write_parallel(black_box(&artifact));
+            HasherContext::finish(&mut kt128)
+        })
+    });
+
+    b_group.finish();
+}
+
+criterion_group!(benches, compare_sha_impls, compare_compression_impls, compare_batch_backends, compare_tree_hashing,);
 criterion_main!(benches);
@@ -0,0 +1,224 @@
+//! Verification of checksum manifests, in the GNU `<digest>  <file>` and BSD `<NAME> (<file>) = <digest>` formats,
+//! as `sha256sum -c` does, names holding a newline or a backslash being escaped behind a leading backslash.
+
+use super::{digests::DigestAlgorithm, encoding, open_input, stream_input, CliError};
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    process::ExitCode,
+};
+
+/// Options tuning what `check` reports and what it counts as a failure.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CheckOptions {
+    /// Do not print a line for each file that matches
+    pub quiet: bool,
+    /// Print nothing, and only report through the exit code
+    pub status: bool,
+    /// Skip the files that do not exist, instead of failing on them
+    pub ignore_missing: bool,
+    /// Fail on improperly formatted lines, instead of only warning about them
+    pub strict: bool,
+}
+
+/// A line of a manifest, once parsed.
+struct Entry {
+    algorithm: &'static DigestAlgorithm,
+    path: String,
+    digest: Vec<u8>,
+}
+
+/// Counts of the problems met along the manifests.
+#[derive(Debug, Default)]
+struct Tally {
+    verified: usize,
+    mismatched: usize,
+    unreadable: usize,
+    malformed: usize,
+}
+
+/// Verifies the files listed in each of the `manifests`, whose GNU lines are hashed with `algorithm`.
+///
+/// Every file is reported as OK or FAILED, unless the options silence them, and the exit code is a failure if any file
+/// did not match or could not be read.
+pub fn run(
+    manifests: &[&str],
+    algorithm: &'static DigestAlgorithm,
+    options: CheckOptions,
+) -> Result<ExitCode, CliError> {
+    let mut stdout = io::stdout().lock();
+    let mut failed = false;
+
+    for &manifest in manifests {
+        let lines = match open_input(manifest) {
+            Ok(reader) => BufReader::new(reader).lines(),
+            Err(error) => {
+                eprintln!("rs_shield_cli: dgst: {}: {}", manifest, error);
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut tally = Tally::default();
+        let mut entries = 0;
+        for (number, line) in lines.enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => {
+                    eprintln!("rs_shield_cli: dgst: {}: {}", manifest, error);
+                    failed = true;
+                    break;
+                }
+            };
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some(entry) = parse_line(line, algorithm) else {
+                tally.malformed += 1;
+                if !options.status && options.strict {
+                    eprintln!("rs_shield_cli: dgst: {}: {}: improperly formatted checksum line", manifest, number + 1);
+                }
+                continue;
+            };
+            entries += 1;
+            verify(&entry, options, &mut tally, &mut stdout)?;
+        }
+
+        if entries == 0 {
+            eprintln!("rs_shield_cli: dgst: {}: no properly formatted checksum lines found", manifest);
+            failed = true;
+            continue;
+        }
+        if options.ignore_missing && tally.verified == 0 && tally.unreadable == 0 {
+            if !options.status {
+                eprintln!("rs_shield_cli: dgst: {}: no file was verified", manifest);
+            }
+            failed = true;
+        }
+        if !options.status {
+            warn(tally.malformed, "line is improperly formatted", "lines are improperly formatted");
+            warn(tally.unreadable, "listed file could not be read", "listed files could not be read");
+            warn(tally.mismatched, "computed checksum did NOT match", "computed checksums did NOT match");
+        }
+        failed |= tally.mismatched > 0 || tally.unreadable > 0 || (options.strict && tally.malformed > 0);
+    }
+    stdout.flush()?;
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Hashes the file `entry` lists, and reports whether it matches.
+fn verify(entry: &Entry, options: CheckOptions, tally: &mut Tally, stdout: &mut impl Write) -> Result<(), CliError> {
+    let mut hash = entry.algorithm.start();
+    if let Err(error) = stream_input(&entry.path, |bytes| hash.update(bytes)) {
+        if options.ignore_missing && error.kind() == io::ErrorKind::NotFound {
+            return Ok(());
+        }
+        tally.unreadable += 1;
+        if !options.status {
+            eprintln!("rs_shield_cli: dgst: {}: {}", entry.path, error);
+            report(stdout, &entry.path, "FAILED open or read")?;
+        }
+        return Ok(());
+    }
+
+    let mut digest = vec![0u8; entry.digest.len()];
+    hash.finalize_into(&mut digest);
+    tally.verified += 1;
+    if digest == entry.digest {
+        if !options.status && !options.quiet {
+            report(stdout, &entry.path, "OK")?;
+        }
+    } else {
+        tally.mismatched += 1;
+        if !options.status {
+            report(stdout, &entry.path, "FAILED")?;
+        }
+    }
+    Ok(())
+}
+
+/// Prints the `status` of the file at `path`, whose name is escaped and the line marked with a leading backslash if it
+/// holds a newline or a backslash, as in the manifests `dgst` prints.
+fn report(stdout: &mut impl Write, path: &str, status: &str) -> io::Result<()> {
+    match encoding::escape(path) {
+        Some(escaped) => writeln!(stdout, "\\{}: {}", escaped, status),
+        None => writeln!(stdout, "{}: {}", path, status),
+    }
+}
+
+fn warn(count: usize, one: &str, many: &str) {
+    match count {
+        0 => (),
+        1 => eprintln!("rs_shield_cli: WARNING: 1 {}", one),
+        count => eprintln!("rs_shield_cli: WARNING: {} {}", count, many),
+    }
+}
+
+/// Parses a manifest line in either format, the GNU ones being hashed with `algorithm`.
+fn parse_line(line: &str, algorithm: &'static DigestAlgorithm) -> Option<Entry> {
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(line) => (true, line),
+        None => (false, line),
+    };
+    let entry = parse_bsd_line(line).or_else(|| parse_gnu_line(line, algorithm))?;
+
+    // Names holding a newline or a backslash are escaped, and the line marked with a leading backslash
+    let path = if escaped {
+        encoding::unescape(&entry.path)?
+    } else {
+        entry.path
+    };
+    Some(Entry {
+        path,
+        ..entry
+    })
+}
+
+/// Parses `<NAME> (<file>) = <digest>`, the space before the parenthesis and the one before the equal sign being
+/// optional, as `dgst` and OpenSSL print them.
+fn parse_bsd_line(line: &str) -> Option<Entry> {
+    let (name, rest) = line.split_once('(')?;
+    let algorithm = DigestAlgorithm::find_by_name(name.trim_end())?;
+    let (path, digest) = rest.rsplit_once(") = ").or_else(|| rest.rsplit_once(")= "))?;
+    let digest = checked_digest(algorithm, digest)?;
+
+    Some(Entry {
+        algorithm,
+        path: path.to_string(),
+        digest,
+    })
+}
+
+/// Parses `<digest>  <file>`, or `<digest> *<file>` for files hashed in binary mode.
+fn parse_gnu_line(line: &str, algorithm: &'static DigestAlgorithm) -> Option<Entry> {
+    let (digest, rest) = line.split_once(' ')?;
+    let path = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*'))?;
+    let digest = checked_digest(algorithm, digest)?;
+    if path.is_empty() {
+        return None;
+    }
+
+    Some(Entry {
+        algorithm,
+        path: path.to_string(),
+        digest,
+    })
+}
+
+/// Decodes `digest`, as long as its length fits `algorithm`.
+fn checked_digest(algorithm: &DigestAlgorithm, digest: &str) -> Option<Vec<u8>> {
+    let digest = encoding::from_hex(digest)?;
+    let function = algorithm.function();
+    let fits = if function.is_xof {
+        !digest.is_empty()
+    } else {
+        digest.len() == function.output_len
+    };
+    fits.then_some(digest)
+}
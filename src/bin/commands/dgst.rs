@@ -0,0 +1,215 @@
+//! `dgst`: hashes files, or the standard input, with any of the supported hash functions.
+
+use super::{
+    check::{self, CheckOptions},
+    digests::{DigestAlgorithm, DIGEST_ALGORITHMS},
+    output::{self, Format, Layout},
+    parse_len, stream_input, CliError, STDIN_PATH,
+};
+use rs_shield::DynHasher;
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage: rs_shield_cli dgst [-<digest>] [-xoflen <bytes>] [-hex | -base64 | -binary] [-r | -tag] [--] [file...]
+       rs_shield_cli dgst -c [-<digest>] [--quiet] [--status] [--ignore-missing] [--strict] [--] [manifest...]
+
+Hashes each file, or the standard input when none is given or for `-`, with SHA-256 unless told otherwise.
+With -c, verifies instead the files listed in each manifest, in the format printed by -r or -tag, or by sha256sum
+and its `--tag` option. The digest option only picks the function of the lines that do not name one.
+
+Options:
+  -<digest>         Hash function to use, among those listed below
+  -xoflen <bytes>   Length of the digests of an extendable-output function
+  -hex              Print the digests in hexadecimal, which is the default
+  -base64           Print the digests in base64
+  -binary           Write the raw digests only, without the file names
+  -r                Print `<digest> *<file>` lines, as sha256sum does
+  -tag              Print `<NAME> (<file>) = <digest>` lines, as sha256sum --tag does, with its names
+  -c, --check       Verify the files listed in the manifests
+  --quiet           Do not print OK for the files that match
+  --status          Print nothing, and only report through the exit code
+  --ignore-missing  Skip the listed files that do not exist
+  --strict          Fail on improperly formatted manifest lines
+  -h, -help         Print this help
+
+Digests (extendable-output functions are marked with their default length):";
+
+/// Function `dgst` hashes with unless another is chosen
+const DEFAULT_DIGEST: &str = "sha256";
+
+/// Options shared by the subcommands printing one digest per input.
+pub struct DigestOptions<'a> {
+    /// Length of the digests
+    pub output_len: usize,
+    pub format: Format,
+    pub layout: Layout,
+    /// Inputs to digest, `-` standing for the standard input
+    pub paths: Vec<&'a str>,
+}
+
+impl<'a> DigestOptions<'a> {
+    /// Starts with digests of `output_len` bytes, printed in hexadecimal as OpenSSL does.
+    pub fn new(output_len: usize) -> Self {
+        Self {
+            output_len,
+            format: Format::Hex,
+            layout: Layout::OpenSsl,
+            paths: Vec::new(),
+        }
+    }
+
+    /// Applies `option` if it is an output format or layout, and returns whether it was.
+    pub fn apply(&mut self, option: &str) -> bool {
+        if let Some(format) = Format::from_option(option) {
+            self.format = format;
+        } else if let Some(layout) = Layout::from_option(option) {
+            self.layout = layout;
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Reads the standard input when no path was given.
+    pub fn default_to_stdin(&mut self) {
+        if self.paths.is_empty() {
+            self.paths.push(STDIN_PATH);
+        }
+    }
+}
+
+/// The parsed command line of `dgst`.
+struct Options<'a> {
+    algorithm: &'static DigestAlgorithm,
+    digest: DigestOptions<'a>,
+    check: Option<CheckOptions>,
+}
+
+impl<'a> Options<'a> {
+    /// Parses `args`, or returns `None` if the help was asked for.
+    fn parse(args: &'a [String]) -> Result<Option<Self>, CliError> {
+        let mut algorithm = None;
+        let mut xoflen = None;
+        let mut digest = DigestOptions::new(0);
+        let mut check = false;
+        let mut check_options = CheckOptions::default();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if digest.apply(arg) {
+                continue;
+            }
+            match arg.as_str() {
+                "--" => digest.paths.extend(args.by_ref().map(String::as_str)),
+                "-h" | "-help" | "--help" => return Ok(None),
+                "-c" | "-check" | "--check" => check = true,
+                "--quiet" => check_options.quiet = true,
+                "--status" => check_options.status = true,
+                "--ignore-missing" => check_options.ignore_missing = true,
+                "--strict" => check_options.strict = true,
+                "-xoflen" => xoflen = Some(parse_len("-xoflen", args.next()).map_err(|reason| usage(&reason))?),
+                option if option.len() > 1 && option.starts_with('-') => {
+                    if algorithm.is_some() {
+                        return Err(usage("only one digest can be chosen"));
+                    }
+                    algorithm = Some(
+                        DigestAlgorithm::find(&option[1..])
+                            .ok_or_else(|| usage(&format!("unknown option or digest: {}", option)))?,
+                    );
+                }
+                path => digest.paths.push(path),
+            }
+        }
+
+        let algorithm = algorithm.unwrap_or_else(|| DigestAlgorithm::find(DEFAULT_DIGEST).unwrap());
+        let function = algorithm.function();
+        digest.output_len = match xoflen {
+            Some(_) if !function.is_xof => {
+                return Err(usage(&format!(
+                    "-xoflen only applies to extendable-output functions, not {}",
+                    algorithm.name
+                )))
+            }
+            Some(len) => len,
+            None => function.output_len,
+        };
+        if !check && check_options != CheckOptions::default() {
+            return Err(usage("--quiet, --status, --ignore-missing and --strict only apply to -c"));
+        }
+        digest.default_to_stdin();
+
+        Ok(Some(Self {
+            algorithm,
+            digest,
+            check: check.then_some(check_options),
+        }))
+    }
+}
+
+/// Runs `dgst` with the arguments following the subcommand name.
+///
+/// Inputs that cannot be read are reported and skipped, and make the exit code a failure.
+pub fn run(args: &[String]) -> Result<ExitCode, CliError> {
+    let Some(options) = Options::parse(args)? else {
+        println!("{}", help());
+        return Ok(ExitCode::SUCCESS);
+    };
+    if let Some(check_options) = options.check {
+        return check::run(&options.digest.paths, options.algorithm, check_options);
+    }
+
+    let algorithm = options.algorithm;
+    digest_inputs("dgst", (algorithm.name, algorithm.tag), &options.digest, || algorithm.start())
+}
+
+/// Prints the digests of the inputs `options` lists, computed by the function printed under `names`, as OpenSSL and as
+/// coreutils `-tag` lines name it, which `start` begins.
+///
+/// Inputs that cannot be read are reported and skipped, and make the exit code a failure.
+pub fn digest_inputs(
+    command: &str,
+    names: (&str, &str),
+    options: &DigestOptions,
+    start: impl Fn() -> Box<dyn DynHasher>,
+) -> Result<ExitCode, CliError> {
+    let mut stdout = io::stdout().lock();
+    let mut digest = vec![0u8; options.output_len];
+    let mut failed = false;
+    for &path in &options.paths {
+        let mut hash = start();
+        if let Err(error) = stream_input(path, |bytes| hash.update(bytes)) {
+            eprintln!("rs_shield_cli: {}: {}: {}", command, path, error);
+            failed = true;
+            continue;
+        }
+        hash.finalize_into(&mut digest);
+        output::print_digest(&mut stdout, options.format, options.layout, names, path, &digest)?;
+    }
+    stdout.flush()?;
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn help() -> String {
+    let mut help = String::from(USAGE);
+    for algorithm in DIGEST_ALGORITHMS {
+        let function = algorithm.function();
+        if function.is_xof {
+            help.push_str(&format!("\n  -{:<17} {} ({} bytes)", algorithm.flag, algorithm.name, function.output_len));
+        } else {
+            help.push_str(&format!("\n  -{:<17} {}", algorithm.flag, algorithm.name));
+        }
+    }
+    help
+}
+
+fn usage(reason: &str) -> CliError {
+    CliError::Usage(format!("dgst: {}\nTry `rs_shield_cli dgst -help`.", reason))
+}
@@ -0,0 +1,127 @@
+//! The hash functions the subcommands can pick by name, which are those of the registry of `rs_shield` under the
+//! options and names OpenSSL and coreutils give them.
+
+use core::hash::Hasher;
+use rs_shield::{DynHasher, HashFunction};
+
+/// A hash function of the registry, as named on the command line.
+pub struct DigestAlgorithm {
+    /// Name the digests are printed under
+    pub name: &'static str,
+    /// Name of the `-tag` lines, which is the one coreutils prints for the functions it has
+    pub tag: &'static str,
+    /// Option selecting the function, without its leading dash
+    pub flag: &'static str,
+    /// Name of the function in the registry
+    function: &'static str,
+}
+
+impl DigestAlgorithm {
+    /// Returns the function selected by the option `flag`, given without its leading dash.
+    pub fn find(flag: &str) -> Option<&'static Self> {
+        DIGEST_ALGORITHMS.iter().find(|algorithm| algorithm.flag.eq_ignore_ascii_case(flag))
+    }
+
+    /// Returns the function called `name` by the registry or by one of its aliases, which covers the names printed, the
+    /// options and the names coreutils and OpenSSL give them, such as `SHA256`, `BLAKE2b` or `SHA512/256`.
+    pub fn find_by_name(name: &str) -> Option<&'static Self> {
+        let function = HashFunction::find(name)?;
+        DIGEST_ALGORITHMS.iter().find(|algorithm| algorithm.function == function.name)
+    }
+
+    /// Returns the function of the registry.
+    pub fn function(&self) -> &'static HashFunction {
+        HashFunction::find(self.function).expect("the registry has every function of the CLI")
+    }
+
+    /// Starts a new hash computation.
+    pub fn start(&self) -> Box<dyn DynHasher> {
+        self.function().new_hasher()
+    }
+}
+
+macro_rules! digest_algorithm {
+    ($name:literal, $tag:literal, $flag:literal, $function:literal) => {
+        DigestAlgorithm {
+            name: $name,
+            tag: $tag,
+            flag: $flag,
+            function: $function,
+        }
+    };
+}
+
+/// Every hash function of the registry that digests a plain byte stream.
+pub const DIGEST_ALGORITHMS: &[DigestAlgorithm] = &[
+    digest_algorithm!("SHA1", "SHA1", "sha1", "SHA-1"),
+    digest_algorithm!("SHA2-224", "SHA224", "sha224", "SHA-224"),
+    digest_algorithm!("SHA2-256", "SHA256", "sha256", "SHA-256"),
+    digest_algorithm!("SHA2-384", "SHA384", "sha384", "SHA-384"),
+    digest_algorithm!("SHA2-512", "SHA512", "sha512", "SHA-512"),
+    digest_algorithm!("SHA2-512/224", "SHA2-512/224", "sha512-224", "SHA-512/224"),
+    digest_algorithm!("SHA2-512/256", "SHA2-512/256", "sha512-256", "SHA-512/256"),
+    digest_algorithm!("SHA3-224", "SHA3-224", "sha3-224", "SHA3-224"),
+    digest_algorithm!("SHA3-256", "SHA3-256", "sha3-256", "SHA3-256"),
+    digest_algorithm!("SHA3-384", "SHA3-384", "sha3-384", "SHA3-384"),
+    digest_algorithm!("SHA3-512", "SHA3-512", "sha3-512", "SHA3-512"),
+    digest_algorithm!("BLAKE2B-256", "BLAKE2b-256", "blake2b256", "BLAKE2b-256"),
+    digest_algorithm!("BLAKE2B-512", "BLAKE2b", "blake2b512", "BLAKE2b-512"),
+    digest_algorithm!("SHAKE-128", "SHAKE-128", "shake128", "SHAKE128"),
+    digest_algorithm!("SHAKE-256", "SHAKE-256", "shake256", "SHAKE256"),
+    digest_algorithm!("CSHAKE-128", "CSHAKE-128", "cshake128", "cSHAKE128"),
+    digest_algorithm!("CSHAKE-256", "CSHAKE-256", "cshake256", "cSHAKE256"),
+    digest_algorithm!("TURBOSHAKE-128", "TURBOSHAKE-128", "turboshake128", "TurboSHAKE128"),
+    digest_algorithm!("TURBOSHAKE-256", "TURBOSHAKE-256", "turboshake256", "TurboSHAKE256"),
+    digest_algorithm!("KT128", "KT128", "kt128", "KT128"),
+    digest_algorithm!("KT256", "KT256", "kt256", "KT256"),
+    digest_algorithm!("PARALLELHASH-128", "PARALLELHASH-128", "parallelhash128", "ParallelHash128"),
+    digest_algorithm!("PARALLELHASH-256", "PARALLELHASH-256", "parallelhash256", "ParallelHash256"),
+];
+
+/// A MAC keyed once for all, driven as a hash function whose digests `finish_into` fills.
+#[derive(Clone)]
+pub struct Keyed<M, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> {
+    initial: M,
+    mac: M,
+    finish_into: fn(&mut M, &mut [u8]),
+}
+
+impl<M: Clone, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> Keyed<M, OUTPUT_LEN, BLOCK_LEN> {
+    pub fn new(mac: M, finish_into: fn(&mut M, &mut [u8])) -> Self {
+        Self {
+            initial: mac.clone(),
+            mac,
+            finish_into,
+        }
+    }
+}
+
+impl<M, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> DynHasher for Keyed<M, OUTPUT_LEN, BLOCK_LEN>
+where
+    M: Clone + Hasher + 'static,
+{
+    fn update(&mut self, bytes: &[u8]) {
+        self.mac.write(bytes);
+    }
+
+    fn finalize_into(&mut self, output: &mut [u8]) {
+        (self.finish_into)(&mut self.mac, output);
+        self.reset();
+    }
+
+    fn output_len(&self) -> usize {
+        OUTPUT_LEN
+    }
+
+    fn block_len(&self) -> usize {
+        BLOCK_LEN
+    }
+
+    fn reset(&mut self) {
+        self.mac.clone_from(&self.initial);
+    }
+
+    fn box_clone(&self) -> Box<dyn DynHasher> {
+        Box::new(self.clone())
+    }
+}
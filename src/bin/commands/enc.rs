@@ -0,0 +1,233 @@
+//! `enc`: derives the key and IV of symmetric encryption as OpenSSL does, for its `Salted__` file format.
+//!
+//! Files start with `Salted__` and the 8 bytes of the salt, from which the key and IV are derived with the password,
+//! through EVP_BytesToKey or PBKDF2. Encrypting and decrypting the rest of the file await block and stream ciphers:
+//! `rs_aes` and `rs_chacha20` do not implement theirs yet, so that only `-P`, which prints the key and IV, is
+//! available for now.
+
+use super::{digests::DigestAlgorithm, encoding, hmacs::HmacAlgorithm, open_input, parse_len, read_bytes, CliError};
+use rs_shield::{DrbgError, EntropySource, OsEntropy, Zeroize};
+use std::{
+    io::{self, Read},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage: rs_shield_cli enc -<cipher> [-e | -d] (-k <password> | -pass <bytes>) [-S <salt> | -nosalt] [-md <digest>]
+                         [-pbkdf2] [-iter <count>] [-a] [-in <file>] -P
+
+Derives the key and IV of a cipher from a password as `openssl enc` does, for files in its `Salted__` format.
+Encryption and decryption are not available yet, as the ciphers are still in progress: only -P is.
+
+Bytes are given as hex:<digits>, str:<text>, file:<path> or env:<variable>. Files and environment variables keep
+the password out of the process listings, unlike -k.
+
+Options:
+  -<cipher>       Cipher, among aes-128-cbc, aes-192-cbc, aes-256-cbc and chacha20-poly1305
+  -e              Encrypt, which is the default
+  -d              Decrypt, the salt being read from the input
+  -k <password>   Password
+  -pass <bytes>   Password
+  -S <salt>       Salt, of 8 bytes in hexadecimal, random by default
+  -nosalt         Derive the key and IV without salt
+  -md <digest>    Hash function of the key derivation, SHA-256 by default
+  -pbkdf2         Derive the key and IV with PBKDF2, rather than with the deprecated EVP_BytesToKey
+  -iter <count>   Iteration count of PBKDF2, of 10000 by default, which implies -pbkdf2
+  -a, -base64     Read and write the files armored in base64
+  -in <file>      Input, the standard input by default
+  -P              Print the salt, key and IV, and stop
+  -h, -help       Print this help";
+
+/// Magic the salted files start with, followed by the salt
+const MAGIC: &[u8; 8] = b"Salted__";
+/// Length of the salt
+const SALT_LEN: usize = 8;
+/// Iteration count of PBKDF2 unless chosen otherwise, as in OpenSSL
+const DEFAULT_ITERATIONS: u32 = 10000;
+/// Hash function of the key derivation unless chosen otherwise, as in OpenSSL
+const DEFAULT_DIGEST: &str = "sha256";
+
+/// A cipher `enc` derives keys for.
+struct Cipher {
+    /// Option selecting the cipher, without its leading dash
+    flag: &'static str,
+    key_len: usize,
+    iv_len: usize,
+}
+
+const CIPHERS: &[Cipher] = &[
+    Cipher {
+        flag: "aes-128-cbc",
+        key_len: 16,
+        iv_len: 16,
+    },
+    Cipher {
+        flag: "aes-192-cbc",
+        key_len: 24,
+        iv_len: 16,
+    },
+    Cipher {
+        flag: "aes-256-cbc",
+        key_len: 32,
+        iv_len: 16,
+    },
+    Cipher {
+        flag: "chacha20-poly1305",
+        key_len: 32,
+        iv_len: 12,
+    },
+];
+
+/// Where the salt comes from.
+enum Salt {
+    Random,
+    Given(Vec<u8>),
+    None,
+}
+
+/// Runs `enc` with the arguments following the subcommand name.
+pub fn run(args: &[String]) -> Result<ExitCode, CliError> {
+    let mut cipher = None;
+    let mut decrypt = false;
+    let mut password = None;
+    let mut salt = Salt::Random;
+    let mut digest = DEFAULT_DIGEST.to_string();
+    let mut pbkdf2 = false;
+    let mut iterations = None;
+    let mut base64 = false;
+    let mut input = None;
+    let mut print_only = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "-help" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(ExitCode::SUCCESS);
+            }
+            "-e" => decrypt = false,
+            "-d" => decrypt = true,
+            "-k" => password = Some(args.next().ok_or_else(|| usage("-k needs a password"))?.as_bytes().to_vec()),
+            "-pass" => password = Some(read_bytes("-pass", args.next()).map_err(|reason| usage(&reason))?),
+            "-S" => {
+                let hex = args.next().ok_or_else(|| usage("-S needs a salt"))?;
+                match encoding::from_hex(hex) {
+                    Some(bytes) if bytes.len() == SALT_LEN => salt = Salt::Given(bytes),
+                    _ => return Err(usage(&format!("-S needs {} bytes in hexadecimal", SALT_LEN))),
+                }
+            }
+            "-nosalt" => salt = Salt::None,
+            "-md" => digest = args.next().ok_or_else(|| usage("-md needs a digest"))?.clone(),
+            "-pbkdf2" => pbkdf2 = true,
+            "-iter" => {
+                let count = parse_len("-iter", args.next()).map_err(|reason| usage(&reason))?;
+                iterations = Some(u32::try_from(count).map_err(|_| usage(&format!("invalid -iter count: {}", count)))?);
+                pbkdf2 = true;
+            }
+            "-a" | "-base64" => base64 = true,
+            "-in" => input = Some(args.next().ok_or_else(|| usage("-in needs a file"))?.as_str()),
+            "-P" => print_only = true,
+            option => {
+                let chosen =
+                    option.strip_prefix('-').and_then(|flag| CIPHERS.iter().find(|cipher| cipher.flag == flag));
+                match chosen {
+                    Some(_) if cipher.is_some() => return Err(usage("only one cipher can be chosen")),
+                    Some(chosen) => cipher = Some(chosen),
+                    None => return Err(usage(&format!("unknown option or cipher: {}", option))),
+                }
+            }
+        }
+    }
+
+    let cipher = cipher.ok_or_else(|| usage("a cipher is needed"))?;
+    if !print_only {
+        return Err(usage(&format!("{} encryption and decryption are not available yet, only -P is", cipher.flag)));
+    }
+    let mut password = password.ok_or_else(|| usage("-k or -pass is needed"))?;
+
+    // Decryption takes the salt from the header of the input, as long as it is not given
+    let salt = match salt {
+        Salt::Random if decrypt => Some(read_salt(input.unwrap_or(super::STDIN_PATH), base64)?),
+        Salt::Random => Some(random_salt().map_err(|error| usage(&format!("cannot draw a salt: {}", error)))?),
+        Salt::Given(salt) => Some(salt),
+        Salt::None => None,
+    };
+
+    let mut key_iv = vec![0u8; cipher.key_len + cipher.iv_len];
+    let salt_bytes = salt.as_deref().unwrap_or_default();
+    if pbkdf2 {
+        let hmac = HmacAlgorithm::find(&digest).ok_or_else(|| usage(&format!("unknown -md digest: {}", digest)))?;
+        let iterations = iterations.unwrap_or(DEFAULT_ITERATIONS);
+        hmac.pbkdf2(&password, salt_bytes, iterations, &mut key_iv).map_err(|error| usage(&error.to_string()))?;
+    } else {
+        let algorithm = DigestAlgorithm::find_by_name(&digest)
+            .filter(|algorithm| !algorithm.function().is_xof)
+            .ok_or_else(|| usage(&format!("unknown -md digest: {}", digest)))?;
+        eprintln!("rs_shield_cli: enc: warning: EVP_BytesToKey is deprecated, -pbkdf2 is better");
+        evp_bytes_to_key(algorithm, &password, salt_bytes, &mut key_iv);
+    }
+    password.zeroize();
+
+    if let Some(salt) = &salt {
+        println!("salt={}", encoding::hex(salt).to_ascii_uppercase());
+    }
+    let (key, iv) = key_iv.split_at(cipher.key_len);
+    println!("key={}", encoding::hex(key).to_ascii_uppercase());
+    println!("iv ={}", encoding::hex(iv).to_ascii_uppercase());
+    key_iv.zeroize();
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Fills `key_iv` with EVP_BytesToKey of OpenSSL, iterated once: the concatenation of `D_i = H(D_(i-1) || password ||
+/// salt)`.
+fn evp_bytes_to_key(algorithm: &DigestAlgorithm, password: &[u8], salt: &[u8], key_iv: &mut [u8]) {
+    let mut hash = algorithm.start();
+    let mut block = vec![0u8; hash.output_len()];
+    for (i, chunk) in key_iv.chunks_mut(block.len()).enumerate() {
+        if i > 0 {
+            hash.update(&block);
+        }
+        hash.update(password);
+        hash.update(salt);
+        hash.finalize_into(&mut block);
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+    block.zeroize();
+}
+
+/// Reads the salt from the `Salted__` header at the start of the input at `path`.
+fn read_salt(path: &str, base64: bool) -> Result<Vec<u8>, CliError> {
+    let read_error = |error: io::Error| CliError::Failed(format!("enc: {}: {}", path, error));
+    let mut reader = open_input(path).map_err(read_error)?;
+
+    let mut header = Vec::with_capacity(MAGIC.len() + SALT_LEN);
+    if base64 {
+        // The 16 bytes of the header take the first 24 base64 digits, the line breaks aside
+        let mut digits = String::new();
+        let mut byte = [0u8; 1];
+        while digits.len() < 24 && reader.read(&mut byte).map_err(read_error)? == 1 {
+            if !byte[0].is_ascii_whitespace() {
+                digits.push(byte[0] as char);
+            }
+        }
+        header = encoding::from_base64(&digits).unwrap_or_default();
+    } else {
+        reader.take((MAGIC.len() + SALT_LEN) as u64).read_to_end(&mut header).map_err(read_error)?;
+    }
+
+    match header.strip_prefix(MAGIC.as_slice()) {
+        Some(salt) if salt.len() >= SALT_LEN => Ok(salt[..SALT_LEN].to_vec()),
+        _ => Err(CliError::Failed(format!("enc: {}: bad magic number, not a salted file", path))),
+    }
+}
+
+fn random_salt() -> Result<Vec<u8>, DrbgError> {
+    let mut salt = vec![0u8; SALT_LEN];
+    OsEntropy.fill_entropy(&mut salt)?;
+    Ok(salt)
+}
+
+fn usage(reason: &str) -> CliError {
+    CliError::Usage(format!("enc: {}\nTry `rs_shield_cli enc -help`.", reason))
+}
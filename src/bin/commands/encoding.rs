@@ -0,0 +1,94 @@
+//! Text encodings digests and file names are printed in.
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes `bytes` in lowercase hexadecimal.
+pub fn hex(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        encoded.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        encoded.push(HEX_DIGITS[(byte & 0x0F) as usize] as char);
+    }
+    encoded
+}
+
+/// Decodes `hex`, in lowercase or uppercase, or returns `None` if it is not hexadecimal.
+pub fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    hex.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| {
+            let digits = core::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(digits, 16).ok()
+        })
+        .collect()
+}
+
+/// Encodes `bytes` in base64, with the standard alphabet and padding of RFC 4648.
+pub fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for group in bytes.chunks(3) {
+        let word = group.iter().enumerate().fold(0u32, |word, (i, &byte)| word | (byte as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= group.len() {
+                encoded.push(BASE64_ALPHABET[(word >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes `base64`, with the standard alphabet and padding of RFC 4648, or returns `None` if it is not base64.
+pub fn from_base64(base64: &str) -> Option<Vec<u8>> {
+    let base64 = base64.as_bytes();
+    if !base64.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(base64.len() / 4 * 3);
+    for (i, group) in base64.chunks_exact(4).enumerate() {
+        let is_last = i == base64.len() / 4 - 1;
+        let padding = group.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && !is_last) {
+            return None;
+        }
+
+        let mut word = 0u32;
+        for &c in &group[..4 - padding] {
+            let value = BASE64_ALPHABET.iter().position(|&symbol| symbol == c)?;
+            word = word << 6 | value as u32;
+        }
+        word <<= 6 * padding;
+        decoded.extend_from_slice(&word.to_be_bytes()[1..4 - padding]);
+    }
+    Some(decoded)
+}
+
+/// Escapes the backslashes, newlines and carriage returns of `path`, as GNU checksum lines do, or returns `None` if
+/// it holds none of them.
+pub fn escape(path: &str) -> Option<String> {
+    path.contains(['\\', '\n', '\r']).then(|| path.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r"))
+}
+
+/// Reverts `escape`, or returns `None` if `path` holds an unknown escape sequence.
+pub fn unescape(path: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(path.len());
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        unescaped.push(match c {
+            '\\' => match chars.next()? {
+                '\\' => '\\',
+                'n' => '\n',
+                'r' => '\r',
+                _ => return None,
+            },
+            c => c,
+        });
+    }
+    Some(unescaped)
+}
@@ -0,0 +1,81 @@
+//! The hash functions HMAC, and the key derivation functions built on it, can be keyed with.
+
+use super::digests::{DigestAlgorithm, Keyed};
+use rs_shield::{
+    DynHasher, HasherContext, Hkdf, HkdfError, Hmac, Pbkdf2, Pbkdf2Error, Sha1State, Sha224State, Sha256State,
+    Sha384State, Sha3_224State, Sha3_256State, Sha3_384State, Sha3_512State, Sha512State, Sha512_224State,
+    Sha512_256State,
+};
+
+/// HKDF filling its last argument from the salt, the input key material and the information
+type HkdfFn = fn(&[u8], &[u8], &[u8], &mut [u8]) -> Result<(), HkdfError>;
+/// PBKDF2 filling its last argument from the password, the salt and the iteration count
+type Pbkdf2Fn = fn(&[u8], &[u8], u32, &mut [u8]) -> Result<(), Pbkdf2Error>;
+
+/// A hash function HMAC can be built on, with the constructions taking it.
+pub struct HmacAlgorithm {
+    /// Name of the underlying hash function, as `dgst` prints it
+    pub name: &'static str,
+    /// Length of the HMAC outputs, which is that of the hash function
+    pub output_len: usize,
+    new_hmac: fn(&[u8]) -> Box<dyn DynHasher>,
+    hkdf: HkdfFn,
+    pbkdf2: Pbkdf2Fn,
+}
+
+impl HmacAlgorithm {
+    /// Returns the function named `name`, as spelled for `dgst`, such as `sha256` or `SHA2-256`.
+    pub fn find(name: &str) -> Option<&'static Self> {
+        let digest = DigestAlgorithm::find_by_name(name)?;
+        HMAC_ALGORITHMS.iter().find(|algorithm| algorithm.name == digest.name)
+    }
+
+    /// Starts a new HMAC computation keyed with `key`.
+    pub fn start(&self, key: &[u8]) -> Box<dyn DynHasher> {
+        (self.new_hmac)(key)
+    }
+
+    /// Fills the whole of `okm` with the HKDF of the input key material `ikm`, `salt` and `info`.
+    pub fn hkdf(&self, salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), HkdfError> {
+        (self.hkdf)(salt, ikm, info, okm)
+    }
+
+    /// Fills the whole of `okm` with the PBKDF2 of `password` and `salt`, over `rounds` iterations.
+    pub fn pbkdf2(&self, password: &[u8], salt: &[u8], rounds: u32, okm: &mut [u8]) -> Result<(), Pbkdf2Error> {
+        (self.pbkdf2)(password, salt, rounds, okm)
+    }
+}
+
+macro_rules! hmac_algorithm {
+    ($name:literal, $state:ty, $output_len:literal, $block_len:literal) => {
+        HmacAlgorithm {
+            name: $name,
+            output_len: $output_len,
+            new_hmac: |key| {
+                Box::new(Keyed::<_, $output_len, $block_len>::new(
+                    Hmac::<$state, $output_len>::new(key),
+                    |hmac, output| output.copy_from_slice(HasherContext::finish(hmac).as_ref()),
+                ))
+            },
+            hkdf: |salt, ikm, info, okm| Hkdf::<$state, $output_len>::new(salt, ikm).expand(info, okm),
+            pbkdf2: |password, salt, rounds, okm| {
+                Pbkdf2::<$state, $output_len>::new(password).derive(salt, rounds, okm)
+            },
+        }
+    };
+}
+
+/// Every hash function re-exported by `rs_shield` that HMAC can be built on.
+pub const HMAC_ALGORITHMS: &[HmacAlgorithm] = &[
+    hmac_algorithm!("SHA1", Sha1State, 20, 64),
+    hmac_algorithm!("SHA2-224", Sha224State, 28, 64),
+    hmac_algorithm!("SHA2-256", Sha256State, 32, 64),
+    hmac_algorithm!("SHA2-384", Sha384State, 48, 128),
+    hmac_algorithm!("SHA2-512", Sha512State, 64, 128),
+    hmac_algorithm!("SHA2-512/224", Sha512_224State, 28, 128),
+    hmac_algorithm!("SHA2-512/256", Sha512_256State, 32, 128),
+    hmac_algorithm!("SHA3-224", Sha3_224State, 28, 144),
+    hmac_algorithm!("SHA3-256", Sha3_256State, 32, 136),
+    hmac_algorithm!("SHA3-384", Sha3_384State, 48, 104),
+    hmac_algorithm!("SHA3-512", Sha3_512State, 64, 72),
+];
@@ -0,0 +1,207 @@
+//! `kdf`: derives a key with HKDF, PBKDF2 or KMAC.
+
+use super::{
+    hmacs::{HmacAlgorithm, HMAC_ALGORITHMS},
+    output::Format,
+    parse_len, read_bytes, CliError,
+};
+use core::hash::Hasher;
+use rs_shield::{Kmac128, Kmac256, Zeroize};
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage: rs_shield_cli kdf -hkdf <digest> -key <bytes> [-salt <bytes>] [-info <bytes>] [-length <bytes>] [format]
+       rs_shield_cli kdf -pbkdf2 <digest> -key <bytes> -salt <bytes> -iter <count> [-length <bytes>] [format]
+       rs_shield_cli kdf (-kmac128 | -kmac256) -key <bytes> [-context <bytes>] [-label <bytes>] [-length <bytes>]
+                         [format]
+
+Derives a key from the input key material or password given as -key, with HKDF (RFC 5869), PBKDF2 (RFC 8018) or
+KMAC as a key derivation function (SP 800-108).
+
+Bytes are given as hex:<digits>, str:<text>, file:<path> or env:<variable>. Files and environment variables keep
+secrets out of the process listings.
+
+Options:
+  -hkdf <digest>      HKDF on the HMAC of the given hash function, among those listed below
+  -pbkdf2 <digest>    PBKDF2 on the HMAC of the given hash function, among those listed below
+  -kmac128, -kmac256  KMAC128 or KMAC256, keyed with -key, over -context and customized with -label
+  -key <bytes>        Input key material of HKDF, password of PBKDF2, or key of KMAC
+  -salt <bytes>       Salt of HKDF, empty by default, or of PBKDF2
+  -info <bytes>       Context and application information of HKDF, empty by default
+  -iter <count>       Iteration count of PBKDF2
+  -context <bytes>    Context of KMAC, empty by default
+  -label <bytes>      Label of KMAC, empty by default
+  -length <bytes>     Length of the derived key, of the hash output length for HKDF and PBKDF2, of 32 bytes for
+                      KMAC128 and of 64 bytes for KMAC256 by default
+  -hex                Print the key in hexadecimal, which is the default
+  -base64             Print the key in base64
+  -binary             Write the raw key
+  -h, -help           Print this help
+
+Digests:";
+
+/// The key derivation function chosen on the command line.
+#[derive(Clone, Copy)]
+enum KdfAlgorithm {
+    Hkdf(&'static HmacAlgorithm),
+    Pbkdf2(&'static HmacAlgorithm),
+    Kmac128,
+    Kmac256,
+}
+
+/// The inputs of the key derivation functions, which are wiped on drop.
+#[derive(Default)]
+struct Inputs {
+    key: Option<Vec<u8>>,
+    salt: Option<Vec<u8>>,
+    info: Option<Vec<u8>>,
+    context: Option<Vec<u8>>,
+    label: Option<Vec<u8>>,
+}
+
+impl Drop for Inputs {
+    fn drop(&mut self) {
+        for input in
+            [&mut self.key, &mut self.salt, &mut self.info, &mut self.context, &mut self.label].into_iter().flatten()
+        {
+            input.zeroize();
+        }
+    }
+}
+
+/// Runs `kdf` with the arguments following the subcommand name.
+pub fn run(args: &[String]) -> Result<ExitCode, CliError> {
+    let mut algorithm = None;
+    let mut inputs = Inputs::default();
+    let mut rounds = None;
+    let mut length = None;
+    let mut format = Format::Hex;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if let Some(chosen) = Format::from_option(arg) {
+            format = chosen;
+            continue;
+        }
+        let chosen = match arg.as_str() {
+            "-h" | "-help" | "--help" => {
+                println!("{}", help());
+                return Ok(ExitCode::SUCCESS);
+            }
+            "-hkdf" => Some(KdfAlgorithm::Hkdf(hmac_algorithm("-hkdf", args.next())?)),
+            "-pbkdf2" => Some(KdfAlgorithm::Pbkdf2(hmac_algorithm("-pbkdf2", args.next())?)),
+            "-kmac128" => Some(KdfAlgorithm::Kmac128),
+            "-kmac256" => Some(KdfAlgorithm::Kmac256),
+            option @ ("-key" | "-salt" | "-info" | "-context" | "-label") => {
+                let input = match option {
+                    "-key" => &mut inputs.key,
+                    "-salt" => &mut inputs.salt,
+                    "-info" => &mut inputs.info,
+                    "-context" => &mut inputs.context,
+                    _ => &mut inputs.label,
+                };
+                *input = Some(read_bytes(option, args.next()).map_err(|reason| usage(&reason))?);
+                None
+            }
+            "-iter" => {
+                let count = parse_len("-iter", args.next()).map_err(|reason| usage(&reason))?;
+                rounds = Some(u32::try_from(count).map_err(|_| usage(&format!("invalid -iter count: {}", count)))?);
+                None
+            }
+            "-length" => {
+                length = Some(parse_len("-length", args.next()).map_err(|reason| usage(&reason))?);
+                None
+            }
+            option => return Err(usage(&format!("unknown option: {}", option))),
+        };
+        if chosen.is_some() {
+            if algorithm.is_some() {
+                return Err(usage("only one key derivation function can be chosen"));
+            }
+            algorithm = chosen;
+        }
+    }
+
+    let algorithm = algorithm.ok_or_else(|| usage("-hkdf, -pbkdf2, -kmac128 or -kmac256 is needed"))?;
+    let key = inputs.key.as_deref().ok_or_else(|| usage("-key is needed"))?;
+    let given = [
+        ("-salt", inputs.salt.is_some()),
+        ("-info", inputs.info.is_some()),
+        ("-iter", rounds.is_some()),
+        ("-context", inputs.context.is_some()),
+        ("-label", inputs.label.is_some()),
+    ];
+    let allowed: &[&str] = match algorithm {
+        KdfAlgorithm::Hkdf(_) => &["-salt", "-info"],
+        KdfAlgorithm::Pbkdf2(_) => &["-salt", "-iter"],
+        KdfAlgorithm::Kmac128 | KdfAlgorithm::Kmac256 => &["-context", "-label"],
+    };
+    if let Some((option, _)) = given.iter().find(|(option, given)| *given && !allowed.contains(option)) {
+        return Err(usage(&format!("{} does not apply to this key derivation function", option)));
+    }
+
+    let mut okm = match algorithm {
+        KdfAlgorithm::Hkdf(hmac) => {
+            let mut okm = vec![0u8; length.unwrap_or(hmac.output_len)];
+            let salt = inputs.salt.as_deref().unwrap_or_default();
+            let info = inputs.info.as_deref().unwrap_or_default();
+            hmac.hkdf(salt, key, info, &mut okm).map_err(|error| usage(&error.to_string()))?;
+            okm
+        }
+        KdfAlgorithm::Pbkdf2(hmac) => {
+            let salt = inputs.salt.as_deref().ok_or_else(|| usage("-salt is needed by PBKDF2"))?;
+            let rounds = rounds.ok_or_else(|| usage("-iter is needed by PBKDF2"))?;
+            let mut okm = vec![0u8; length.unwrap_or(hmac.output_len)];
+            hmac.pbkdf2(key, salt, rounds, &mut okm).map_err(|error| usage(&error.to_string()))?;
+            okm
+        }
+        KdfAlgorithm::Kmac128 | KdfAlgorithm::Kmac256 => {
+            // SP 800-108 derives KMAC(K, X = context, L, S = label)
+            let context = inputs.context.as_deref().unwrap_or_default();
+            let label = inputs.label.as_deref().unwrap_or_default();
+            if let KdfAlgorithm::Kmac128 = algorithm {
+                let mut okm = vec![0u8; length.unwrap_or(32)];
+                let mut kmac = Kmac128::<32>::new(key, label);
+                kmac.write(context);
+                kmac.finish_into(&mut okm);
+                okm
+            } else {
+                let mut okm = vec![0u8; length.unwrap_or(64)];
+                let mut kmac = Kmac256::<64>::new(key, label);
+                kmac.write(context);
+                kmac.finish_into(&mut okm);
+                okm
+            }
+        }
+    };
+
+    let mut stdout = io::stdout().lock();
+    let result = match format.encode(&okm) {
+        Some(encoded) => writeln!(stdout, "{}", encoded),
+        None => stdout.write_all(&okm),
+    };
+    okm.zeroize();
+    result.and_then(|_| stdout.flush())?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn hmac_algorithm(option: &str, name: Option<&String>) -> Result<&'static HmacAlgorithm, CliError> {
+    let name = name.ok_or_else(|| usage(&format!("{} needs a digest", option)))?;
+    HmacAlgorithm::find(name).ok_or_else(|| usage(&format!("unknown {} digest: {}", option, name)))
+}
+
+fn help() -> String {
+    let mut help = String::from(USAGE);
+    for algorithm in HMAC_ALGORITHMS {
+        help.push_str(&format!("\n  {}", algorithm.name));
+    }
+    help
+}
+
+fn usage(reason: &str) -> CliError {
+    CliError::Usage(format!("kdf: {}\nTry `rs_shield_cli kdf -help`.", reason))
+}
@@ -0,0 +1,143 @@
+//! `mac`: authenticates files, or the standard input, with HMAC or KMAC.
+
+use super::{
+    dgst::{digest_inputs, DigestOptions},
+    digests::Keyed,
+    hmacs::{HmacAlgorithm, HMAC_ALGORITHMS},
+    parse_len, read_bytes, CliError,
+};
+use rs_shield::{Kmac128, Kmac256, Zeroize};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: rs_shield_cli mac (-hmac <digest> | -kmac128 | -kmac256) -key <bytes> [-customization <bytes>]
+                         [-length <bytes>] [-hex | -base64 | -binary] [-r | -tag] [--] [file...]
+
+Authenticates each file, or the standard input when none is given or for `-`, with HMAC or KMAC.
+
+Bytes are given as hex:<digits>, str:<text>, file:<path> or env:<variable>. Files and environment variables keep
+the key out of the process listings.
+
+Options:
+  -hmac <digest>            HMAC on the given hash function, among those listed below
+  -kmac128, -kmac256        KMAC128 or KMAC256
+  -key <bytes>              Key
+  -customization <bytes>    Customization string of KMAC, empty by default
+  -length <bytes>           Length of the KMAC outputs, of 32 bytes for KMAC128 and 64 for KMAC256 by default
+  -hex                      Print the MACs in hexadecimal, which is the default
+  -base64                   Print the MACs in base64
+  -binary                   Write the raw MACs only, without the file names
+  -r                        Print `<mac> *<file>` lines
+  -tag                      Print `<NAME> (<file>) = <mac>` lines
+  -h, -help                 Print this help
+
+HMAC digests:";
+
+/// The MAC function chosen on the command line.
+#[derive(Clone, Copy)]
+enum MacAlgorithm {
+    Hmac(&'static HmacAlgorithm),
+    Kmac128,
+    Kmac256,
+}
+
+/// Runs `mac` with the arguments following the subcommand name.
+///
+/// Inputs that cannot be read are reported and skipped, and make the exit code a failure.
+pub fn run(args: &[String]) -> Result<ExitCode, CliError> {
+    let mut algorithm = None;
+    let mut key = None;
+    let mut customization = Vec::new();
+    let mut length = None;
+    let mut options = DigestOptions::new(0);
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if options.apply(arg) {
+            continue;
+        }
+        let chosen = match arg.as_str() {
+            "--" => {
+                options.paths.extend(args.by_ref().map(String::as_str));
+                None
+            }
+            "-h" | "-help" | "--help" => {
+                println!("{}", help());
+                return Ok(ExitCode::SUCCESS);
+            }
+            "-hmac" => {
+                let name = args.next().ok_or_else(|| usage("-hmac needs a digest"))?;
+                let hmac = HmacAlgorithm::find(name).ok_or_else(|| usage(&format!("unknown HMAC digest: {}", name)))?;
+                Some(MacAlgorithm::Hmac(hmac))
+            }
+            "-kmac128" => Some(MacAlgorithm::Kmac128),
+            "-kmac256" => Some(MacAlgorithm::Kmac256),
+            "-key" => {
+                key = Some(read_bytes("-key", args.next()).map_err(|reason| usage(&reason))?);
+                None
+            }
+            "-customization" => {
+                customization = read_bytes("-customization", args.next()).map_err(|reason| usage(&reason))?;
+                None
+            }
+            "-length" => {
+                length = Some(parse_len("-length", args.next()).map_err(|reason| usage(&reason))?);
+                None
+            }
+            option if option.len() > 1 && option.starts_with('-') => {
+                return Err(usage(&format!("unknown option: {}", option)))
+            }
+            path => {
+                options.paths.push(path);
+                None
+            }
+        };
+        if chosen.is_some() {
+            if algorithm.is_some() {
+                return Err(usage("only one MAC function can be chosen"));
+            }
+            algorithm = chosen;
+        }
+    }
+
+    let algorithm = algorithm.ok_or_else(|| usage("-hmac, -kmac128 or -kmac256 is needed"))?;
+    let mut key = key.ok_or_else(|| usage("-key is needed"))?;
+    options.default_to_stdin();
+
+    let result = match algorithm {
+        MacAlgorithm::Hmac(_) if length.is_some() || !customization.is_empty() => {
+            Err(usage("-length and -customization only apply to KMAC"))
+        }
+        MacAlgorithm::Hmac(hmac) => {
+            options.output_len = hmac.output_len;
+            let name = format!("HMAC-{}", hmac.name);
+            digest_inputs("mac", (&name, &name), &options, || hmac.start(&key))
+        }
+        MacAlgorithm::Kmac128 => {
+            options.output_len = length.unwrap_or(32);
+            digest_inputs("mac", ("KMAC128", "KMAC128"), &options, || {
+                Box::new(Keyed::<_, 32, 168>::new(Kmac128::<32>::new(&key, &customization), Kmac128::finish_into))
+            })
+        }
+        MacAlgorithm::Kmac256 => {
+            options.output_len = length.unwrap_or(64);
+            digest_inputs("mac", ("KMAC256", "KMAC256"), &options, || {
+                Box::new(Keyed::<_, 64, 136>::new(Kmac256::<64>::new(&key, &customization), Kmac256::finish_into))
+            })
+        }
+    };
+    key.zeroize();
+    result
+}
+
+fn help() -> String {
+    let mut help = String::from(USAGE);
+    for algorithm in HMAC_ALGORITHMS {
+        help.push_str(&format!("\n  {}", algorithm.name));
+    }
+    help
+}
+
+fn usage(reason: &str) -> CliError {
+    CliError::Usage(format!("mac: {}\nTry `rs_shield_cli mac -help`.", reason))
+}
@@ -0,0 +1,107 @@
+//! Subcommands of `rs_shield_cli`, and the plumbing they share.
+
+pub mod check;
+pub mod dgst;
+pub mod digests;
+pub mod enc;
+pub mod encoding;
+pub mod hmacs;
+pub mod kdf;
+pub mod mac;
+pub mod output;
+pub mod scan;
+pub mod selftest;
+pub mod speed;
+
+use std::{
+    env, fmt,
+    fs::{self, File},
+    io::{self, Read},
+};
+
+/// Size of the buffer inputs are streamed through
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Path standing for the standard input on the command line
+pub const STDIN_PATH: &str = "-";
+
+/// Errors that abort a subcommand as a whole.
+#[derive(Debug)]
+pub enum CliError {
+    /// The command line could not be understood, for the given reason
+    Usage(String),
+    /// Writing the results failed
+    Output(io::Error),
+    /// The subcommand could not complete, for the given reason
+    Failed(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(reason) => f.write_str(reason),
+            CliError::Output(error) => write!(f, "cannot write the output: {}", error),
+            CliError::Failed(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(error: io::Error) -> Self {
+        CliError::Output(error)
+    }
+}
+
+/// Reads the bytes `source` designates on the command line, after `option`: `hex:<digits>`, `str:<text>`,
+/// `file:<path>`, whose content is taken as is, or `env:<variable>`.
+///
+/// Files and environment variables keep secrets out of the process listings, unlike the two other sources.
+pub fn read_bytes(option: &str, source: Option<&String>) -> Result<Vec<u8>, String> {
+    let source = source.ok_or_else(|| format!("{} needs hex:, str:, file: or env: bytes", option))?;
+    let (kind, value) = source.split_once(':').unwrap_or(("", source));
+    match kind {
+        "hex" => encoding::from_hex(value).ok_or_else(|| format!("{}: invalid hexadecimal", option)),
+        "str" => Ok(value.as_bytes().to_vec()),
+        "file" => fs::read(value).map_err(|error| format!("{}: {}: {}", option, value, error)),
+        "env" => env::var_os(value)
+            .map(|value| value.into_encoded_bytes())
+            .ok_or_else(|| format!("{}: environment variable {} is not set", option, value)),
+        _ => Err(format!("{} needs hex:, str:, file: or env: bytes, not {}", option, source)),
+    }
+}
+
+/// Parses the length following `option` on the command line, which must be greater than zero.
+pub fn parse_len(option: &str, value: Option<&String>) -> Result<usize, String> {
+    let value = value.ok_or_else(|| format!("{} needs a length", option))?;
+    match value.parse() {
+        Ok(len) if len > 0 => Ok(len),
+        _ => Err(format!("invalid {} length: {}", option, value)),
+    }
+}
+
+/// Opens the file at `path`, or the standard input for `-`.
+pub fn open_input(path: &str) -> io::Result<Box<dyn Read>> {
+    if path == STDIN_PATH {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(File::open(path)?))
+    }
+}
+
+/// Feeds `consume` with the content of the file at `path`, or of the standard input for `-`, one buffer at a time.
+pub fn stream_input(path: &str, consume: impl FnMut(&[u8])) -> io::Result<()> {
+    stream(open_input(path)?, consume)
+}
+
+/// Feeds `consume` with the content of `reader`, one buffer at a time.
+pub fn stream(mut reader: impl Read, mut consume: impl FnMut(&[u8])) -> io::Result<()> {
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => consume(&buffer[..read]),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! How the subcommands print the digests, MACs and keys they compute.
+
+use super::{encoding, STDIN_PATH};
+use std::io::{self, Write};
+
+/// Encoding of the printed bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Hex,
+    Base64,
+    Binary,
+}
+
+impl Format {
+    /// Returns the format the command-line `option` selects, if it is one of `-hex`, `-base64` and `-binary`.
+    pub fn from_option(option: &str) -> Option<Self> {
+        match option {
+            "-hex" => Some(Format::Hex),
+            "-base64" => Some(Format::Base64),
+            "-binary" => Some(Format::Binary),
+            _ => None,
+        }
+    }
+
+    /// Encodes `bytes`, or returns `None` for the raw binary format.
+    pub fn encode(&self, bytes: &[u8]) -> Option<String> {
+        match self {
+            Format::Hex => Some(encoding::hex(bytes)),
+            Format::Base64 => Some(encoding::base64(bytes)),
+            Format::Binary => None,
+        }
+    }
+}
+
+/// How the lines naming the inputs are laid out.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Layout {
+    /// `<NAME>(<file>) = <digest>`
+    OpenSsl,
+    /// `<digest> *<file>`
+    Gnu,
+    /// `<TAG> (<file>) = <digest>`, with the name coreutils gives the function
+    Bsd,
+}
+
+impl Layout {
+    /// Returns the layout the command-line `option` selects, if it is one of `-r` and `-tag`.
+    pub fn from_option(option: &str) -> Option<Self> {
+        match option {
+            "-r" => Some(Layout::Gnu),
+            "-tag" | "--tag" => Some(Layout::Bsd),
+            _ => None,
+        }
+    }
+}
+
+/// Prints the `digest` of the input at `path`, computed by the function printed as `name`, or as `tag` in the BSD
+/// layout.
+///
+/// As coreutils does, the GNU and BSD lines of the names holding a newline or a backslash escape them, and are marked
+/// with a leading backslash.
+pub fn print_digest(
+    output: &mut impl Write,
+    format: Format,
+    layout: Layout,
+    (name, tag): (&str, &str),
+    path: &str,
+    digest: &[u8],
+) -> io::Result<()> {
+    let Some(encoded) = format.encode(digest) else {
+        return output.write_all(digest);
+    };
+
+    let escaped = encoding::escape(path);
+    let (marker, escaped_path) = match &escaped {
+        Some(escaped) => ("\\", escaped.as_str()),
+        None => ("", path),
+    };
+    match layout {
+        Layout::OpenSsl if path == STDIN_PATH => writeln!(output, "{}(stdin) = {}", name, encoded),
+        Layout::OpenSsl => writeln!(output, "{}({}) = {}", name, path, encoded),
+        Layout::Gnu => writeln!(output, "{}{} *{}", marker, encoded, escaped_path),
+        Layout::Bsd => writeln!(output, "{}{} ({}) = {}", marker, tag, escaped_path, encoded),
+    }
+}
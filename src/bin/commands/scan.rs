@@ -0,0 +1,439 @@
+//! `scan`: records a baseline of the files of a directory tree, and reports how the tree changed since, as AIDE does.
+//!
+//! The baseline is a text file whose first line names the hash function, followed by a `# exclude <glob>` line per
+//! exclude glob, which later runs apply as well, and by one line per regular file: `<digest> <size> <mode> <path>`,
+//! with the mode in octal and the path relative to the scanned directory. Names holding a newline or a backslash are
+//! escaped as `dgst -c` reads them. Symbolic links are neither followed nor recorded.
+
+use super::{digests::DigestAlgorithm, encoding, stream, CliError};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    process::ExitCode,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+const USAGE: &str = "\
+Usage: rs_shield_cli scan init [-<digest>] [-exclude <glob>]... [-db <file>] <directory>
+       rs_shield_cli scan check -db <file> [-exclude <glob>]... <directory>
+
+init records the size, mode and digest of every regular file under the directory in a baseline database, written
+to the standard output unless -db is given. check compares the directory with that baseline, and lists the files
+added, removed and changed since, failing if there is any. The globs excluded by init are excluded by check too.
+
+Options:
+  -<digest>        Hash function, among those of `dgst`, SHA-256 by default
+  -exclude <glob>  Skip the paths matching the glob, where `*` and `?` stop at slashes and `**` does not; a glob
+                   without a slash is matched against the names of the files and directories at any depth
+  -db <file>       Baseline database
+  -h, -help        Print this help";
+
+/// First words of the baselines, followed by the option of their hash function
+const HEADER: &str = "# rs_shield_cli scan v1";
+/// First words of the lines recording the exclude globs of the baselines
+const EXCLUDE: &str = "# exclude ";
+/// Function `scan` hashes with unless another is chosen
+const DEFAULT_DIGEST: &str = "sha256";
+
+/// What the baseline records of a file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct FileRecord {
+    size: u64,
+    mode: u32,
+    digest: Vec<u8>,
+}
+
+/// What a baseline holds besides its records.
+struct Baseline {
+    algorithm: &'static DigestAlgorithm,
+    excludes: Vec<String>,
+    records: BTreeMap<String, FileRecord>,
+}
+
+/// The parsed command line of `scan`.
+struct Options<'a> {
+    check: bool,
+    algorithm: Option<&'static DigestAlgorithm>,
+    excludes: Vec<&'a str>,
+    database: Option<&'a str>,
+    root: &'a str,
+}
+
+impl<'a> Options<'a> {
+    /// Parses `args`, or returns `None` if the help was asked for.
+    fn parse(args: &'a [String]) -> Result<Option<Self>, CliError> {
+        let mut args = args.iter();
+        let check = match args.next().map(String::as_str) {
+            Some("init") => false,
+            Some("check") => true,
+            Some("-h" | "-help" | "--help") => return Ok(None),
+            Some(action) => return Err(usage(&format!("unknown action: {}", action))),
+            None => return Err(usage("init or check is needed")),
+        };
+
+        let mut algorithm = None;
+        let mut excludes = Vec::new();
+        let mut database = None;
+        let mut root = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "-help" | "--help" => return Ok(None),
+                "-exclude" => excludes.push(args.next().ok_or_else(|| usage("-exclude needs a glob"))?.as_str()),
+                "-db" => database = Some(args.next().ok_or_else(|| usage("-db needs a file"))?.as_str()),
+                option if option.len() > 1 && option.starts_with('-') => {
+                    let chosen = DigestAlgorithm::find(&option[1..])
+                        .ok_or_else(|| usage(&format!("unknown option or digest: {}", option)))?;
+                    if algorithm.replace(chosen).is_some() {
+                        return Err(usage("only one digest can be chosen"));
+                    }
+                }
+                path if root.is_none() => root = Some(path),
+                _ => return Err(usage("only one directory can be scanned")),
+            }
+        }
+
+        if check && database.is_none() {
+            return Err(usage("check needs the baseline given with -db"));
+        }
+        if check && algorithm.is_some() {
+            return Err(usage("check hashes with the function of the baseline"));
+        }
+
+        Ok(Some(Self {
+            check,
+            algorithm,
+            excludes,
+            database,
+            root: root.ok_or_else(|| usage("a directory is needed"))?,
+        }))
+    }
+}
+
+/// Runs `scan` with the arguments following the subcommand name.
+pub fn run(args: &[String]) -> Result<ExitCode, CliError> {
+    let Some(options) = Options::parse(args)? else {
+        println!("{}", USAGE);
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    let baseline = match (options.check, options.database) {
+        (true, Some(database)) => Some(read_baseline(database)?),
+        _ => None,
+    };
+    let mut excludes = options.excludes.clone();
+    let algorithm = match &baseline {
+        Some(baseline) => {
+            excludes.extend(baseline.excludes.iter().map(String::as_str));
+            baseline.algorithm
+        }
+        None => options.algorithm.unwrap_or_else(|| DigestAlgorithm::find(DEFAULT_DIGEST).unwrap()),
+    };
+
+    let mut failed = false;
+    let mut paths = Vec::new();
+    let root = Path::new(options.root);
+    if let Err(error) = walk(root, "", &excludes, &mut paths, &mut failed) {
+        return Err(failure(&format!("{}: {}", options.root, error)));
+    }
+    let records = hash_files(root, algorithm, paths, &mut failed);
+
+    let mut stdout = io::stdout().lock();
+    match baseline {
+        None => {
+            let mut output: Box<dyn Write> = match options.database {
+                Some(database) => {
+                    Box::new(File::create(database).map_err(|error| failure(&format!("{}: {}", database, error)))?)
+                }
+                None => Box::new(stdout),
+            };
+            write_baseline(&mut output, algorithm, &excludes, &records)?;
+            output.flush()?;
+        }
+        Some(baseline) => {
+            failed |= report_changes(&mut stdout, &baseline.records, &records)?;
+            stdout.flush()?;
+        }
+    }
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Collects the paths, relative to `root`, of the regular files under its `relative` directory that no glob excludes.
+///
+/// Entries that cannot be listed are reported and skipped, and set `failed`.
+fn walk(root: &Path, relative: &str, excludes: &[&str], paths: &mut Vec<String>, failed: &mut bool) -> io::Result<()> {
+    let directory = root.join(relative);
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(&directory)? {
+        match entry {
+            Ok(entry) => entries.push(entry),
+            Err(error) => {
+                eprintln!("rs_shield_cli: scan: {}: {}", directory.display(), error);
+                *failed = true;
+            }
+        }
+    }
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+            eprintln!("rs_shield_cli: scan: {}: skipping a name that is not UTF-8", entry.path().display());
+            *failed = true;
+            continue;
+        };
+        let path = if relative.is_empty() {
+            name.clone()
+        } else {
+            format!("{}/{}", relative, name)
+        };
+        if excludes.iter().any(|glob| is_excluded(glob, &path, &name)) {
+            continue;
+        }
+
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(error) => {
+                eprintln!("rs_shield_cli: scan: {}: {}", path, error);
+                *failed = true;
+                continue;
+            }
+        };
+        if file_type.is_dir() {
+            if let Err(error) = walk(root, &path, excludes, paths, failed) {
+                eprintln!("rs_shield_cli: scan: {}: {}", path, error);
+                *failed = true;
+            }
+        } else if file_type.is_file() {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Hashes the files at `paths`, relative to `root`, on all the available threads.
+///
+/// Files that cannot be read are reported and left out, and set `failed`.
+fn hash_files(
+    root: &Path,
+    algorithm: &DigestAlgorithm,
+    paths: Vec<String>,
+    failed: &mut bool,
+) -> BTreeMap<String, FileRecord> {
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get()).min(paths.len().max(1));
+    let next = AtomicUsize::new(0);
+
+    let results: Vec<(usize, io::Result<FileRecord>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(path) = paths.get(index) else {
+                            return results;
+                        };
+                        results.push((index, hash_file(&root.join(path), algorithm)));
+                    }
+                })
+            })
+            .collect();
+        workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect()
+    });
+
+    let mut records = BTreeMap::new();
+    for (index, result) in results {
+        match result {
+            Ok(record) => {
+                records.insert(paths[index].clone(), record);
+            }
+            Err(error) => {
+                eprintln!("rs_shield_cli: scan: {}: {}", paths[index], error);
+                *failed = true;
+            }
+        }
+    }
+    records
+}
+
+fn hash_file(path: &Path, algorithm: &DigestAlgorithm) -> io::Result<FileRecord> {
+    let file = File::open(path)?;
+    let metadata = file.metadata()?;
+
+    let mut hash = algorithm.start();
+    stream(file, |bytes| hash.update(bytes))?;
+    let mut digest = vec![0u8; hash.output_len()];
+    hash.finalize_into(&mut digest);
+
+    Ok(FileRecord {
+        size: metadata.len(),
+        mode: mode(&metadata),
+        digest,
+    })
+}
+
+#[cfg(unix)]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o7777
+}
+
+#[cfg(not(unix))]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    if metadata.permissions().readonly() {
+        0o444
+    } else {
+        0o644
+    }
+}
+
+/// Prints the files added, removed and changed between `baseline` and `records`, and returns whether there are any.
+fn report_changes(
+    output: &mut impl Write,
+    baseline: &BTreeMap<String, FileRecord>,
+    records: &BTreeMap<String, FileRecord>,
+) -> io::Result<bool> {
+    let (mut added, mut removed, mut changed) = (0, 0, 0);
+
+    for (path, record) in records {
+        match baseline.get(path) {
+            None => {
+                added += 1;
+                writeln!(output, "added: {}", path)?;
+            }
+            Some(recorded) if recorded != record => {
+                changed += 1;
+                let differences: Vec<&str> = [
+                    ("size", recorded.size != record.size),
+                    ("mode", recorded.mode != record.mode),
+                    ("digest", recorded.digest != record.digest),
+                ]
+                .iter()
+                .filter(|(_, differs)| *differs)
+                .map(|(field, _)| *field)
+                .collect();
+                writeln!(output, "changed: {} ({})", path, differences.join(", "))?;
+            }
+            Some(_) => (),
+        }
+    }
+    for path in baseline.keys().filter(|path| !records.contains_key(*path)) {
+        removed += 1;
+        writeln!(output, "removed: {}", path)?;
+    }
+
+    writeln!(output, "{} added, {} removed, {} changed", added, removed, changed)?;
+    Ok(added + removed + changed > 0)
+}
+
+fn write_baseline(
+    output: &mut impl Write,
+    algorithm: &DigestAlgorithm,
+    excludes: &[&str],
+    records: &BTreeMap<String, FileRecord>,
+) -> io::Result<()> {
+    writeln!(output, "{} {}", HEADER, algorithm.flag)?;
+    for glob in excludes {
+        writeln!(output, "{}{}", EXCLUDE, glob)?;
+    }
+    for (path, record) in records {
+        let digest = encoding::hex(&record.digest);
+        match encoding::escape(path) {
+            Some(escaped) => writeln!(output, "\\{} {} {:o} {}", digest, record.size, record.mode, escaped)?,
+            None => writeln!(output, "{} {} {:o} {}", digest, record.size, record.mode, path)?,
+        }
+    }
+    Ok(())
+}
+
+fn read_baseline(database: &str) -> Result<Baseline, CliError> {
+    let file = File::open(database).map_err(|error| failure(&format!("{}: {}", database, error)))?;
+    let mut lines = BufReader::new(file).lines();
+    let malformed = |number: usize| failure(&format!("{}: {}: malformed baseline line", database, number));
+    let read_error = |error: io::Error| failure(&format!("{}: {}", database, error));
+
+    let header = lines.next().transpose().map_err(read_error)?.unwrap_or_default();
+    let algorithm = header
+        .strip_prefix(HEADER)
+        .and_then(|flag| DigestAlgorithm::find(flag.trim()))
+        .ok_or_else(|| failure(&format!("{}: not a scan baseline", database)))?;
+
+    let mut excludes = Vec::new();
+    let mut records = BTreeMap::new();
+    for (number, line) in lines.enumerate() {
+        let line = line.map_err(read_error)?;
+        if let Some(glob) = line.strip_prefix(EXCLUDE) {
+            excludes.push(glob.to_string());
+            continue;
+        }
+        let (escaped, line) = match line.strip_prefix('\\') {
+            Some(line) => (true, line),
+            None => (false, line.as_str()),
+        };
+        let mut fields = line.splitn(4, ' ');
+        let (Some(digest), Some(size), Some(mode), Some(path)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(malformed(number + 2));
+        };
+
+        let record = FileRecord {
+            size: size.parse().map_err(|_| malformed(number + 2))?,
+            mode: u32::from_str_radix(mode, 8).map_err(|_| malformed(number + 2))?,
+            digest: encoding::from_hex(digest).ok_or_else(|| malformed(number + 2))?,
+        };
+        let path = if escaped {
+            encoding::unescape(path).ok_or_else(|| malformed(number + 2))?
+        } else {
+            path.to_string()
+        };
+        records.insert(path, record);
+    }
+    Ok(Baseline {
+        algorithm,
+        excludes,
+        records,
+    })
+}
+
+/// Returns whether `glob` excludes the file or directory at the relative `path`, named `name`.
+fn is_excluded(glob: &str, path: &str, name: &str) -> bool {
+    if glob.contains('/') {
+        glob_matches(glob.trim_start_matches('/').as_bytes(), path.as_bytes())
+    } else {
+        glob_matches(glob.as_bytes(), name.as_bytes())
+    }
+}
+
+/// Matches `text` against `glob`, whose `*` and `?` do not match slashes, unlike `**`.
+fn glob_matches(glob: &[u8], text: &[u8]) -> bool {
+    match glob {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            // `**/` also matches no directory at all
+            let rest_after_slash = rest.strip_prefix(b"/").unwrap_or(rest);
+            (0..=text.len()).any(|i| glob_matches(rest, &text[i..]))
+                || (rest.starts_with(b"/") && glob_matches(rest_after_slash, text))
+        }
+        [b'*', rest @ ..] => {
+            let segment_len = text.iter().position(|&c| c == b'/').unwrap_or(text.len());
+            (0..=segment_len).any(|i| glob_matches(rest, &text[i..]))
+        }
+        [b'?', rest @ ..] => matches!(text, [c, text @ ..] if *c != b'/' && glob_matches(rest, text)),
+        [c, rest @ ..] => matches!(text, [t, text @ ..] if t == c && glob_matches(rest, text)),
+    }
+}
+
+fn usage(reason: &str) -> CliError {
+    CliError::Usage(format!("scan: {}\nTry `rs_shield_cli scan -help`.", reason))
+}
+
+fn failure(reason: &str) -> CliError {
+    CliError::Failed(format!("scan: {}", reason))
+}
@@ -0,0 +1,72 @@
+//! `selftest`: runs the known-answer tests of the library, and reports the algorithms that fail them.
+
+use super::CliError;
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage: rs_shield_cli selftest [-quiet]
+
+Computes a sample of every hash function, MAC, key derivation function and DRBG, taken from the standard defining
+it, and compares it with the expected answer. Prints OK or FAILED for each algorithm, and fails if any answer is
+wrong. Built with the cli-power-on-self-test feature, rs_shield_cli runs these tests before every other command,
+and refuses to run it unless they all pass.
+
+Options:
+  -quiet     Print the algorithms that fail only
+  -h, -help  Print this help";
+
+/// Runs `selftest` with the arguments following the subcommand name.
+pub fn run(args: &[String]) -> Result<ExitCode, CliError> {
+    let mut quiet = false;
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "-help" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(ExitCode::SUCCESS);
+            }
+            "-q" | "-quiet" | "--quiet" => quiet = true,
+            other => return Err(usage(&format!("unknown option: {}", other))),
+        }
+    }
+
+    let report = rs_shield::self_test();
+    let mut stdout = io::stdout().lock();
+    for outcome in report.outcomes() {
+        if !outcome.passed {
+            writeln!(stdout, "{}: FAILED", outcome.algorithm)?;
+        } else if !quiet {
+            writeln!(stdout, "{}: OK", outcome.algorithm)?;
+        }
+    }
+    stdout.flush()?;
+
+    if report.passed() {
+        return Ok(ExitCode::SUCCESS);
+    }
+    eprintln!(
+        "rs_shield_cli: selftest: WARNING: {} of {} known-answer tests failed",
+        report.failures().count(),
+        report.outcomes().len()
+    );
+    Ok(ExitCode::FAILURE)
+}
+
+/// Runs the known-answer tests before a command, and refuses it unless they all pass.
+#[cfg(feature = "cli-power-on-self-test")]
+pub fn power_on() -> Result<(), CliError> {
+    let report = rs_shield::self_test();
+    if report.passed() {
+        return Ok(());
+    }
+    Err(CliError::Failed(format!(
+        "power-on self-test failed for {}, refusing to operate\nRun `rs_shield_cli selftest` for details.",
+        report.failures().map(|outcome| outcome.algorithm).collect::<Vec<_>>().join(", ")
+    )))
+}
+
+fn usage(reason: &str) -> CliError {
+    CliError::Usage(format!("selftest: {}\nTry `rs_shield_cli selftest -help`.", reason))
+}
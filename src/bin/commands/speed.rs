@@ -0,0 +1,170 @@
+//! `speed`: measures the throughput of the hash functions and MACs, in the manner of `openssl speed`.
+
+use super::{
+    digests::{DigestAlgorithm, Keyed, DIGEST_ALGORITHMS},
+    hmacs::HMAC_ALGORITHMS,
+    CliError,
+};
+use rs_shield::{DynHasher, Kmac128, Kmac256};
+use std::{
+    hint::black_box,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+const USAGE: &str = "\
+Usage: rs_shield_cli speed [-seconds <seconds>] [-bytes <size>] [algorithm...]
+
+Measures how many bytes per second each algorithm processes, hashing or authenticating blocks of 16 bytes to 16 KiB
+over and over for a fixed duration, and prints them in a table as `openssl speed` does. Every algorithm is measured
+unless some are named. Ciphers will join once they are implemented.
+
+Options:
+  -seconds <seconds>  Duration of each measure, of 3 seconds by default
+  -bytes <size>       Measure blocks of this size only
+  -h, -help           Print this help
+
+Algorithms:";
+
+/// Sizes of the blocks measured by default, as in OpenSSL
+const BLOCK_SIZES: &[usize] = &[16, 64, 256, 1024, 8192, 16384];
+/// Duration of each measure unless chosen otherwise
+const DEFAULT_SECONDS: f64 = 3.0;
+/// Count of operations between two readings of the clock
+const OPS_PER_READING: u64 = 16;
+/// Key of the MACs, whose value does not matter
+const KEY: [u8; 32] = [0x5A; 32];
+
+/// An algorithm `speed` can measure.
+struct SpeedAlgorithm {
+    name: String,
+    start: Box<dyn Fn() -> Box<dyn DynHasher>>,
+}
+
+/// Every algorithm `speed` can measure: the digests of `dgst`, then the MACs of `mac`.
+fn algorithms() -> Vec<SpeedAlgorithm> {
+    let digests = DIGEST_ALGORITHMS.iter().map(|algorithm| SpeedAlgorithm {
+        name: algorithm.flag.to_string(),
+        start: Box::new(|| algorithm.start()),
+    });
+    let hmacs = HMAC_ALGORITHMS.iter().map(|algorithm| SpeedAlgorithm {
+        name: format!(
+            "hmac-{}",
+            DigestAlgorithm::find_by_name(algorithm.name).map_or(algorithm.name, |digest| digest.flag)
+        ),
+        start: Box::new(|| algorithm.start(&KEY)),
+    });
+    let kmacs = [
+        SpeedAlgorithm {
+            name: "kmac128".to_string(),
+            start: Box::new(|| Box::new(Keyed::<_, 32, 168>::new(Kmac128::<32>::new(&KEY, b""), Kmac128::finish_into))),
+        },
+        SpeedAlgorithm {
+            name: "kmac256".to_string(),
+            start: Box::new(|| Box::new(Keyed::<_, 64, 136>::new(Kmac256::<64>::new(&KEY, b""), Kmac256::finish_into))),
+        },
+    ];
+
+    digests.chain(hmacs).chain(kmacs).collect()
+}
+
+/// Runs `speed` with the arguments following the subcommand name.
+pub fn run(args: &[String]) -> Result<ExitCode, CliError> {
+    let mut seconds = DEFAULT_SECONDS;
+    let mut block_sizes = BLOCK_SIZES.to_vec();
+    let mut names = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "-help" | "--help" => {
+                println!("{}", help());
+                return Ok(ExitCode::SUCCESS);
+            }
+            "-seconds" => {
+                let value = args.next().ok_or_else(|| usage("-seconds needs a duration"))?;
+                seconds = match value.parse::<f64>() {
+                    Ok(seconds) if seconds > 0.0 && seconds.is_finite() => seconds,
+                    _ => return Err(usage(&format!("invalid -seconds duration: {}", value))),
+                };
+            }
+            "-bytes" => {
+                let size = super::parse_len("-bytes", args.next()).map_err(|reason| usage(&reason))?;
+                block_sizes = vec![size];
+            }
+            option if option.starts_with('-') => return Err(usage(&format!("unknown option: {}", option))),
+            name => names.push(name.to_ascii_lowercase()),
+        }
+    }
+
+    let mut algorithms = algorithms();
+    if !names.is_empty() {
+        if let Some(unknown) = names.iter().find(|name| !algorithms.iter().any(|algorithm| &algorithm.name == *name)) {
+            return Err(usage(&format!("unknown algorithm: {}", unknown)));
+        }
+        algorithms.retain(|algorithm| names.contains(&algorithm.name));
+    }
+
+    let duration = Duration::from_secs_f64(seconds);
+    let block = vec![0xA5u8; block_sizes.iter().copied().max().unwrap_or_default()];
+    let mut rows = Vec::with_capacity(algorithms.len());
+    for algorithm in &algorithms {
+        let mut output = vec![0u8; (algorithm.start)().output_len()];
+        let throughputs: Vec<f64> = block_sizes
+            .iter()
+            .map(|&size| {
+                eprintln!("Doing {} for {}s on {} size blocks", algorithm.name, seconds, size);
+                let (ops, elapsed) = measure(duration, || {
+                    let mut hash = (algorithm.start)();
+                    hash.update(black_box(&block[..size]));
+                    hash.finalize_into(&mut output);
+                    black_box(&output);
+                });
+                eprintln!("{} {} ops in {:.2}s", ops, algorithm.name, elapsed.as_secs_f64());
+                (ops * size as u64) as f64 / elapsed.as_secs_f64()
+            })
+            .collect();
+        rows.push((algorithm.name.as_str(), throughputs));
+    }
+
+    println!("The 'numbers' are in 1000s of bytes per second processed.");
+    print!("{:<18}", "type");
+    block_sizes.iter().for_each(|size| print!(" {:>12}", format!("{} bytes", size)));
+    println!();
+    for (name, throughputs) in rows {
+        print!("{:<18}", name);
+        throughputs.iter().for_each(|throughput| print!(" {:>11.2}k", throughput / 1000.0));
+        println!();
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Runs `operation` over and over for at least `duration`, and returns how many times it ran, and for how long.
+fn measure(duration: Duration, mut operation: impl FnMut()) -> (u64, Duration) {
+    let start = Instant::now();
+    let mut ops = 0;
+    loop {
+        for _ in 0..OPS_PER_READING {
+            operation();
+        }
+        ops += OPS_PER_READING;
+
+        let elapsed = start.elapsed();
+        if elapsed >= duration {
+            return (ops, elapsed);
+        }
+    }
+}
+
+fn help() -> String {
+    let mut help = String::from(USAGE);
+    for algorithm in algorithms() {
+        help.push_str(&format!("\n  {}", algorithm.name));
+    }
+    help
+}
+
+fn usage(reason: &str) -> CliError {
+    CliError::Usage(format!("speed: {}\nTry `rs_shield_cli speed -help`.", reason))
+}
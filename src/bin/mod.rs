@@ -1,37 +1,51 @@
-use core::hash::{BuildHasher, Hasher};
-use rs_shield::HasherContext;
-use std::{env, fs, process};
-
-use rs_sha3_224::Sha3_224State;
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let file_path = parse_arguments(args.iter()).unwrap_or_else(|err| {
-        eprintln!("{}", err);
-        process::exit(1);
-    });
-
-    let mut hasher = Sha3_224State::default().build_hasher();
-    fs::read(file_path)
-        .unwrap_or_else(|_| panic!("Not able to read {}", file_path))
-        .chunks(128)
-        .for_each(|line| hasher.write(line.as_ref()));
-
-    let result = HasherContext::finish(&mut hasher);
-    println!("SHA3-224({}) = {:02x}", file_path, result)
-}
-
-fn parse_arguments<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<&'a String, &'static str> {
-    match args.next() {
-        Some(_) => (),
-        None => return Err("Executable cannot have no name"),
-    };
-
-    let file_path = match args.next() {
-        Some(file_path) => file_path,
-        None => return Err("Did not receive a file path"),
+mod commands;
+
+use commands::{dgst, enc, kdf, mac, scan, selftest, speed, CliError};
+use std::{env, process::ExitCode};
+
+const USAGE: &str = "\
+Usage: rs_shield_cli <command> [options]
+
+Commands:
+  dgst      Hash files or the standard input, or verify checksum manifests
+  mac       Authenticate files or the standard input with HMAC or KMAC
+  kdf       Derive a key with HKDF, PBKDF2 or KMAC
+  enc       Derive the key and IV of OpenSSL salted files
+  speed     Measure the throughput of the algorithms
+  scan      Record a baseline of a directory tree, or compare the tree with it
+  selftest  Run the known-answer tests of the algorithms
+
+Run `rs_shield_cli <command> -help` for the options of a command.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    #[cfg(feature = "cli-power-on-self-test")]
+    if args.first().is_some_and(|command| command != "selftest") {
+        if let Err(error) = selftest::power_on() {
+            eprintln!("rs_shield_cli: {}", error);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let result = match args.split_first() {
+        Some((command, args)) if command == "dgst" => dgst::run(args),
+        Some((command, args)) if command == "mac" => mac::run(args),
+        Some((command, args)) if command == "kdf" => kdf::run(args),
+        Some((command, args)) if command == "enc" => enc::run(args),
+        Some((command, args)) if command == "speed" => speed::run(args),
+        Some((command, args)) if command == "scan" => scan::run(args),
+        Some((command, args)) if command == "selftest" => selftest::run(args),
+        Some((command, _)) if matches!(command.as_str(), "-h" | "-help" | "--help" | "help") => {
+            println!("{}", USAGE);
+            Ok(ExitCode::SUCCESS)
+        }
+        Some((command, _)) => Err(CliError::Usage(format!("unknown command: {}\n\n{}", command, USAGE))),
+        None => Err(CliError::Usage(USAGE.to_string())),
     };
 
-    Ok(file_path)
+    result.unwrap_or_else(|error| {
+        eprintln!("rs_shield_cli: {}", error);
+        ExitCode::FAILURE
+    })
 }
@@ -60,7 +60,18 @@
 //! | Triple DES - `coming soon`    | SHAKE256 - [`rs_shake256`](../rs_shake256/index.html)        |                                             |
 //! | GOST 28147-89 - `coming soon` | HMAC - [`rs_hmac`](../rs_hmac/index.html)                    |                                             |
 //! | SM4 - `coming soon`           | Generic Keccak {200, 400, 800, 1600} - [`rs_keccak_nbits`](../rs_keccak_nbits/index.html) |                                             |
-//! |                               | BLAKE2 - `coming soon`                                       |                                             |
+//! | SpongeWrap - [`rs_sponge_wrap`](../rs_sponge_wrap/index.html) | cSHAKE - [`rs_cshake`](../rs_cshake/index.html)              |                                             |
+//! |                               | KMAC - [`rs_kmac`](../rs_kmac/index.html)                    |                                             |
+//! |                               | TupleHash - [`rs_tuple_hash`](../rs_tuple_hash/index.html)   |                                             |
+//! |                               | ParallelHash - [`rs_parallel_hash`](../rs_parallel_hash/index.html) |                                      |
+//! |                               | TurboSHAKE - [`rs_turbo_shake`](../rs_turbo_shake/index.html) |                                            |
+//! |                               | KangarooTwelve - [`rs_kangaroo_twelve`](../rs_kangaroo_twelve/index.html) |                                |
+//! |                               | HKDF - [`rs_hkdf`](../rs_hkdf/index.html)                    |                                             |
+//! |                               | PBKDF2 - [`rs_pbkdf2`](../rs_pbkdf2/index.html)              |                                             |
+//! |                               | scrypt - [`rs_scrypt`](../rs_scrypt/index.html)              |                                             |
+//! |                               | Argon2 - [`rs_argon2`](../rs_argon2/index.html)              |                                             |
+//! |                               | BLAKE2b - [`rs_blake2`](../rs_blake2/index.html)             |                                             |
+//! |                               | HMAC_DRBG, Hash_DRBG - [`rs_drbg`](../rs_drbg/index.html)    |                                             |
 //! |                               | GOST R 34.11-94 - `coming soon`                              |                                             |
 //! |                               | MD2 - `coming soon`                                          |                                             |
 //! |                               | MD4 - `coming soon`                                          |                                             |
@@ -70,6 +81,210 @@
 //! |                               | SM3 - `coming soon`                                          |                                             |
 //! |                               | Whirlpool - `coming soon`                                    |                                             |
 //!
+//! ## Cargo Features
+//!
+//! Each algorithm sits behind a feature named after its crate without the `rs_` prefix, such as `sha256`, `hmac` or
+//! `kangaroo_twelve`, so that constrained targets only build what they use:
+//!
+//! ```toml
+//! rs_shield = { version = "0.1", default-features = false, features = ["sha256", "hmac"] }
+//! ```
+//!
+//! The features are also grouped:
+//!
+//! - `sha2`: SHA-224, SHA-256, SHA-384, SHA-512, SHA-512/224 and SHA-512/256;
+//! - `sha3`: the SHA-3 functions, SHAKE and everything else built upon Keccak, from cSHAKE to KangarooTwelve;
+//! - `legacy`: SHA-1, and the older hash functions and ciphers, such as MD5 and DES;
+//! - `kdf`: HKDF, PBKDF2, scrypt and Argon2;
+//! - `aead`: AES, ChaCha20, Poly1305 and SpongeWrap;
+//! - `ciphers`: the `aead` group and the other block ciphers;
+//! - `pubkey`: the public-key functions;
+//! - `full`: every algorithm.
+//!
+//! The default features, `sha1`, `sha2`, `sha3`, `blake2`, `hmac`, `kdf`, `drbg` and `sponge_wrap`, bring every
+//! algorithm implemented so far. The `rs_shield_cli` binary needs `sha1`, `sha2`, `sha3`, `blake2`, `hmac`, `hkdf`,
+//! `pbkdf2`, `drbg`, `alloc`, for the registry of hash functions it picks them from, and `std`, for the operating
+//! system entropy its salts are drawn from.
+//!
+//! ## Saving and Resuming a Hash Computation
+//!
+//! The hashers of the SHA and Keccak families implement `Midstate`, which exports their full in-progress state into a
+//! versioned blob tagged with the algorithm and its parameters. The blob can be stored and imported back later on, even
+//! by another process, to resume the computation where it left off. Importing refuses blobs written by another
+//! algorithm, by an unknown layout version, or whose checksum does not match.
+//!
+//! ```rust
+//! # use std::hash::Hasher;
+//! # use rs_shield::{HasherContext, Midstate, MidstateError, Sha256Hasher, Sha512Hasher};
+//! let mut sha256hasher = Sha256Hasher::default();
+//! sha256hasher.write(b"hello");
+//!
+//! let mut midstate = [0u8; 256];
+//! let len = sha256hasher.export_midstate(&mut midstate).unwrap();
+//!
+//! let mut resumed = Sha256Hasher::import_midstate(&midstate[..len]).unwrap();
+//! resumed.write(b" world");
+//! assert_eq!(
+//!     format!("{:02x}", HasherContext::finish(&mut resumed)),
+//!     "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+//! );
+//!
+//! assert_eq!(Sha512Hasher::import_midstate(&midstate[..len]), Err(MidstateError::AlgorithmMismatch));
+//! ```
+//!
+//! ## Password-Based Key Derivation
+//!
+//! `Pbkdf2` derives keys out of passwords with any hash function supported by `Hmac`, into caller provided buffers.
+//! `Scrypt` and `Argon2` additionally need scratch memory, which is either lent by the caller or, with the `alloc`
+//! feature of this crate, allocated by `Scrypt::derive` and `Argon2::hash`. The `alloc` feature also brings the PHC
+//! string format of Argon2, and the `std` feature lets Argon2 fill its lanes on several threads.
+//!
+//! ```rust
+//! # use rs_shield::{Pbkdf2, Scrypt, Sha256State};
+//! let mut key = [0u8; 32];
+//! Pbkdf2::<Sha256State, 32>::new(b"password").derive(b"salt", 4096, &mut key).unwrap();
+//!
+//! let scrypt = Scrypt::new(4, 1, 1).unwrap();
+//! let mut scratch = [0u8; 128 * (16 + 1 + 2)];
+//! scrypt.derive_with_scratch(b"password", b"salt", &mut scratch, &mut key).unwrap();
+//! ```
+//!
+//! ## Random Bit Generation
+//!
+//! `HmacDrbg` and `HashDrbg` are the deterministic random bit generators of NIST SP 800-90A, over any hash function
+//! supported by `Hmac`. A `Drbg` draws their entropy from an `EntropySource`, reseeds them when needed, and implements
+//! `RngCore`. With the `std` feature of this crate, `OsEntropy` draws the entropy from the operating system.
+//!
+//! ```rust
+//! # use rs_shield::{Drbg, DrbgError, EntropySource, HmacDrbg, RngCore, Sha256State};
+//! struct FixedEntropy;
+//!
+//! impl EntropySource for FixedEntropy {
+//!     fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), DrbgError> {
+//!         dest.fill(0x2A);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut drbg = Drbg::<HmacDrbg<Sha256State, 32>, _>::new(FixedEntropy, b"app").unwrap();
+//! let mut key = [0u8; 32];
+//! drbg.fill_bytes(&mut key);
+//! ```
+//!
+//! ## Zeroization
+//!
+//! Types holding secret material, such as `Hmac`, `Kmac128`, `SpongeWrap` and the DRBGs, implement `Zeroize`. Their
+//! state is overwritten with volatile writes when dropped, or earlier through `Zeroize::zeroize`, and their `Debug`
+//! output leaves it out. The key derivation functions also wipe their intermediate values once the key is derived.
+//!
+//! ```rust
+//! # use rs_shield::{Hmac, Mac, Sha256State, Zeroize};
+//! let mut hmac = Hmac::<Sha256State, 32>::new(b"key");
+//! assert_eq!(format!("{hmac:?}"), "Hmac { .. }");
+//!
+//! hmac.update(b"message");
+//! hmac.zeroize();
+//! ```
+//!
+//! ## Constant-Time Comparisons
+//!
+//! `ConstantTimeEq` compares secret values in a time that does not depend on where they differ, and returns a `Choice`
+//! rather than a `bool`, which `ConditionallySelectable` turns into branch-free selections. Digests and tags, held in
+//! `ByteArrayWrapper`, compare in constant time even through `==`, and `Mac::verify` relies on them.
+//!
+//! ```rust
+//! # use rs_shield::{ConditionallySelectable, ConstantTimeEq, Hmac, Sha256State};
+//! let tag = Hmac::<Sha256State, 32>::digest(b"key", b"message");
+//! let forged_tag = [0u8; 32];
+//!
+//! let is_valid = tag.as_ref().ct_eq(&forged_tag);
+//! assert_eq!(u8::conditional_select(&0, &1, is_valid), 0);
+//! ```
+//!
+//! ## Batch Hashing
+//!
+//! `Sha256Batch` and `Sha512Batch` hash many independent messages at once, such as the nodes of a Merkle tree, by
+//! interleaving their rounds across SIMD lanes. The `BatchBackend` is picked at runtime among SSE4.1 and AVX2 with the
+//! `std` feature, and a portable one is always available; every backend yields the same digests as the hashers.
+//!
+//! ```rust
+//! # use rs_shield::{Sha256Batch, Sha256Hasher, HasherContext};
+//! # use std::hash::Hasher;
+//! let nodes: Vec<[u8; 64]> = (0..100).map(|i| [i as u8; 64]).collect();
+//! let messages: Vec<&[u8]> = nodes.iter().map(|node| &node[..]).collect();
+//! let mut digests = vec![Default::default(); nodes.len()];
+//!
+//! Sha256Batch::default().digest(&messages, &mut digests);
+//!
+//! let mut sha256hasher = Sha256Hasher::default();
+//! sha256hasher.write(&nodes[42]);
+//! assert_eq!(digests[42], HasherContext::finish(&mut sha256hasher));
+//! ```
+//!
+//! ## Parallel Tree Hashing
+//!
+//! KT128 and KT256 hash their input as a Sakura-encoded tree of 8 KiB chunks over TurboSHAKE. With the `std` feature,
+//! `write_parallel` hashes these chunks on all the available threads, and `hash_file` does so over a file mapped in
+//! memory, with the same digests as a serial `write`.
+//!
+//! ```rust
+//! # use rs_shield::{HasherContext, Kt128};
+//! # use std::hash::Hasher;
+//! let artifact: Vec<u8> = (0..1 << 20).map(|i| i as u8).collect();
+//!
+//! let mut kt128 = Kt128::<32>::default();
+//! # #[cfg(feature = "std")]
+//! kt128.write_parallel(&artifact);
+//! # #[cfg(not(feature = "std"))]
+//! # kt128.write(&artifact);
+//!
+//! let mut serial_kt128 = Kt128::<32>::default();
+//! serial_kt128.write(&artifact);
+//! assert_eq!(HasherContext::finish(&mut kt128), HasherContext::finish(&mut serial_kt128));
+//! ```
+//!
+//! ## Hardware Acceleration
+//!
+//! SHA-1, SHA-224 and SHA-256 compress their blocks with the SHA extensions on x86_64 CPUs, and with the cryptographic
+//! extension on AArch64 ones, whenever the running CPU has them. The detection happens once, at the first block
+//! hashed, and the portable implementation remains the fallback. Both yield the same digests.
+//!
+//! ## Self-Tests
+//!
+//! `self_test` runs a known-answer test of every hash function, MAC, key derivation function and DRBG the cargo
+//! features enable, against the examples of their standards, and returns a `SelfTestReport` telling which ones
+//! returned the expected answer.
+//! FIPS-style deployments run it at startup and refuse to operate unless it passed. The library leaves that decision
+//! to its caller, as the algorithms can be used straight from their crates; only `rs_shield_cli` refuses to operate on
+//! its own, when built with the `cli-power-on-self-test` feature.
+//!
+//! ```rust
+//! let report = rs_shield::self_test();
+//! for failure in report.failures() {
+//!     eprintln!("{} failed its known-answer test", failure.algorithm);
+//! }
+//! assert!(report.passed());
+//! ```
+//!
+//! ## Choosing the Algorithm at Runtime
+//!
+//! With the `alloc` feature, `HashFunction::find` looks the hash functions up by name, such as `SHA3-256` or
+//! `sha512/256`, and `HashFunction::find_by_oid` by ASN.1 object identifier. `HashFunction::new_hasher` then returns
+//! a `Box<dyn DynHasher>`, which drives any of them through the same calls. `HASH_FUNCTIONS` lists them all.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! # use rs_shield::HashFunction;
+//! let configured = "sha3_256";
+//! let mut hasher = HashFunction::find(configured).unwrap().new_hasher();
+//! hasher.update(b"hello");
+//!
+//! let mut digest = vec![0u8; hasher.output_len()];
+//! hasher.finalize_into(&mut digest);
+//! # }
+//! ```
+//!
 //!  ## On Hash Trait and Trailing Byte
 //!
 //! The Rust `Hash` trait includes [a mechanism to guard against prefix collision attacks](https://doc.rust-lang.org/1.69.0/std/hash/trait.Hash.html#prefix-collisions) that appends a
@@ -90,19 +305,74 @@
 
 #![no_std]
 
-pub use rs_hasher_ctx::HasherContext;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub use registry::{DynHasher, HashFunction, HASH_FUNCTIONS};
+#[cfg(feature = "argon2")]
+pub use rs_argon2::{Algorithm as Argon2Algorithm, Argon2, Argon2Error};
+#[cfg(feature = "blake2")]
+pub use rs_blake2::{Blake2b256Hasher, Blake2b512Hasher, Blake2bHasher, Blake2bState};
+pub use rs_constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+#[cfg(feature = "cshake")]
+pub use rs_cshake::{CShake128Hasher, CShake128State, CShake256Hasher, CShake256State};
+#[cfg(all(feature = "drbg", feature = "std"))]
+pub use rs_drbg::OsEntropy;
+#[cfg(feature = "drbg")]
+pub use rs_drbg::{Drbg, DrbgError, DrbgMechanism, EntropySource, HashDrbg, HmacDrbg, RngCore};
+pub use rs_hasher_ctx::{HasherContext, Mac, MacError, Midstate, MidstateError, Zeroize, MIDSTATE_VERSION};
+#[cfg(feature = "hkdf")]
+pub use rs_hkdf::{Hkdf, HkdfError};
+#[cfg(feature = "hmac")]
 pub use rs_hmac::Hmac;
+pub use rs_internal_state::XofReader;
+#[cfg(feature = "kangaroo_twelve")]
+pub use rs_kangaroo_twelve::{Kt128, Kt256};
+#[cfg(feature = "keccak_nbits")]
 pub use rs_keccak_nbits::{NBitKeccakHasher, NBitKeccakState};
+#[cfg(feature = "kmac")]
+pub use rs_kmac::{Kmac128, Kmac256};
+#[cfg(feature = "parallel_hash")]
+pub use rs_parallel_hash::{ParallelHash128, ParallelHash256};
+#[cfg(feature = "pbkdf2")]
+pub use rs_pbkdf2::{Pbkdf2, Pbkdf2Error};
+#[cfg(feature = "scrypt")]
+pub use rs_scrypt::{Scrypt, ScryptError};
+#[cfg(feature = "sha1")]
 pub use rs_sha1::{Sha1Hasher, Sha1State};
+#[cfg(feature = "sha224")]
 pub use rs_sha224::{Sha224Hasher, Sha224State};
-pub use rs_sha256::{Sha256Hasher, Sha256State};
+#[cfg(feature = "sha256")]
+pub use rs_sha256::{BatchBackend, Sha256Batch, Sha256Hasher, Sha256State};
+#[cfg(feature = "sha384")]
 pub use rs_sha384::{Sha384Hasher, Sha384State};
+#[cfg(feature = "sha3_224")]
 pub use rs_sha3_224::{Sha3_224Hasher, Sha3_224State};
+#[cfg(feature = "sha3_256")]
 pub use rs_sha3_256::{Sha3_256Hasher, Sha3_256State};
+#[cfg(feature = "sha3_384")]
 pub use rs_sha3_384::{Sha3_384Hasher, Sha3_384State};
+#[cfg(feature = "sha3_512")]
 pub use rs_sha3_512::{Sha3_512Hasher, Sha3_512State};
-pub use rs_sha512::{Sha512Hasher, Sha512State};
+#[cfg(feature = "sha512")]
+pub use rs_sha512::{Sha512Batch, Sha512Hasher, Sha512State};
+#[cfg(feature = "sha512_224")]
 pub use rs_sha512_224::{Sha512_224Hasher, Sha512_224State};
+#[cfg(feature = "sha512_256")]
 pub use rs_sha512_256::{Sha512_256Hasher, Sha512_256State};
-pub use rs_shake128::{Shake128Hasher, Shake128State};
-pub use rs_shake256::{Shake256Hasher, Shake256State};
+#[cfg(feature = "shake128")]
+pub use rs_shake128::{Shake128Hasher, Shake128Reader, Shake128State};
+#[cfg(feature = "shake256")]
+pub use rs_shake256::{Shake256Hasher, Shake256Reader, Shake256State};
+#[cfg(feature = "sponge_wrap")]
+pub use rs_sponge_wrap::SpongeWrap;
+#[cfg(feature = "tuple_hash")]
+pub use rs_tuple_hash::{TupleHash128, TupleHash256};
+#[cfg(feature = "turbo_shake")]
+pub use rs_turbo_shake::{TurboShake128Hasher, TurboShake128State, TurboShake256Hasher, TurboShake256State};
+pub use self_test::{self_test, KatOutcome, SelfTestReport};
+
+#[cfg(feature = "alloc")]
+mod registry;
+mod self_test;
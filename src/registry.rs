@@ -0,0 +1,551 @@
+//! A registry of the hash functions of RustyShield, looked up by name or ASN.1 object identifier at runtime, and the
+//! `DynHasher` trait object they are driven through.
+
+#[cfg(feature = "sha1")]
+use crate::Sha1Hasher;
+#[cfg(feature = "sha224")]
+use crate::Sha224Hasher;
+#[cfg(feature = "sha256")]
+use crate::Sha256Hasher;
+#[cfg(feature = "sha384")]
+use crate::Sha384Hasher;
+#[cfg(feature = "sha3_224")]
+use crate::Sha3_224Hasher;
+#[cfg(feature = "sha3_256")]
+use crate::Sha3_256Hasher;
+#[cfg(feature = "sha3_384")]
+use crate::Sha3_384Hasher;
+#[cfg(feature = "sha3_512")]
+use crate::Sha3_512Hasher;
+#[cfg(feature = "sha512")]
+use crate::Sha512Hasher;
+#[cfg(feature = "sha512_224")]
+use crate::Sha512_224Hasher;
+#[cfg(feature = "sha512_256")]
+use crate::Sha512_256Hasher;
+#[cfg(feature = "shake128")]
+use crate::Shake128Hasher;
+#[cfg(feature = "shake256")]
+use crate::Shake256Hasher;
+#[cfg(feature = "blake2")]
+use crate::{Blake2b256Hasher, Blake2b512Hasher};
+#[cfg(feature = "cshake")]
+use crate::{CShake128Hasher, CShake256Hasher};
+#[cfg(feature = "kangaroo_twelve")]
+use crate::{Kt128, Kt256};
+#[cfg(feature = "parallel_hash")]
+use crate::{ParallelHash128, ParallelHash256};
+#[cfg(feature = "turbo_shake")]
+use crate::{TurboShake128Hasher, TurboShake256Hasher};
+use alloc::boxed::Box;
+
+/// Byte length of the blocks ParallelHash splits its input into, the 8 KiB of the chunks of KangarooTwelve
+#[cfg(feature = "parallel_hash")]
+const PARALLEL_HASH_BLOCK_SIZE: usize = 8192;
+
+/// `DynHasher` is the object-safe interface shared by every hash function, whichever its type and output length, so
+/// that applications picking the function at runtime can hold any of them in a `Box<dyn DynHasher>`.
+pub trait DynHasher {
+    /// Feeds `bytes` into the hash.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Fills `output` with the digest of the bytes fed since the start or the last reset, then resets the hasher.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has a fixed output length and `output` is not `output_len` bytes long. Extendable-output
+    /// functions fill `output` whatever its length.
+    fn finalize_into(&mut self, output: &mut [u8]);
+
+    /// Returns the byte length of the digests, or their default length for extendable-output functions.
+    fn output_len(&self) -> usize;
+
+    /// Returns the byte length of the blocks the function compresses, or the rate of the sponge functions.
+    fn block_len(&self) -> usize;
+
+    /// Forgets the bytes fed so far.
+    fn reset(&mut self);
+
+    /// Returns a copy of the hasher, in the same state.
+    fn box_clone(&self) -> Box<dyn DynHasher>;
+}
+
+impl Clone for Box<dyn DynHasher> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// `HashFunction` describes a hash function of the registry, and creates hashers computing it.
+#[derive(Clone, Copy, Debug)]
+pub struct HashFunction {
+    /// Name of the function, as its standard spells it
+    pub name: &'static str,
+    /// Other names the function is known by, such as the OpenSSL ones
+    pub aliases: &'static [&'static str],
+    /// Dotted ASN.1 object identifier of the function, for those that have one
+    pub oid: Option<&'static str>,
+    /// Byte length of the digests, or their default length for extendable-output functions
+    pub output_len: usize,
+    /// Byte length of the blocks, or rate of the sponge functions
+    pub block_len: usize,
+    /// Whether the function is an extendable-output function
+    pub is_xof: bool,
+    new: fn() -> Box<dyn DynHasher>,
+}
+
+impl HashFunction {
+    /// Returns the function called `name`, ignoring its case, dashes, slashes and underscores, so that `SHA3-256`,
+    /// `sha3_256` and `SHA3256` all name SHA3-256.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rs_shield::HashFunction;
+    /// let sha512_256 = HashFunction::find("sha512/256").unwrap();
+    /// assert_eq!(sha512_256.name, "SHA-512/256");
+    /// assert_eq!(HashFunction::find("SHA2-512/256").unwrap().name, "SHA-512/256");
+    /// assert!(HashFunction::find("MD5").is_none());
+    /// ```
+    pub fn find(name: &str) -> Option<&'static Self> {
+        HASH_FUNCTIONS.iter().find(|function| {
+            core::iter::once(&function.name).chain(function.aliases).any(|alias| same_name(alias, name))
+        })
+    }
+
+    /// Returns the function identified by the dotted object identifier `oid`, such as `2.16.840.1.101.3.4.2.8`.
+    pub fn find_by_oid(oid: &str) -> Option<&'static Self> {
+        HASH_FUNCTIONS.iter().find(|function| function.oid == Some(oid))
+    }
+
+    /// Creates a hasher computing the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rs_shield::HashFunction;
+    /// let sha3_256 = HashFunction::find_by_oid("2.16.840.1.101.3.4.2.8").unwrap();
+    /// let mut hasher = sha3_256.new_hasher();
+    /// hasher.update(b"abc");
+    ///
+    /// let mut digest = vec![0u8; hasher.output_len()];
+    /// hasher.finalize_into(&mut digest);
+    /// assert_eq!(digest[..4], [0x3A, 0x98, 0x5D, 0xA7]);
+    /// ```
+    pub fn new_hasher(&self) -> Box<dyn DynHasher> {
+        (self.new)()
+    }
+}
+
+/// Every hash function of the registry enabled by the cargo features, in the order of the NIST object identifiers
+/// then of the newer functions
+pub static HASH_FUNCTIONS: &[HashFunction] = &[
+    #[cfg(feature = "sha1")]
+    HashFunction {
+        name: "SHA-1",
+        aliases: &["SHA1"],
+        oid: Some("1.3.14.3.2.26"),
+        output_len: 20,
+        block_len: 64,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 20, 64>::new(Sha1Hasher::default())),
+    },
+    #[cfg(feature = "sha256")]
+    HashFunction {
+        name: "SHA-256",
+        aliases: &["SHA2-256"],
+        oid: Some("2.16.840.1.101.3.4.2.1"),
+        output_len: 32,
+        block_len: 64,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 32, 64>::new(Sha256Hasher::default())),
+    },
+    #[cfg(feature = "sha384")]
+    HashFunction {
+        name: "SHA-384",
+        aliases: &["SHA2-384"],
+        oid: Some("2.16.840.1.101.3.4.2.2"),
+        output_len: 48,
+        block_len: 128,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 48, 128>::new(Sha384Hasher::default())),
+    },
+    #[cfg(feature = "sha512")]
+    HashFunction {
+        name: "SHA-512",
+        aliases: &["SHA2-512"],
+        oid: Some("2.16.840.1.101.3.4.2.3"),
+        output_len: 64,
+        block_len: 128,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 64, 128>::new(Sha512Hasher::default())),
+    },
+    #[cfg(feature = "sha224")]
+    HashFunction {
+        name: "SHA-224",
+        aliases: &["SHA2-224"],
+        oid: Some("2.16.840.1.101.3.4.2.4"),
+        output_len: 28,
+        block_len: 64,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 28, 64>::new(Sha224Hasher::default())),
+    },
+    #[cfg(feature = "sha512_224")]
+    HashFunction {
+        name: "SHA-512/224",
+        aliases: &["SHA2-512/224"],
+        oid: Some("2.16.840.1.101.3.4.2.5"),
+        output_len: 28,
+        block_len: 128,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 28, 128>::new(Sha512_224Hasher::default())),
+    },
+    #[cfg(feature = "sha512_256")]
+    HashFunction {
+        name: "SHA-512/256",
+        aliases: &["SHA2-512/256"],
+        oid: Some("2.16.840.1.101.3.4.2.6"),
+        output_len: 32,
+        block_len: 128,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 32, 128>::new(Sha512_256Hasher::default())),
+    },
+    #[cfg(feature = "sha3_224")]
+    HashFunction {
+        name: "SHA3-224",
+        aliases: &[],
+        oid: Some("2.16.840.1.101.3.4.2.7"),
+        output_len: 28,
+        block_len: 144,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 28, 144>::new(Sha3_224Hasher::default())),
+    },
+    #[cfg(feature = "sha3_256")]
+    HashFunction {
+        name: "SHA3-256",
+        aliases: &[],
+        oid: Some("2.16.840.1.101.3.4.2.8"),
+        output_len: 32,
+        block_len: 136,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 32, 136>::new(Sha3_256Hasher::default())),
+    },
+    #[cfg(feature = "sha3_384")]
+    HashFunction {
+        name: "SHA3-384",
+        aliases: &[],
+        oid: Some("2.16.840.1.101.3.4.2.9"),
+        output_len: 48,
+        block_len: 104,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 48, 104>::new(Sha3_384Hasher::default())),
+    },
+    #[cfg(feature = "sha3_512")]
+    HashFunction {
+        name: "SHA3-512",
+        aliases: &[],
+        oid: Some("2.16.840.1.101.3.4.2.10"),
+        output_len: 64,
+        block_len: 72,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 64, 72>::new(Sha3_512Hasher::default())),
+    },
+    #[cfg(feature = "shake128")]
+    HashFunction {
+        name: "SHAKE128",
+        aliases: &[],
+        oid: Some("2.16.840.1.101.3.4.2.11"),
+        output_len: 32,
+        block_len: 168,
+        is_xof: true,
+        new: || Box::new(Xof::<_, _, 32, 168>::new(Shake128Hasher::<32>::default(), Shake128Hasher::finish_xof)),
+    },
+    #[cfg(feature = "shake256")]
+    HashFunction {
+        name: "SHAKE256",
+        aliases: &[],
+        oid: Some("2.16.840.1.101.3.4.2.12"),
+        output_len: 64,
+        block_len: 136,
+        is_xof: true,
+        new: || Box::new(Xof::<_, _, 64, 136>::new(Shake256Hasher::<64>::default(), Shake256Hasher::finish_xof)),
+    },
+    #[cfg(feature = "blake2")]
+    HashFunction {
+        name: "BLAKE2b-256",
+        aliases: &["BLAKE2B256"],
+        oid: Some("1.3.6.1.4.1.1722.12.2.1.8"),
+        output_len: 32,
+        block_len: 128,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 32, 128>::new(Blake2b256Hasher::default())),
+    },
+    #[cfg(feature = "blake2")]
+    HashFunction {
+        name: "BLAKE2b-512",
+        aliases: &["BLAKE2B512", "BLAKE2b"],
+        oid: Some("1.3.6.1.4.1.1722.12.2.1.16"),
+        output_len: 64,
+        block_len: 128,
+        is_xof: false,
+        new: || Box::new(Fixed::<_, 64, 128>::new(Blake2b512Hasher::default())),
+    },
+    #[cfg(feature = "cshake")]
+    HashFunction {
+        name: "cSHAKE128",
+        aliases: &[],
+        oid: None,
+        output_len: 32,
+        block_len: 168,
+        is_xof: true,
+        new: || {
+            let hasher = CShake128Hasher::<32>::new(b"", b"");
+            Box::new(Xof::<_, _, 32, 168>::new(hasher, CShake128Hasher::finish_xof))
+        },
+    },
+    #[cfg(feature = "cshake")]
+    HashFunction {
+        name: "cSHAKE256",
+        aliases: &[],
+        oid: None,
+        output_len: 64,
+        block_len: 136,
+        is_xof: true,
+        new: || {
+            let hasher = CShake256Hasher::<64>::new(b"", b"");
+            Box::new(Xof::<_, _, 64, 136>::new(hasher, CShake256Hasher::finish_xof))
+        },
+    },
+    #[cfg(feature = "turbo_shake")]
+    HashFunction {
+        name: "TurboSHAKE128",
+        aliases: &[],
+        oid: None,
+        output_len: 32,
+        block_len: 168,
+        is_xof: true,
+        new: || {
+            let hasher = TurboShake128Hasher::<32>::default();
+            Box::new(Xof::<_, _, 32, 168>::new(hasher, TurboShake128Hasher::finish_xof))
+        },
+    },
+    #[cfg(feature = "turbo_shake")]
+    HashFunction {
+        name: "TurboSHAKE256",
+        aliases: &[],
+        oid: None,
+        output_len: 64,
+        block_len: 136,
+        is_xof: true,
+        new: || {
+            let hasher = TurboShake256Hasher::<64>::default();
+            Box::new(Xof::<_, _, 64, 136>::new(hasher, TurboShake256Hasher::finish_xof))
+        },
+    },
+    #[cfg(feature = "kangaroo_twelve")]
+    HashFunction {
+        name: "KT128",
+        aliases: &["KangarooTwelve"],
+        oid: None,
+        output_len: 32,
+        block_len: 168,
+        is_xof: true,
+        new: || Box::new(Xof::<_, _, 32, 168>::new(Kt128::<32>::new(b""), Kt128::finish_xof)),
+    },
+    #[cfg(feature = "kangaroo_twelve")]
+    HashFunction {
+        name: "KT256",
+        aliases: &[],
+        oid: None,
+        output_len: 64,
+        block_len: 136,
+        is_xof: true,
+        new: || Box::new(Xof::<_, _, 64, 136>::new(Kt256::<64>::new(b""), Kt256::finish_xof)),
+    },
+    #[cfg(feature = "parallel_hash")]
+    HashFunction {
+        name: "ParallelHash128",
+        aliases: &[],
+        oid: None,
+        output_len: 32,
+        block_len: 168,
+        is_xof: true,
+        new: || {
+            let hasher = ParallelHash128::<32>::new(PARALLEL_HASH_BLOCK_SIZE, b"");
+            Box::new(Xof::<_, _, 32, 168>::new(hasher, ParallelHash128::finish_xof))
+        },
+    },
+    #[cfg(feature = "parallel_hash")]
+    HashFunction {
+        name: "ParallelHash256",
+        aliases: &[],
+        oid: None,
+        output_len: 64,
+        block_len: 136,
+        is_xof: true,
+        new: || {
+            let hasher = ParallelHash256::<64>::new(PARALLEL_HASH_BLOCK_SIZE, b"");
+            Box::new(Xof::<_, _, 64, 136>::new(hasher, ParallelHash256::finish_xof))
+        },
+    },
+];
+
+// Keeps the items only when a hash function of the kind they wrap is enabled
+macro_rules! fixed_len {
+    ($($item:item)*) => {
+        $(
+            #[cfg(any(
+                feature = "sha1",
+                feature = "sha224",
+                feature = "sha256",
+                feature = "sha384",
+                feature = "sha512",
+                feature = "sha512_224",
+                feature = "sha512_256",
+                feature = "sha3_224",
+                feature = "sha3_256",
+                feature = "sha3_384",
+                feature = "sha3_512",
+                feature = "blake2"
+            ))]
+            $item
+        )*
+    };
+}
+
+macro_rules! extendable_output {
+    ($($item:item)*) => {
+        $(
+            #[cfg(any(
+                feature = "shake128",
+                feature = "shake256",
+                feature = "cshake",
+                feature = "turbo_shake",
+                feature = "kangaroo_twelve",
+                feature = "parallel_hash"
+            ))]
+            $item
+        )*
+    };
+}
+
+fixed_len! {
+    use crate::HasherContext;
+
+    /// Fixed-length hash function, kept along with its initial state to reset it
+    #[derive(Clone)]
+    struct Fixed<H, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> {
+        initial: H,
+        hasher: H,
+    }
+
+    impl<H: Clone, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> Fixed<H, OUTPUT_LEN, BLOCK_LEN> {
+        fn new(hasher: H) -> Self {
+            Self {
+                initial: hasher.clone(),
+                hasher,
+            }
+        }
+    }
+
+    impl<H, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> DynHasher for Fixed<H, OUTPUT_LEN, BLOCK_LEN>
+    where
+        H: Clone + core::hash::Hasher + HasherContext<OUTPUT_LEN> + 'static,
+        H::Output: AsRef<[u8]>,
+    {
+        fn update(&mut self, bytes: &[u8]) {
+            self.hasher.write(bytes);
+        }
+
+        fn finalize_into(&mut self, output: &mut [u8]) {
+            output.copy_from_slice(HasherContext::finish(&mut self.hasher).as_ref());
+            self.reset();
+        }
+
+        fn output_len(&self) -> usize {
+            OUTPUT_LEN
+        }
+
+        fn block_len(&self) -> usize {
+            BLOCK_LEN
+        }
+
+        fn reset(&mut self) {
+            self.hasher.clone_from(&self.initial);
+        }
+
+        fn box_clone(&self) -> Box<dyn DynHasher> {
+            Box::new(self.clone())
+        }
+    }
+}
+
+extendable_output! {
+    use crate::XofReader;
+
+    /// Extendable-output function, squeezed through the reader `finish_xof` returns
+    struct Xof<H, R, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> {
+        initial: H,
+        hasher: H,
+        finish_xof: fn(&mut H) -> R,
+    }
+
+    impl<H: Clone, R, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> Xof<H, R, OUTPUT_LEN, BLOCK_LEN> {
+        fn new(hasher: H, finish_xof: fn(&mut H) -> R) -> Self {
+            Self {
+                initial: hasher.clone(),
+                hasher,
+                finish_xof,
+            }
+        }
+    }
+
+    impl<H: Clone, R, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> Clone for Xof<H, R, OUTPUT_LEN, BLOCK_LEN> {
+        fn clone(&self) -> Self {
+            Self {
+                initial: self.initial.clone(),
+                hasher: self.hasher.clone(),
+                finish_xof: self.finish_xof,
+            }
+        }
+    }
+
+    impl<H, R, const OUTPUT_LEN: usize, const BLOCK_LEN: usize> DynHasher for Xof<H, R, OUTPUT_LEN, BLOCK_LEN>
+    where
+        H: Clone + core::hash::Hasher + 'static,
+        R: XofReader + 'static,
+    {
+        fn update(&mut self, bytes: &[u8]) {
+            self.hasher.write(bytes);
+        }
+
+        fn finalize_into(&mut self, output: &mut [u8]) {
+            (self.finish_xof)(&mut self.hasher).read(output);
+            self.reset();
+        }
+
+        fn output_len(&self) -> usize {
+            OUTPUT_LEN
+        }
+
+        fn block_len(&self) -> usize {
+            BLOCK_LEN
+        }
+
+        fn reset(&mut self) {
+            self.hasher.clone_from(&self.initial);
+        }
+
+        fn box_clone(&self) -> Box<dyn DynHasher> {
+            Box::new(self.clone())
+        }
+    }
+}
+
+/// Compares the names `a` and `b`, ignoring their case, dashes, slashes and underscores.
+fn same_name(a: &str, b: &str) -> bool {
+    fn significant(name: &str) -> impl Iterator<Item = u8> + '_ {
+        name.bytes().filter(|byte| !matches!(byte, b'-' | b'/' | b'_')).map(|byte| byte.to_ascii_lowercase())
+    }
+    significant(a).eq(significant(b))
+}
@@ -0,0 +1,641 @@
+//! Known-answer tests of the algorithms RustyShield implements, run on demand through `self_test`.
+//!
+//! Each test computes one published sample, mostly the first example of the standard defining the algorithm, and
+//! compares it with the expected answer. The samples are kept small, so that a whole run takes a few milliseconds and
+//! fits on the stack of constrained targets.
+
+#[cfg(feature = "blake2")]
+use crate::Blake2b512Hasher;
+#[cfg(feature = "tuple_hash")]
+use crate::HasherContext;
+#[cfg(all(feature = "hkdf", feature = "sha256"))]
+use crate::Hkdf;
+#[cfg(all(feature = "hmac", feature = "sha256"))]
+use crate::Hmac;
+#[cfg(all(feature = "pbkdf2", feature = "sha1"))]
+use crate::Pbkdf2;
+#[cfg(feature = "scrypt")]
+use crate::Scrypt;
+#[cfg(feature = "sha1")]
+use crate::Sha1Hasher;
+#[cfg(all(feature = "pbkdf2", feature = "sha1"))]
+use crate::Sha1State;
+#[cfg(feature = "sha224")]
+use crate::Sha224Hasher;
+#[cfg(feature = "sha256")]
+use crate::Sha256Hasher;
+#[cfg(all(feature = "sha256", any(feature = "hmac", feature = "hkdf", feature = "drbg")))]
+use crate::Sha256State;
+#[cfg(feature = "sha384")]
+use crate::Sha384Hasher;
+#[cfg(feature = "sha3_224")]
+use crate::Sha3_224Hasher;
+#[cfg(feature = "sha3_256")]
+use crate::Sha3_256Hasher;
+#[cfg(feature = "sha3_384")]
+use crate::Sha3_384Hasher;
+#[cfg(feature = "sha3_512")]
+use crate::Sha3_512Hasher;
+#[cfg(feature = "sha512")]
+use crate::Sha512Hasher;
+#[cfg(feature = "sha512_224")]
+use crate::Sha512_224Hasher;
+#[cfg(feature = "sha512_256")]
+use crate::Sha512_256Hasher;
+#[cfg(feature = "shake128")]
+use crate::Shake128Hasher;
+#[cfg(feature = "shake256")]
+use crate::Shake256Hasher;
+#[cfg(feature = "argon2")]
+use crate::{Argon2, Argon2Algorithm};
+#[cfg(feature = "cshake")]
+use crate::{CShake128Hasher, CShake256Hasher};
+#[cfg(all(feature = "drbg", feature = "sha256"))]
+use crate::{DrbgMechanism, HashDrbg, HmacDrbg};
+#[cfg(feature = "kmac")]
+use crate::{Kmac128, Kmac256};
+#[cfg(feature = "kangaroo_twelve")]
+use crate::{Kt128, Kt256};
+#[cfg(feature = "parallel_hash")]
+use crate::{ParallelHash128, ParallelHash256};
+#[cfg(feature = "tuple_hash")]
+use crate::{TupleHash128, TupleHash256};
+#[cfg(feature = "turbo_shake")]
+use crate::{TurboShake128Hasher, TurboShake256Hasher};
+#[cfg(feature = "tuple_hash")]
+use core::hash::Hasher;
+#[cfg(feature = "argon2")]
+use rs_argon2::Block;
+
+/// Message of the examples of FIPS 180 and FIPS 202, and of BLAKE2 in RFC 7693
+#[cfg(any(
+    feature = "sha1",
+    feature = "sha224",
+    feature = "sha256",
+    feature = "sha384",
+    feature = "sha512",
+    feature = "sha512_224",
+    feature = "sha512_256",
+    feature = "sha3_224",
+    feature = "sha3_256",
+    feature = "sha3_384",
+    feature = "sha3_512",
+    feature = "shake128",
+    feature = "shake256",
+    feature = "blake2"
+))]
+const ABC: &[u8] = b"abc";
+/// Data of the first NIST samples of cSHAKE and KMAC
+#[cfg(any(feature = "cshake", feature = "kmac"))]
+const SAMPLE_DATA: [u8; 4] = [0x00, 0x01, 0x02, 0x03];
+/// Key of the NIST samples of KMAC, the bytes `0x40..=0x5F`
+#[cfg(feature = "kmac")]
+const KMAC_KEY: [u8; 32] = [
+    0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x5B, 0x5C, 0x5D, 0x5E, 0x5F,
+];
+/// Data of the first NIST samples of ParallelHash, three blocks of 8 bytes where block `i` starts at `0x10 * i`
+#[cfg(feature = "parallel_hash")]
+const PARALLEL_DATA: [u8; 24] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x20, 0x21, 0x22,
+    0x23, 0x24, 0x25, 0x26, 0x27,
+];
+/// Elements of the first NIST samples of TupleHash
+#[cfg(feature = "tuple_hash")]
+const TUPLE_ELEMENTS: [&[u8]; 2] = [&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15]];
+/// Byte length of the scratch memory of scrypt with `N = 16`, `r = 1` and `p = 1`
+#[cfg(feature = "scrypt")]
+const SCRYPT_SCRATCH_LEN: usize = 128 * (16 + 1 + 2);
+/// Count of blocks of memory of Argon2 with `m = 32` and `p = 4`
+#[cfg(feature = "argon2")]
+const ARGON2_BLOCK_COUNT: usize = 32;
+
+/// Outcome of the known-answer test of an algorithm.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KatOutcome {
+    /// Name of the algorithm tested
+    pub algorithm: &'static str,
+    /// Whether the algorithm returned the expected answer
+    pub passed: bool,
+}
+
+/// Known-answer test of an algorithm, whose check computes the answer and compares it with the expected one
+struct Kat {
+    algorithm: &'static str,
+    check: fn() -> bool,
+}
+
+/// `SelfTestReport` holds the outcome of the known-answer test of every algorithm, in the order `self_test` ran them.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SelfTestReport {
+    outcomes: [KatOutcome; KATS.len()],
+}
+
+impl SelfTestReport {
+    /// Returns whether every algorithm returned its expected answer.
+    pub fn passed(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.passed)
+    }
+
+    /// Returns the outcomes of all the known-answer tests.
+    pub fn outcomes(&self) -> &[KatOutcome] {
+        &self.outcomes
+    }
+
+    /// Returns the outcomes of the known-answer tests that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &KatOutcome> {
+        self.outcomes.iter().filter(|outcome| !outcome.passed)
+    }
+}
+
+/// Runs the known-answer test of every algorithm, and reports which ones returned their expected answer.
+///
+/// Deployments that must not operate with a faulty implementation, as FIPS 140 requires, call it at startup and stop
+/// unless `SelfTestReport::passed` holds.
+///
+/// # Example
+///
+/// ```rust
+/// let report = rs_shield::self_test();
+/// assert!(report.passed());
+/// assert!(report.outcomes().iter().any(|outcome| outcome.algorithm == "SHA-256"));
+/// assert_eq!(report.failures().count(), 0);
+/// ```
+pub fn self_test() -> SelfTestReport {
+    let mut outcomes = [KatOutcome {
+        algorithm: "",
+        passed: false,
+    }; KATS.len()];
+    for (outcome, kat) in outcomes.iter_mut().zip(KATS.iter()) {
+        *outcome = KatOutcome {
+            algorithm: kat.algorithm,
+            passed: (kat.check)(),
+        };
+    }
+
+    SelfTestReport {
+        outcomes,
+    }
+}
+
+const KATS: &[Kat] = &[
+    #[cfg(feature = "sha1")]
+    Kat {
+        algorithm: "SHA-1",
+        check: || digests_to(Sha1Hasher::default(), ABC, "a9993e364706816aba3e25717850c26c9cd0d89d"),
+    },
+    #[cfg(feature = "sha224")]
+    Kat {
+        algorithm: "SHA-224",
+        check: || digests_to(Sha224Hasher::default(), ABC, "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"),
+    },
+    #[cfg(feature = "sha256")]
+    Kat {
+        algorithm: "SHA-256",
+        check: || {
+            digests_to(Sha256Hasher::default(), ABC, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        },
+    },
+    #[cfg(feature = "sha384")]
+    Kat {
+        algorithm: "SHA-384",
+        check: || {
+            digests_to(
+                Sha384Hasher::default(),
+                ABC,
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7",
+            )
+        },
+    },
+    #[cfg(feature = "sha512")]
+    Kat {
+        algorithm: "SHA-512",
+        check: || {
+            digests_to(
+                Sha512Hasher::default(),
+                ABC,
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            )
+        },
+    },
+    #[cfg(feature = "sha512_224")]
+    Kat {
+        algorithm: "SHA-512/224",
+        check: || {
+            digests_to(Sha512_224Hasher::default(), ABC, "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa")
+        },
+    },
+    #[cfg(feature = "sha512_256")]
+    Kat {
+        algorithm: "SHA-512/256",
+        check: || {
+            digests_to(
+                Sha512_256Hasher::default(),
+                ABC,
+                "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23",
+            )
+        },
+    },
+    #[cfg(feature = "sha3_224")]
+    Kat {
+        algorithm: "SHA3-224",
+        check: || {
+            digests_to(Sha3_224Hasher::default(), ABC, "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf")
+        },
+    },
+    #[cfg(feature = "sha3_256")]
+    Kat {
+        algorithm: "SHA3-256",
+        check: || {
+            digests_to(
+                Sha3_256Hasher::default(),
+                ABC,
+                "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            )
+        },
+    },
+    #[cfg(feature = "sha3_384")]
+    Kat {
+        algorithm: "SHA3-384",
+        check: || {
+            digests_to(
+                Sha3_384Hasher::default(),
+                ABC,
+                "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25",
+            )
+        },
+    },
+    #[cfg(feature = "sha3_512")]
+    Kat {
+        algorithm: "SHA3-512",
+        check: || {
+            digests_to(
+                Sha3_512Hasher::default(),
+                ABC,
+                "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+                10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+            )
+        },
+    },
+    #[cfg(feature = "shake128")]
+    Kat {
+        algorithm: "SHAKE128",
+        check: || {
+            digests_to(
+                Shake128Hasher::<32>::default(),
+                ABC,
+                "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8",
+            )
+        },
+    },
+    #[cfg(feature = "shake256")]
+    Kat {
+        algorithm: "SHAKE256",
+        check: || {
+            digests_to(
+                Shake256Hasher::<64>::default(),
+                ABC,
+                "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739\
+                d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4",
+            )
+        },
+    },
+    #[cfg(feature = "cshake")]
+    Kat {
+        algorithm: "cSHAKE128",
+        check: || {
+            digests_to(
+                CShake128Hasher::<32>::new(b"", b"Email Signature"),
+                &SAMPLE_DATA,
+                "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5",
+            )
+        },
+    },
+    #[cfg(feature = "cshake")]
+    Kat {
+        algorithm: "cSHAKE256",
+        check: || {
+            digests_to(
+                CShake256Hasher::<64>::new(b"", b"Email Signature"),
+                &SAMPLE_DATA,
+                "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+                64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c",
+            )
+        },
+    },
+    #[cfg(feature = "turbo_shake")]
+    Kat {
+        algorithm: "TurboSHAKE128",
+        check: || {
+            digests_to(
+                TurboShake128Hasher::<32>::default(),
+                b"",
+                "1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c",
+            )
+        },
+    },
+    #[cfg(feature = "turbo_shake")]
+    Kat {
+        algorithm: "TurboSHAKE256",
+        check: || {
+            digests_to(
+                TurboShake256Hasher::<64>::default(),
+                b"",
+                "367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db\
+                11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0",
+            )
+        },
+    },
+    #[cfg(feature = "kangaroo_twelve")]
+    Kat {
+        algorithm: "KT128",
+        check: || {
+            digests_to(Kt128::<32>::new(b""), b"", "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5")
+        },
+    },
+    #[cfg(feature = "kangaroo_twelve")]
+    Kat {
+        algorithm: "KT256",
+        check: || {
+            digests_to(
+                Kt256::<64>::new(b""),
+                b"",
+                "b23d2e9cea9f4904e02bec06817fc10ce38ce8e93ef4c89e6537076af8646404\
+                e3e8b68107b8833a5d30490aa33482353fd4adc7148ecb782855003aaebde4a9",
+            )
+        },
+    },
+    #[cfg(feature = "parallel_hash")]
+    Kat {
+        algorithm: "ParallelHash128",
+        check: || {
+            digests_to(
+                ParallelHash128::<32>::new(8, b""),
+                &PARALLEL_DATA,
+                "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5",
+            )
+        },
+    },
+    #[cfg(feature = "parallel_hash")]
+    Kat {
+        algorithm: "ParallelHash256",
+        check: || {
+            digests_to(
+                ParallelHash256::<64>::new(8, b""),
+                &PARALLEL_DATA,
+                "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c45110553\
+                1b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429",
+            )
+        },
+    },
+    #[cfg(feature = "tuple_hash")]
+    Kat {
+        algorithm: "TupleHash128",
+        check: || {
+            let mut tuple_hash = TupleHash128::<32>::new(b"");
+            TUPLE_ELEMENTS.iter().for_each(|element| tuple_hash.write(element));
+            matches(
+                HasherContext::finish(&mut tuple_hash).as_ref(),
+                "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1",
+            )
+        },
+    },
+    #[cfg(feature = "tuple_hash")]
+    Kat {
+        algorithm: "TupleHash256",
+        check: || {
+            let mut tuple_hash = TupleHash256::<64>::new(b"");
+            TUPLE_ELEMENTS.iter().for_each(|element| tuple_hash.write(element));
+            matches(
+                HasherContext::finish(&mut tuple_hash).as_ref(),
+                "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec6073\
+                11ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194",
+            )
+        },
+    },
+    #[cfg(feature = "kmac")]
+    Kat {
+        algorithm: "KMAC128",
+        check: || {
+            let tag = Kmac128::<32>::digest(&KMAC_KEY, &SAMPLE_DATA, b"");
+            matches(tag.as_ref(), "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e")
+        },
+    },
+    #[cfg(feature = "kmac")]
+    Kat {
+        algorithm: "KMAC256",
+        check: || {
+            let tag = Kmac256::<64>::digest(&KMAC_KEY, &SAMPLE_DATA, b"My Tagged Application");
+            matches(
+                tag.as_ref(),
+                "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+                f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd",
+            )
+        },
+    },
+    #[cfg(feature = "blake2")]
+    Kat {
+        algorithm: "BLAKE2b-512",
+        check: || {
+            digests_to(
+                Blake2b512Hasher::default(),
+                ABC,
+                "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+                7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+            )
+        },
+    },
+    #[cfg(all(feature = "hmac", feature = "sha256"))]
+    Kat {
+        algorithm: "HMAC-SHA-256",
+        check: || {
+            let tag = Hmac::<Sha256State, 32>::digest(b"Jefe", b"what do ya want for nothing?");
+            matches(tag.as_ref(), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+        },
+    },
+    #[cfg(all(feature = "hkdf", feature = "sha256"))]
+    Kat {
+        algorithm: "HKDF-SHA-256",
+        check: || {
+            let salt = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C];
+            let info = [0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8, 0xF9];
+            let mut okm = [0u8; 42];
+            Hkdf::<Sha256State, 32>::new(&salt, &[0x0B; 22]).expand(&info, &mut okm).is_ok()
+                && matches(&okm, "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")
+        },
+    },
+    #[cfg(all(feature = "pbkdf2", feature = "sha1"))]
+    Kat {
+        algorithm: "PBKDF2-SHA-1",
+        check: || {
+            let mut okm = [0u8; 20];
+            Pbkdf2::<Sha1State, 20>::new(b"password").derive(b"salt", 2, &mut okm).is_ok()
+                && matches(&okm, "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957")
+        },
+    },
+    #[cfg(feature = "scrypt")]
+    Kat {
+        algorithm: "scrypt",
+        check: || {
+            let mut scratch = [0u8; SCRYPT_SCRATCH_LEN];
+            let mut okm = [0u8; 64];
+            Scrypt::new(4, 1, 1).and_then(|scrypt| scrypt.derive_with_scratch(b"", b"", &mut scratch, &mut okm)).is_ok()
+                && matches(
+                    &okm,
+                    "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+                    fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906",
+                )
+        },
+    },
+    #[cfg(feature = "argon2")]
+    Kat {
+        algorithm: "Argon2id",
+        check: || {
+            let mut memory = [Block::default(); ARGON2_BLOCK_COUNT];
+            let mut tag = [0u8; 32];
+            Argon2::new(Argon2Algorithm::Argon2id, 32, 3, 4)
+                .and_then(|argon2| {
+                    argon2.with_secret(&[0x03; 8]).with_associated_data(&[0x04; 12]).hash_with_memory(
+                        &[0x01; 32],
+                        &[0x02; 16],
+                        &mut memory,
+                        &mut tag,
+                    )
+                })
+                .is_ok()
+                && matches(&tag, "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659")
+        },
+    },
+    #[cfg(all(feature = "drbg", feature = "sha256"))]
+    Kat {
+        algorithm: "HMAC_DRBG-SHA-256",
+        check: || {
+            let entropy_input: [u8; 32] = from_hex("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488");
+            let nonce: [u8; 16] = from_hex("659ba96c601dc69fc902940805ec0ca8");
+            let mut returned_bits = [0u8; 128];
+            HmacDrbg::<Sha256State, 32>::instantiate(&entropy_input, &nonce, &[])
+                .and_then(|mut drbg| {
+                    drbg.generate(&[], &mut returned_bits)?;
+                    drbg.generate(&[], &mut returned_bits)
+                })
+                .is_ok()
+                && matches(
+                    &returned_bits,
+                    "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89\
+                    d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1\
+                    07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668\
+                    961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8",
+                )
+        },
+    },
+    #[cfg(all(feature = "drbg", feature = "sha256"))]
+    Kat {
+        algorithm: "Hash_DRBG-SHA-256",
+        check: || {
+            let entropy_input: [u8; 32] = from_hex("23ff9d796022b6b49941cea8a1bad3b858a4e2a79639fb99697c0d2a770820d3");
+            let nonce: [u8; 16] = from_hex("67b7cb923ba0c08ba6a1a270d1767856");
+            let personalization: [u8; 32] =
+                from_hex("1698f3a03a739f8c38c9f7ad89da7620498fe9abf8529443795e659e3b5c96f4");
+            let additional_input: [u8; 32] =
+                from_hex("a2c3f2993c9b6427effdd32e9cbc907241ee7e4b1e4eec263a5c69cf181cf848");
+            let mut returned_bits = [0u8; 128];
+            HashDrbg::<Sha256State, 32>::instantiate(&entropy_input, &nonce, &personalization)
+                .and_then(|mut drbg| drbg.generate(&additional_input, &mut returned_bits))
+                .is_ok()
+                && matches(
+                    &returned_bits,
+                    "ffb7d9ffb39713398b21eb25bbc4d66b20b8a8ee1003c350bb813c4023b28fd2\
+                    0282236ecfff651ec73833fe4a4c9b4bcae553723a82538c3e02bfc54e97dbd3\
+                    2e1932045b303dc43a961653f36e8c81bfc44e8fd41e03f912abe6900b350ae1\
+                    a34d8e3bede40b9bfacfac7a136caf062e79b3e264cd02df73a3aafe3af190ac",
+                )
+        },
+    },
+];
+
+/// Returns whether `hasher`, fed with `message`, digests to the bytes `expected` spells in hexadecimal.
+#[cfg(any(
+    feature = "sha1",
+    feature = "sha224",
+    feature = "sha256",
+    feature = "sha384",
+    feature = "sha512",
+    feature = "sha512_224",
+    feature = "sha512_256",
+    feature = "sha3_224",
+    feature = "sha3_256",
+    feature = "sha3_384",
+    feature = "sha3_512",
+    feature = "shake128",
+    feature = "shake256",
+    feature = "cshake",
+    feature = "turbo_shake",
+    feature = "kangaroo_twelve",
+    feature = "parallel_hash",
+    feature = "blake2"
+))]
+fn digests_to<H, const OUTPUT_SIZE: usize>(mut hasher: H, message: &[u8], expected: &str) -> bool
+where
+    H: core::hash::Hasher + crate::HasherContext<OUTPUT_SIZE>,
+    H::Output: AsRef<[u8]>,
+{
+    hasher.write(message);
+    matches(crate::HasherContext::finish(&mut hasher).as_ref(), expected)
+}
+
+// Keeps the items only when the known-answer test of some algorithm is enabled
+macro_rules! any_kat {
+    ($($item:item)*) => {
+        $(
+            #[cfg(any(
+                feature = "sha1",
+                feature = "sha224",
+                feature = "sha256",
+                feature = "sha384",
+                feature = "sha512",
+                feature = "sha512_224",
+                feature = "sha512_256",
+                feature = "sha3_224",
+                feature = "sha3_256",
+                feature = "sha3_384",
+                feature = "sha3_512",
+                feature = "shake128",
+                feature = "shake256",
+                feature = "cshake",
+                feature = "turbo_shake",
+                feature = "kangaroo_twelve",
+                feature = "parallel_hash",
+                feature = "tuple_hash",
+                feature = "kmac",
+                feature = "blake2",
+                feature = "scrypt",
+                feature = "argon2"
+            ))]
+            $item
+        )*
+    };
+}
+
+any_kat! {
+    /// Returns whether `bytes` are those `expected` spells in hexadecimal.
+    fn matches(bytes: &[u8], expected: &str) -> bool {
+        expected.len() == 2 * bytes.len()
+            && bytes.iter().zip(expected.as_bytes().chunks_exact(2)).all(|(&byte, pair)| hex_byte(pair) == Some(byte))
+    }
+
+    fn hex_byte(pair: &[u8]) -> Option<u8> {
+        let digit = |digit: u8| (digit as char).to_digit(16);
+        Some((digit(pair[0])? << 4 | digit(pair[1])?) as u8)
+    }
+}
+
+/// Decodes the `N` bytes `hex` spells, which the samples above spell correctly.
+#[cfg(all(feature = "drbg", feature = "sha256"))]
+fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+        *byte = hex_byte(pair).unwrap_or_default();
+    }
+    bytes
+}
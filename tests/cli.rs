@@ -0,0 +1,23 @@
+#![cfg(feature = "std")]
+
+use std::{
+    io::{ErrorKind, Write},
+    process::{Command, Output, Stdio},
+};
+
+/// Runs `rs_shield_cli` with `args`, writing `stdin` to its standard input, and collects its output.
+///
+/// `setup` is handed the command before it is spawned, to set anything else such as its environment or its working
+/// directory.
+pub fn rs_shield_cli(args: &[&str], stdin: &[u8], setup: impl FnOnce(&mut Command)) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_rs_shield_cli"));
+    command.args(args).stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+    setup(&mut command);
+
+    let mut child = command.spawn().unwrap();
+    // The CLI may reject its arguments and exit before reading its input, closing the pipe
+    if let Err(error) = child.stdin.take().unwrap().write_all(stdin) {
+        assert_eq!(error.kind(), ErrorKind::BrokenPipe);
+    }
+    child.wait_with_output().unwrap()
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+use std::{env, fs, path::Path, process::Output};
+
+mod cli;
+
+fn rs_shield_cli_in(directory: &Path, args: &[&str]) -> Output {
+    rs_shield_cli(args, b"", |command| {
+        command.current_dir(directory);
+    })
+}
+
+fn directory_with_files(name: &str) -> std::path::PathBuf {
+    let directory = env::temp_dir().join(name);
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir(&directory).unwrap();
+    fs::write(directory.join("abc"), b"abc").unwrap();
+    fs::write(directory.join("hello world"), b"hello").unwrap();
+    directory
+}
+
+#[test]
+fn check_verifies_gnu_and_bsd_manifests() {
+    let directory = directory_with_files("rs_shield_cli_check_formats");
+    fs::write(
+        directory.join("SHA256SUMS"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  abc\n\
+         2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824 *hello world\n",
+    )
+    .unwrap();
+    fs::write(
+        directory.join("BSD"),
+        "SHA1 (abc) = a9993e364706816aba3e25717850c26c9cd0d89d\n\
+         SHA3-256(hello world) = 3338be694f50c5f338814986cdf0686453a888b84f424d792af4b9202398f392\n",
+    )
+    .unwrap();
+
+    let output = rs_shield_cli_in(&directory, &["dgst", "-c", "SHA256SUMS", "BSD"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "abc: OK\nhello world: OK\n".repeat(2));
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn check_verifies_the_manifests_dgst_generates() {
+    let directory = directory_with_files("rs_shield_cli_check_round_trip");
+    for args in [
+        ["-sha512-256", "-tag"],
+        ["-sha256", "-tag"],
+        ["-blake2b512", "-tag"],
+        ["-kt128", "-r"],
+        ["-blake2b256", "-hex"],
+    ] {
+        let manifest = rs_shield_cli_in(&directory, &["dgst", args[0], args[1], "abc", "hello world"]).stdout;
+        fs::write(directory.join("MANIFEST"), manifest).unwrap();
+
+        let output = rs_shield_cli_in(&directory, &["dgst", "-c", args[0], "MANIFEST"]);
+        assert!(output.status.success(), "{:?}", args);
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), "abc: OK\nhello world: OK\n");
+    }
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn check_verifies_the_escaped_names_dgst_generates() {
+    let directory = directory_with_files("rs_shield_cli_check_escaped_names");
+    fs::write(directory.join("new\nline"), b"abc").unwrap();
+    fs::write(directory.join("back\\slash"), b"abc").unwrap();
+    for layout in ["-r", "-tag"] {
+        let manifest = rs_shield_cli_in(&directory, &["dgst", layout, "new\nline", "back\\slash"]).stdout;
+        assert_eq!(String::from_utf8(manifest.clone()).unwrap().lines().count(), 2);
+        assert!(manifest.starts_with(b"\\"));
+        fs::write(directory.join("MANIFEST"), manifest).unwrap();
+
+        let output = rs_shield_cli_in(&directory, &["dgst", "-c", "MANIFEST"]);
+        assert!(output.status.success(), "{}", layout);
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), "\\new\\nline: OK\n\\back\\\\slash: OK\n");
+    }
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn check_fails_on_mismatches_and_missing_files() {
+    let directory = directory_with_files("rs_shield_cli_check_failures");
+    fs::write(
+        directory.join("SHA256SUMS"),
+        "0000000000000000000000000000000000000000000000000000000000000000  abc\n\
+         2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  hello world\n\
+         2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  missing\n",
+    )
+    .unwrap();
+
+    let output = rs_shield_cli_in(&directory, &["dgst", "-c", "SHA256SUMS"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "abc: FAILED\nhello world: OK\nmissing: FAILED open or read\n"
+    );
+
+    let output = rs_shield_cli_in(&directory, &["dgst", "-c", "--quiet", "--ignore-missing", "SHA256SUMS"]);
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "abc: FAILED\n");
+
+    let output = rs_shield_cli_in(&directory, &["dgst", "-c", "--status", "SHA256SUMS"]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty() && output.stderr.is_empty());
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn check_only_fails_on_improperly_formatted_lines_with_strict() {
+    let directory = directory_with_files("rs_shield_cli_check_strict");
+    fs::write(
+        directory.join("SHA256SUMS"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  abc\nnot a checksum line\n",
+    )
+    .unwrap();
+
+    let output = rs_shield_cli_in(&directory, &["dgst", "-c", "SHA256SUMS"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("1 line is improperly formatted"));
+
+    let output = rs_shield_cli_in(&directory, &["dgst", "-c", "--strict", "SHA256SUMS"]);
+    assert!(!output.status.success());
+
+    fs::write(directory.join("EMPTY"), "not a checksum line\n").unwrap();
+    let output = rs_shield_cli_in(&directory, &["dgst", "-c", "EMPTY"]);
+    assert!(!output.status.success());
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+use std::{env, fs};
+
+mod cli;
+
+#[test]
+fn dgst_hashes_files_with_the_chosen_digest_and_encoding() {
+    let path = env::temp_dir().join("rs_shield_cli_dgst_abc");
+    fs::write(&path, b"abc").unwrap();
+    let path = path.to_str().unwrap();
+
+    let output = rs_shield_cli(&["dgst", path], b"", |_| {});
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("SHA2-256({}) = ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\n", path)
+    );
+
+    let output = rs_shield_cli(&["dgst", "-sha3-512", "-base64", path, path], b"", |_| {});
+    let expected = format!(
+        "SHA3-512({}) = t1GFCxpXFopWk82SS2sJbgj2IYJ0RPcNiE9dAkDScS4Q4RbpGSrzyRp+xXZH45NAVzQLTPQI1aVlkvgnTuxT8A==\n",
+        path
+    );
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected.repeat(2));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn dgst_streams_the_standard_input() {
+    let output = rs_shield_cli(&["dgst", "-sha1", "-r"], b"abc", |_| {});
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "a9993e364706816aba3e25717850c26c9cd0d89d *-\n");
+
+    // Larger than the buffer the input is read through
+    let message = vec![b'a'; 1_000_000];
+    let output = rs_shield_cli(&["dgst", "-sha256", "-binary", "-"], &message, |_| {});
+    assert_eq!(
+        output.stdout,
+        [
+            0xcd, 0xc7, 0x6e, 0x5c, 0x99, 0x14, 0xfb, 0x92, 0x81, 0xa1, 0xc7, 0xe2, 0x84, 0xd7, 0x3e, 0x67, 0xf1, 0x80,
+            0x9a, 0x48, 0xa4, 0x97, 0x20, 0x0e, 0x04, 0x6d, 0x39, 0xcc, 0xc7, 0x11, 0x2c, 0xd0
+        ]
+    );
+}
+
+#[test]
+fn dgst_tags_lines_with_the_names_of_coreutils() {
+    let output = rs_shield_cli(&["dgst", "-sha256", "-tag"], b"abc", |_| {});
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "SHA256 (-) = ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\n"
+    );
+
+    let output = rs_shield_cli(&["dgst", "-blake2b256", "-tag"], b"abc", |_| {});
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "BLAKE2b-256 (-) = bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319\n"
+    );
+}
+
+#[test]
+fn dgst_reads_extendable_outputs_of_the_requested_length() {
+    let output = rs_shield_cli(&["dgst", "-shake128", "-xoflen", "16"], b"abc", |_| {});
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "SHAKE-128(stdin) = 5881092dd818bf5cf8a3ddb793fbcba7\n");
+
+    let output = rs_shield_cli(&["dgst", "-sha256", "-xoflen", "16"], b"abc", |_| {});
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn dgst_reports_unreadable_files_and_goes_on() {
+    let output = rs_shield_cli(&["dgst", "-sha1", "/nonexistent/rs_shield_cli", "-"], b"abc", |_| {});
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("/nonexistent/rs_shield_cli"));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "SHA1(stdin) = a9993e364706816aba3e25717850c26c9cd0d89d\n");
+}
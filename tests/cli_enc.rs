@@ -0,0 +1,65 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+use std::{env, fs};
+
+mod cli;
+
+#[test]
+fn enc_derives_the_key_and_iv_as_openssl_does() {
+    let output = rs_shield_cli(&["enc", "-aes-256-cbc", "-k", "secret", "-S", "0102030405060708", "-P"], b"", |_| {});
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "salt=0102030405060708\n\
+         key=03B375940CB96C16F84FAA87F5EF39CC0BC7066CCD3E14456D9D74E438E35832\n\
+         iv =904AEBC6E588FDB49FD15806BB4FEE6F\n"
+    );
+
+    let args = ["enc", "-aes-128-cbc", "-pbkdf2", "-iter", "1000", "-pass", "str:secret", "-S", "0102030405060708"];
+    let output = rs_shield_cli(&[&args[..], &["-md", "sha512", "-P"]].concat(), b"", |_| {});
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "salt=0102030405060708\nkey=D8F9DA2363B6C40DEC3476125AA34924\niv =34971A5C69EA3DEAD7A7446A90314D6B\n"
+    );
+}
+
+#[test]
+fn enc_draws_a_fresh_salt_from_the_operating_system() {
+    let salt = || {
+        let output = rs_shield_cli(&["enc", "-aes-256-cbc", "-k", "secret", "-P"], b"", |_| {});
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap().lines().next().unwrap().to_string()
+    };
+
+    let (first, second) = (salt(), salt());
+    assert_eq!(first.len(), "salt=".len() + 16);
+    assert_ne!(first, second);
+}
+
+#[test]
+fn enc_reads_the_salt_of_salted_files_when_decrypting() {
+    let path = env::temp_dir().join("rs_shield_cli_enc_salted");
+    fs::write(&path, "U2FsdGVkX18Q4hGFfpBhTqsXzwgUqhEfJDumLW47SHo=\n").unwrap();
+    let path = path.to_str().unwrap();
+
+    let output =
+        rs_shield_cli(&["enc", "-d", "-aes-256-cbc", "-k", "secret", "-a", "-pbkdf2", "-in", path, "-P"], b"", |_| {});
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "salt=10E211857E90614E\n\
+         key=47865CB6AC6D78E27984D2046E35664C514720F49887F1F075E9AA95FFBF44BE\n\
+         iv =C9DA282DB4EDB15344491671A02D8F9E\n"
+    );
+
+    fs::write(path, "not salted").unwrap();
+    let output = rs_shield_cli(&["enc", "-d", "-aes-256-cbc", "-k", "secret", "-in", path, "-P"], b"", |_| {});
+    assert!(!output.status.success());
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn enc_refuses_to_encrypt_until_the_ciphers_land() {
+    let output = rs_shield_cli(&["enc", "-chacha20-poly1305", "-e", "-k", "secret"], b"", |_| {});
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
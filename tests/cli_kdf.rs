@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+
+mod cli;
+
+#[test]
+fn kdf_derives_hkdf_keys_of_rfc_5869() {
+    let output = rs_shield_cli(
+        &[
+            "kdf",
+            "-hkdf",
+            "sha256",
+            "-key",
+            "hex:0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "-salt",
+            "hex:000102030405060708090a0b0c",
+            "-info",
+            "hex:f0f1f2f3f4f5f6f7f8f9",
+            "-length",
+            "42",
+        ],
+        b"",
+        |_| {},
+    );
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865\n"
+    );
+}
+
+#[test]
+fn kdf_derives_pbkdf2_keys_of_rfc_6070() {
+    let args = ["kdf", "-pbkdf2", "sha1", "-key", "str:password", "-salt", "str:salt", "-iter", "4096"];
+    let output = rs_shield_cli(&args, b"", |_| {});
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "4b007901b765489abead49d926f721d065a429c1\n");
+
+    let output = rs_shield_cli(&["kdf", "-pbkdf2", "sha1", "-key", "str:password", "-salt", "str:salt"], b"", |_| {});
+    assert!(!output.status.success());
+}
+
+#[test]
+fn kdf_derives_kmac_keys_whose_length_changes_the_whole_output() {
+    let args = ["kdf", "-kmac256", "-key", "hex:000102", "-context", "str:context", "-label", "str:label", "-binary"];
+    let output = rs_shield_cli(&args, b"", |_| {});
+    assert_eq!(output.stdout.len(), 64);
+
+    let output_16 = rs_shield_cli(&[&args[..], &["-length", "16"]].concat(), b"", |_| {});
+    assert_eq!(output_16.stdout.len(), 16);
+    assert_ne!(output_16.stdout[..], output.stdout[..16]);
+
+    let output = rs_shield_cli(&["kdf", "-kmac256", "-key", "hex:000102", "-salt", "str:salt"], b"", |_| {});
+    assert!(!output.status.success());
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+use std::{env, fs};
+
+mod cli;
+
+const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn mac_reads_the_key_from_hex_a_file_or_the_environment() {
+    let expected = "HMAC-SHA2-256(stdin) = f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8\n";
+
+    let output = rs_shield_cli(&["mac", "-hmac", "sha256", "-key", "hex:6b6579"], FOX, |_| {});
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+
+    let output = rs_shield_cli(&["mac", "-hmac", "sha256", "-key", "env:MAC_KEY"], FOX, |command| {
+        command.env("MAC_KEY", "key");
+    });
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+
+    let key_path = env::temp_dir().join("rs_shield_cli_mac_key");
+    fs::write(&key_path, b"key").unwrap();
+    let key = format!("file:{}", key_path.to_str().unwrap());
+    let output = rs_shield_cli(&["mac", "-hmac", "SHA2-256", "-key", &key], FOX, |_| {});
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+    fs::remove_file(key_path).unwrap();
+}
+
+#[test]
+fn mac_supports_every_hmac_digest_and_kmac() {
+    let output = rs_shield_cli(&["mac", "-hmac", "sha3-512", "-key", "str:key", "-r"], FOX, |_| {});
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "237a35049c40b3ef5ddd960b3dc893d8284953b9a4756611b1b61bffcf53edd9\
+         79f93547db714b06ef0a692062c609b70208ab8d4a280ceee40ed8100f293063 *-\n"
+    );
+
+    let key = "hex:404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f";
+    let args = ["mac", "-kmac128", "-key", key, "-customization", "str:My Tagged Application"];
+    let output = rs_shield_cli(&args, &[0x00, 0x01, 0x02, 0x03], |_| {});
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "KMAC128(stdin) = 3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5\n"
+    );
+}
+
+#[test]
+fn mac_refuses_missing_keys_and_unset_variables() {
+    let output = rs_shield_cli(&["mac", "-hmac", "sha256"], FOX, |_| {});
+    assert!(!output.status.success());
+
+    let output = rs_shield_cli(&["mac", "-hmac", "sha256", "-key", "env:RS_SHIELD_CLI_UNSET_KEY"], FOX, |_| {});
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+use std::{env, fs, path::PathBuf};
+
+mod cli;
+
+/// Creates an empty directory under the temporary one, holding the tree to scan and its baseline.
+fn scratch(name: &str) -> (PathBuf, String) {
+    let root = env::temp_dir().join(name);
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("tree/logs")).unwrap();
+    let database = root.join("baseline").to_str().unwrap().to_string();
+    (root, database)
+}
+
+#[test]
+fn scan_check_accepts_an_unchanged_tree() {
+    let (root, database) = scratch("rs_shield_cli_scan_unchanged");
+    let tree = root.join("tree");
+    fs::write(tree.join("a"), "abc").unwrap();
+    let tree = tree.to_str().unwrap();
+
+    assert!(rs_shield_cli(&["scan", "init", "-db", &database, tree], b"", |_| {}).status.success());
+    let baseline = fs::read_to_string(&database).unwrap();
+    assert!(baseline.starts_with("# rs_shield_cli scan v1 sha256\n"));
+    assert!(baseline.contains("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad 3 "));
+
+    let output = rs_shield_cli(&["scan", "check", "-db", &database, tree], b"", |_| {});
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "0 added, 0 removed, 0 changed\n");
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn scan_check_reports_added_removed_and_changed_files() {
+    let (root, database) = scratch("rs_shield_cli_scan_changes");
+    let tree = root.join("tree");
+    fs::write(tree.join("kept"), "abc").unwrap();
+    fs::write(tree.join("edited"), "abc").unwrap();
+    fs::write(tree.join("removed"), "abc").unwrap();
+    let tree_path = tree.to_str().unwrap();
+    assert!(rs_shield_cli(&["scan", "init", "-sha3-256", "-db", &database, tree_path], b"", |_| {}).status.success());
+
+    fs::write(tree.join("edited"), "abd").unwrap();
+    fs::remove_file(tree.join("removed")).unwrap();
+    fs::write(tree.join("logs/added"), "").unwrap();
+    let output = rs_shield_cli(&["scan", "check", "-db", &database, tree_path], b"", |_| {});
+    assert!(!output.status.success());
+    let report = String::from_utf8(output.stdout).unwrap();
+    assert!(report.contains("added: logs/added\n"));
+    assert!(report.contains("changed: edited (digest)\n"));
+    assert!(report.contains("removed: removed\n"));
+    assert!(!report.contains("kept"));
+    assert!(report.ends_with("1 added, 1 removed, 1 changed\n"));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn scan_check_applies_the_excludes_of_the_baseline() {
+    let (root, database) = scratch("rs_shield_cli_scan_excludes");
+    let tree = root.join("tree");
+    fs::write(tree.join("a"), "abc").unwrap();
+    fs::write(tree.join("logs/today.log"), "").unwrap();
+    let tree_path = tree.to_str().unwrap();
+    assert!(rs_shield_cli(&["scan", "init", "-exclude", "logs", "-db", &database, tree_path], b"", |_| {})
+        .status
+        .success());
+    assert!(!fs::read_to_string(&database).unwrap().contains("today.log"));
+
+    fs::write(tree.join("logs/tomorrow.log"), "").unwrap();
+    fs::write(tree.join("a.tmp"), "").unwrap();
+    let output = rs_shield_cli(&["scan", "check", "-db", &database, "-exclude", "*.tmp", tree_path], b"", |_| {});
+    assert!(output.status.success());
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn scan_check_needs_a_readable_baseline() {
+    let (root, database) = scratch("rs_shield_cli_scan_missing");
+    let tree = root.join("tree");
+    let output = rs_shield_cli(&["scan", "check", tree.to_str().unwrap()], b"", |_| {});
+    assert!(!output.status.success());
+
+    let output = rs_shield_cli(&["scan", "check", "-db", &database, tree.to_str().unwrap()], b"", |_| {});
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().starts_with("rs_shield_cli: scan: "));
+    fs::remove_dir_all(root).unwrap();
+}
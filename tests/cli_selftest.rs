@@ -0,0 +1,26 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+
+mod cli;
+
+#[test]
+fn selftest_passes_every_known_answer_test() {
+    let output = rs_shield_cli(&["selftest"], b"", |_| {});
+    assert!(output.status.success());
+    let report = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(report.lines().count(), rs_shield::self_test().outcomes().len());
+    assert!(report.lines().all(|line| line.ends_with(": OK")));
+    assert!(report.contains("SHA-1: OK\n"));
+    assert!(report.contains("Argon2id: OK\n"));
+}
+
+#[test]
+fn selftest_prints_nothing_when_quiet_and_passing() {
+    let output = rs_shield_cli(&["selftest", "-quiet"], b"", |_| {});
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let output = rs_shield_cli(&["selftest", "-fast"], b"", |_| {});
+    assert!(!output.status.success());
+}
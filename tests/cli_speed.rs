@@ -0,0 +1,30 @@
+#![cfg(feature = "std")]
+
+use cli::rs_shield_cli;
+
+mod cli;
+
+#[test]
+fn speed_prints_a_table_of_the_named_algorithms() {
+    let args = ["speed", "-seconds", "0.001", "sha256", "hmac-sha3-256", "kmac128"];
+    let output = rs_shield_cli(&args, b"", |_| {});
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[1].starts_with("type") && lines[1].ends_with("16384 bytes"));
+    for (line, name) in lines[2..].iter().zip(["sha256", "hmac-sha3-256", "kmac128"]) {
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        assert_eq!(columns[0], name);
+        assert_eq!(columns.len(), 7);
+        assert!(columns[1..].iter().all(|column| column.ends_with('k')));
+    }
+}
+
+#[test]
+fn speed_refuses_unknown_algorithms() {
+    let output = rs_shield_cli(&["speed", "md6"], b"", |_| {});
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
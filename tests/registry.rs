@@ -0,0 +1,81 @@
+#![cfg(feature = "alloc")]
+
+use rs_shield::{DynHasher, HashFunction, HasherContext, Sha256Hasher, Shake128Hasher, XofReader, HASH_FUNCTIONS};
+use std::hash::Hasher;
+
+fn finalize(hasher: &mut Box<dyn DynHasher>) -> Vec<u8> {
+    let mut digest = vec![0u8; hasher.output_len()];
+    hasher.finalize_into(&mut digest);
+    digest
+}
+
+#[test]
+fn every_registered_function_is_found_by_its_names_and_oid() {
+    for function in HASH_FUNCTIONS {
+        assert_eq!(HashFunction::find(function.name).unwrap().name, function.name);
+        assert_eq!(HashFunction::find(&function.name.to_lowercase()).unwrap().name, function.name);
+        for alias in function.aliases {
+            assert_eq!(HashFunction::find(alias).unwrap().name, function.name);
+        }
+        if let Some(oid) = function.oid {
+            assert_eq!(HashFunction::find_by_oid(oid).unwrap().name, function.name);
+        }
+
+        let hasher = function.new_hasher();
+        assert_eq!(hasher.output_len(), function.output_len);
+        assert_eq!(hasher.block_len(), function.block_len);
+    }
+
+    assert_eq!(HashFunction::find("SHA2-256").unwrap().name, "SHA-256");
+    assert_eq!(HashFunction::find("sha_512_224").unwrap().name, "SHA-512/224");
+    assert!(HashFunction::find("SHA-257").is_none());
+    assert!(HashFunction::find_by_oid("2.16.840.1.101.3.4.2.13").is_none());
+}
+
+#[test]
+fn dyn_hashers_match_the_typed_hashers() {
+    let mut sha256hasher = Sha256Hasher::default();
+    sha256hasher.write(b"hello world");
+    let mut hasher = HashFunction::find_by_oid("2.16.840.1.101.3.4.2.1").unwrap().new_hasher();
+    hasher.update(b"hello ");
+    hasher.update(b"world");
+    assert_eq!(finalize(&mut hasher), HasherContext::finish(&mut sha256hasher).as_ref());
+
+    let mut shake128hasher = Shake128Hasher::<32>::default();
+    shake128hasher.write(b"hello world");
+    let mut expected = [0u8; 100];
+    shake128hasher.finish_xof().read(&mut expected);
+    let mut hasher = HashFunction::find("SHAKE128").unwrap().new_hasher();
+    hasher.update(b"hello world");
+    let mut digest = [0u8; 100];
+    hasher.finalize_into(&mut digest);
+    assert_eq!(digest, expected);
+}
+
+#[test]
+fn dyn_hashers_reset_and_clone() {
+    for function in HASH_FUNCTIONS {
+        let mut hasher = function.new_hasher();
+        hasher.update(b"abc");
+        let digest = finalize(&mut hasher);
+
+        hasher.update(b"abc");
+        assert_eq!(finalize(&mut hasher), digest, "{} is not reset once finalized", function.name);
+
+        hasher.update(b"discarded");
+        hasher.reset();
+        hasher.update(b"a");
+        let mut clone = hasher.clone();
+        hasher.update(b"bc");
+        clone.update(b"bc");
+        assert_eq!(finalize(&mut hasher), digest, "{} is not reset by reset", function.name);
+        assert_eq!(finalize(&mut clone), digest, "{} is not cloned in its state", function.name);
+    }
+}
+
+#[test]
+#[should_panic]
+fn fixed_length_dyn_hashers_refuse_other_lengths() {
+    let mut hasher = HashFunction::find("SHA-1").unwrap().new_hasher();
+    hasher.finalize_into(&mut [0u8; 32]);
+}
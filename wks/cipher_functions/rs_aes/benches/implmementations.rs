@@ -7,6 +7,7 @@ pub struct FirstImplementationOfGF2ToThe8(NBitWord<u8>);
 impl Add for FirstImplementationOfGF2ToThe8 {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, rhs: Self) -> Self::Output {
         FirstImplementationOfGF2ToThe8(self.0 ^ rhs.0)
     }
@@ -68,6 +69,7 @@ impl SecondImplementationOfGF2ToThe8 {
 impl Add for SecondImplementationOfGF2ToThe8 {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, rhs: Self) -> Self::Output {
         SecondImplementationOfGF2ToThe8(self.0 ^ rhs.0)
     }
@@ -0,0 +1,244 @@
+//! # SpongeWrap `rs_sponge_wrap` - Authenticated encryption over the Keccak duplex
+//!
+//! SpongeWrap is an authenticated encryption mode with associated data, introduced by Bertoni, Daemen, Peeters and
+//! Van Assche in "Duplexing the sponge", built on top of a duplex object. Each message is made of a header, which is
+//! authenticated but left in the clear, and a body, which is both encrypted and authenticated. The key stream for a
+//! body block is the duplex output obtained when absorbing the previous block, and the tag is squeezed once the whole
+//! body has been absorbed.
+//!
+//! This crate instantiates SpongeWrap on any width of the Keccak permutation handled by `rs_internal_state`, running
+//! the full Keccak-f of that width unless a lower number of rounds is given. The smaller permutations, Keccak-f\[400\]
+//! and Keccak-f\[800\], are a good fit for constrained devices, while Keccak-f\[1600\] gives the largest security
+//! margin.
+//!
+//! ## Usage
+//!
+//! Both parties create a SpongeWrap session with the same secret key. Messages are then wrapped and unwrapped in the
+//! same order, as each of them updates the session state. Since the session is deterministic, the key must not be used
+//! for more than one session unless a unique nonce is given as the header of its first message.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use SpongeWrap over Keccak-f\[800\] in Rust:
+//!
+//! ```rust
+//! # use rs_sponge_wrap::SpongeWrap;
+//! let key = b"a secret key of enough length";
+//! let mut sender = SpongeWrap::<u32, 68>::new(key);
+//! let mut receiver = SpongeWrap::<u32, 68>::new(key);
+//!
+//! let mut body = *b"Attack at dawn";
+//! let mut tag = [0u8; 16];
+//! sender.wrap(b"nonce: 1", &mut body, &mut tag);
+//! assert_ne!(&body, b"Attack at dawn");
+//!
+//! assert!(receiver.unwrap(b"nonce: 1", &mut body, &tag).is_ok());
+//! assert_eq!(&body, b"Attack at dawn");
+//! ```
+//!
+//! ## Use Cases
+//!
+//! SpongeWrap is suited for the following contexts:
+//!
+//! - Lightweight authenticated encryption on constrained devices, through the smaller Keccak permutations.
+//! - Secure channels, where a session of sequential messages is kept on both ends.
+//!
+
+#![no_std]
+
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
+use rs_constant_time::{Choice, ConstantTimeEq};
+pub use rs_internal_state::Zeroize;
+use rs_internal_state::{KeccakDuplex, KECCAK_F_ROUNDS};
+use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
+
+/// Delimited suffix carrying a `0` frame bit
+const FRAME_BIT_0: u8 = 0x02;
+/// Delimited suffix carrying a `1` frame bit
+const FRAME_BIT_1: u8 = 0x03;
+
+/// `InvalidTag` is the error returned by `SpongeWrap::unwrap` when the tag does not authenticate the message.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidTag;
+
+impl Display for InvalidTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("authentication tag mismatch")
+    }
+}
+
+/// `SpongeWrap<T, const RATE: usize, const ROUNDS: usize>` is a SpongeWrap session over the Keccak duplex with lanes of
+/// type `T`, a rate of `RATE` bytes and `ROUNDS` rounds of the permutation. As for `KeccakDuplex`, the default
+/// `ROUNDS` runs the full Keccak-f of the lane width.
+///
+/// Data is processed in blocks of `RATE - 1` bytes, each one followed by a frame bit and the padding. The frame bits
+/// of header blocks are `0` but for the last one, which gets a `1`, while those of key and body blocks are `1` but for
+/// the last one, which gets a `0`. Empty keys, headers and bodies still take a block, and tags longer than a block are
+/// squeezed with further empty blocks framed with a `0`.
+///
+/// The duplex state is derived from the key, so it is zeroized on drop, and left out of the `Debug` output.
+///
+/// ## Examples
+///
+/// Tampering with any part of the message is detected, and the body is wiped instead of being handed back:
+///
+///```rust
+/// # use rs_sponge_wrap::{InvalidTag, SpongeWrap};
+/// let mut sender = SpongeWrap::<u16, 36>::new(b"key");
+/// let mut receiver = SpongeWrap::<u16, 36>::new(b"key");
+///
+/// let mut body = *b"Pay 100 to Bob";
+/// let mut tag = [0u8; 8];
+/// sender.wrap(b"", &mut body, &mut tag);
+/// body[0] ^= 0x01;
+///
+/// assert_eq!(receiver.unwrap(b"", &mut body, &tag), Err(InvalidTag));
+/// assert_eq!(body, [0u8; 14]);
+///```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct SpongeWrap<T: Default + Copy, const RATE: usize, const ROUNDS: usize = KECCAK_F_ROUNDS> {
+    duplex: KeccakDuplex<T, RATE, ROUNDS>,
+}
+
+impl<T, const RATE: usize, const ROUNDS: usize> SpongeWrap<T, RATE, ROUNDS>
+where
+    T: BitAnd
+        + BitAndAssign
+        + BitOr<NBitWord<T>, Output = NBitWord<T>>
+        + BitXor<Output = T>
+        + BitXorAssign
+        + Copy
+        + Default
+        + Not<Output = T>,
+    NBitWord<T>: From<u64> + LittleEndianBytes + Rotate + TSize<T>,
+    u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
+{
+    const BLOCK_SIZE: usize = RATE - 1;
+
+    /// Starts a new session by absorbing the secret key.
+    pub fn new(key: &[u8]) -> Self {
+        let mut sponge_wrap = Self {
+            duplex: KeccakDuplex::default(),
+        };
+
+        let blocks_count = Self::blocks_count(key);
+        for (i, key_block) in Self::blocks(key).enumerate() {
+            let frame_bit = if i + 1 < blocks_count {
+                FRAME_BIT_1
+            } else {
+                FRAME_BIT_0
+            };
+            sponge_wrap.duplex.duplexing(key_block, frame_bit, &mut []);
+        }
+
+        sponge_wrap
+    }
+
+    /// Encrypts `body` in place and writes the authentication tag of `header` and `body` into `tag`, whose length is
+    /// the tag length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` is empty.
+    pub fn wrap(&mut self, header: &[u8], body: &mut [u8], tag: &mut [u8]) {
+        assert!(!tag.is_empty(), "SpongeWrap tag cannot be empty");
+        let (mut last_block, last_block_len) = self.crypt(header, body, false);
+
+        let mut input = &last_block[..last_block_len];
+        for tag_block in tag.chunks_mut(Self::BLOCK_SIZE) {
+            self.duplex.duplexing(input, FRAME_BIT_0, tag_block);
+            input = &[];
+        }
+        last_block.zeroize();
+    }
+
+    /// Decrypts `body` in place and checks it against `tag`, in constant time. Empty tags are always rejected.
+    ///
+    /// On an `InvalidTag` error the body is wiped. The session is then out of sync with the sender's one, and should be
+    /// dropped.
+    pub fn unwrap(&mut self, header: &[u8], body: &mut [u8], tag: &[u8]) -> Result<(), InvalidTag> {
+        let (mut last_block, last_block_len) = self.crypt(header, body, true);
+
+        let mut input = &last_block[..last_block_len];
+        let mut expected_tag_block = [0u8; RATE];
+        let mut is_valid = Choice::from(u8::from(!tag.is_empty()));
+        for tag_block in tag.chunks(Self::BLOCK_SIZE) {
+            let expected_tag_block = &mut expected_tag_block[..tag_block.len()];
+            self.duplex.duplexing(input, FRAME_BIT_0, expected_tag_block);
+            is_valid &= tag_block.ct_eq(expected_tag_block);
+            input = &[];
+        }
+        last_block.zeroize();
+
+        if !bool::from(is_valid) {
+            body.zeroize();
+            return Err(InvalidTag);
+        }
+
+        Ok(())
+    }
+
+    /// Absorbs the header and runs the key stream over the body, returning its last plaintext block, which the tag
+    /// squeezing starts from.
+    fn crypt(&mut self, header: &[u8], body: &mut [u8], decrypt: bool) -> ([u8; RATE], usize) {
+        let mut key_stream = [0u8; RATE];
+        let mut plaintext_block = [0u8; RATE];
+        let mut plaintext_block_len = 0;
+
+        let header_blocks_count = Self::blocks_count(header);
+        for (i, header_block) in Self::blocks(header).enumerate() {
+            if i + 1 < header_blocks_count {
+                self.duplex.duplexing(header_block, FRAME_BIT_0, &mut []);
+            } else {
+                let first_body_block_len = body.len().min(Self::BLOCK_SIZE);
+                self.duplex.duplexing(header_block, FRAME_BIT_1, &mut key_stream[..first_body_block_len]);
+            }
+        }
+
+        for (i, body_block) in body.chunks_mut(Self::BLOCK_SIZE).enumerate() {
+            if i > 0 {
+                let key_stream = &mut key_stream[..body_block.len()];
+                self.duplex.duplexing(&plaintext_block[..plaintext_block_len], FRAME_BIT_1, key_stream);
+            }
+
+            if !decrypt {
+                plaintext_block[..body_block.len()].clone_from_slice(body_block);
+            }
+            body_block.iter_mut().zip(key_stream.iter()).for_each(|(byte, key)| *byte ^= key);
+            if decrypt {
+                plaintext_block[..body_block.len()].clone_from_slice(body_block);
+            }
+            plaintext_block_len = body_block.len();
+        }
+        key_stream.zeroize();
+
+        (plaintext_block, plaintext_block_len)
+    }
+
+    fn blocks(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+        bytes.chunks(Self::BLOCK_SIZE).chain(bytes.is_empty().then_some(&[][..]))
+    }
+
+    fn blocks_count(bytes: &[u8]) -> usize {
+        bytes.len().div_ceil(Self::BLOCK_SIZE).max(1)
+    }
+}
+
+impl<T: Default + Copy, const RATE: usize, const ROUNDS: usize> Debug for SpongeWrap<T, RATE, ROUNDS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SpongeWrap").finish_non_exhaustive()
+    }
+}
+
+impl<T: Default + Copy, const RATE: usize, const ROUNDS: usize> Drop for SpongeWrap<T, RATE, ROUNDS> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: Default + Copy, const RATE: usize, const ROUNDS: usize> Zeroize for SpongeWrap<T, RATE, ROUNDS> {
+    fn zeroize(&mut self) {
+        self.duplex.zeroize();
+    }
+}
@@ -0,0 +1,102 @@
+use rs_sponge_wrap::{InvalidTag, SpongeWrap};
+
+const KEY: &[u8; 32] = b"0123456789abcdef0123456789abcdef";
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 % 256) as u8).collect()
+}
+
+fn assert_round_trip<const RATE: usize, const ROUNDS: usize>(header_len: usize, body_len: usize, tag_len: usize) {
+    let header = sample(header_len);
+    let plaintext = sample(body_len);
+    let mut body = plaintext.clone();
+    let mut tag = vec![0u8; tag_len];
+
+    SpongeWrap::<u64, RATE, ROUNDS>::new(KEY).wrap(&header, &mut body, &mut tag);
+    if body_len > 0 {
+        assert_ne!(body, plaintext);
+    }
+
+    assert_eq!(SpongeWrap::<u64, RATE, ROUNDS>::new(KEY).unwrap(&header, &mut body, &tag), Ok(()));
+    assert_eq!(body, plaintext);
+}
+
+#[test]
+fn assert_keccak_f1600_round_trips_across_block_boundaries() {
+    for len in [0, 1, 166, 167, 168, 334, 1000] {
+        assert_round_trip::<168, 24>(len, len, 16);
+        assert_round_trip::<168, 12>(len, 1000 - len, 32);
+    }
+    assert_round_trip::<168, 24>(10, 10, 500);
+}
+
+#[test]
+fn assert_every_width_round_trips() {
+    let plaintext = sample(300);
+    let mut tag = [0u8; 16];
+
+    let mut body = plaintext.clone();
+    SpongeWrap::<u8, 18, 18>::new(KEY).wrap(b"header", &mut body, &mut tag);
+    assert_eq!(SpongeWrap::<u8, 18, 18>::new(KEY).unwrap(b"header", &mut body, &tag), Ok(()));
+    assert_eq!(body, plaintext);
+
+    let mut body = plaintext.clone();
+    SpongeWrap::<u16, 36, 20>::new(KEY).wrap(b"header", &mut body, &mut tag);
+    assert_eq!(SpongeWrap::<u16, 36, 20>::new(KEY).unwrap(b"header", &mut body, &tag), Ok(()));
+    assert_eq!(body, plaintext);
+
+    let mut body = plaintext.clone();
+    SpongeWrap::<u32, 68, 22>::new(KEY).wrap(b"header", &mut body, &mut tag);
+    assert_eq!(SpongeWrap::<u32, 68, 22>::new(KEY).unwrap(b"header", &mut body, &tag), Ok(()));
+    assert_eq!(body, plaintext);
+}
+
+#[test]
+fn assert_sessions_chain_messages() {
+    let mut sender = SpongeWrap::<u32, 68, 22>::new(KEY);
+    let mut receiver = SpongeWrap::<u32, 68, 22>::new(KEY);
+    let mut first_tag = [0u8; 16];
+    let mut second_tag = [0u8; 16];
+    let mut first_body = *b"same body";
+    let mut second_body = *b"same body";
+
+    sender.wrap(b"", &mut first_body, &mut first_tag);
+    sender.wrap(b"", &mut second_body, &mut second_tag);
+    assert_ne!(first_body, second_body);
+    assert_ne!(first_tag, second_tag);
+
+    assert_eq!(receiver.unwrap(b"", &mut first_body, &first_tag), Ok(()));
+    assert_eq!(receiver.unwrap(b"", &mut second_body, &second_tag), Ok(()));
+    assert_eq!(&second_body, b"same body");
+}
+
+#[test]
+fn assert_tampering_is_detected() {
+    let mut body = sample(100);
+    let mut tag = [0u8; 16];
+    SpongeWrap::<u64, 168>::new(KEY).wrap(b"header", &mut body, &mut tag);
+
+    let mut tampered_body = body.clone();
+    tampered_body[99] ^= 0x80;
+    assert_eq!(SpongeWrap::<u64, 168>::new(KEY).unwrap(b"header", &mut tampered_body, &tag), Err(InvalidTag));
+    assert!(tampered_body.iter().all(|&byte| byte == 0));
+
+    let mut tampered_tag = tag;
+    tampered_tag[15] ^= 0x01;
+    assert_eq!(SpongeWrap::<u64, 168>::new(KEY).unwrap(b"header", &mut body.clone(), &tampered_tag), Err(InvalidTag));
+
+    assert_eq!(SpongeWrap::<u64, 168>::new(KEY).unwrap(b"Header", &mut body.clone(), &tag), Err(InvalidTag));
+    assert_eq!(SpongeWrap::<u64, 168>::new(b"other key").unwrap(b"header", &mut body.clone(), &tag), Err(InvalidTag));
+    assert_eq!(SpongeWrap::<u64, 168>::new(KEY).unwrap(b"header", &mut body, &[]), Err(InvalidTag));
+}
+
+#[test]
+fn assert_header_and_body_boundary_is_authenticated() {
+    let mut tag1 = [0u8; 16];
+    let mut tag2 = [0u8; 16];
+
+    SpongeWrap::<u64, 136>::new(KEY).wrap(b"ab", &mut b"c".to_owned(), &mut tag1);
+    SpongeWrap::<u64, 136>::new(KEY).wrap(b"a", &mut b"bc".to_owned(), &mut tag2);
+
+    assert_ne!(tag1, tag2);
+}
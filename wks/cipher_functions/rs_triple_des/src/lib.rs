@@ -7,7 +7,7 @@
 //! used in the following applications:
 //! - Financial services: Adopted by the financial industry for secure transactions.
 //! - Communication systems: Used in various secure communication protocols such as Secure Sockets Layer (SSL),
-//! Transport Layer Security (TLS), and Internet Protocol Security (IPSec), among others.
+//!   Transport Layer Security (TLS), and Internet Protocol Security (IPSec), among others.
 //! - Electronic Key Management System (EKMS) of the United States National Security Agency.
 //!
 //! This crate implements Triple DES as part of the [RustyShield](https://docs.rs/rs_shield/latest/rs_shield/) project.
@@ -0,0 +1,104 @@
+use core::ops::BitXorAssign;
+use rs_blake2::Zeroize;
+
+/// Count of 64-bit words in a block
+const WORDS_LEN: usize = 128;
+/// Byte length of a block
+pub(crate) const BLOCK_LEN: usize = WORDS_LEN * 8;
+
+/// `Block` is the 1 KiB unit of memory Argon2 fills and mixes.
+///
+/// Callers lending the memory of a derivation provide a slice of `Argon2::block_count` blocks, which can be created
+/// with `Block::default()`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Block([u64; WORDS_LEN]);
+
+impl Block {
+    /// The compression function `G` of Argon2, which mixes `x` and `y` through the BLAKE2b based permutation `P`,
+    /// applied on the rows then on the columns of their XOR.
+    pub(crate) fn compress(x: &Block, y: &Block) -> Block {
+        let mut r = *x;
+        r ^= y;
+
+        let mut q = r;
+        for row in 0..8 {
+            permute(&mut q, core::array::from_fn(|i| 16 * row + i));
+        }
+        for column in 0..8 {
+            permute(&mut q, core::array::from_fn(|i| 2 * column + (i / 2) * 16 + i % 2));
+        }
+
+        q ^= &r;
+        q
+    }
+
+    pub(crate) fn word(&self, index: usize) -> u64 {
+        self.0[index]
+    }
+
+    pub(crate) fn set_word(&mut self, index: usize, word: u64) {
+        self.0[index] = word;
+    }
+
+    pub(crate) fn load(&mut self, bytes: &[u8; BLOCK_LEN]) {
+        self.0
+            .iter_mut()
+            .zip(bytes.chunks_exact(8))
+            .for_each(|(word, bytes)| *word = u64::from_le_bytes(bytes.try_into().unwrap()));
+    }
+
+    pub(crate) fn store(&self, bytes: &mut [u8; BLOCK_LEN]) {
+        bytes.chunks_exact_mut(8).zip(self.0).for_each(|(bytes, word)| bytes.clone_from_slice(&word.to_le_bytes()));
+    }
+}
+
+impl Default for Block {
+    fn default() -> Self {
+        Self([0u64; WORDS_LEN])
+    }
+}
+
+impl BitXorAssign<&Block> for Block {
+    fn bitxor_assign(&mut self, rhs: &Block) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(word, rhs)| *word ^= rhs);
+    }
+}
+
+/// The permutation `P` of Argon2, applied on the sixteen words of `q` at `indices`
+impl Zeroize for Block {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+fn permute(q: &mut Block, indices: [usize; 16]) {
+    let mut v: [u64; 16] = core::array::from_fn(|i| q.0[indices[i]]);
+
+    gb(&mut v, 0, 4, 8, 12);
+    gb(&mut v, 1, 5, 9, 13);
+    gb(&mut v, 2, 6, 10, 14);
+    gb(&mut v, 3, 7, 11, 15);
+    gb(&mut v, 0, 5, 10, 15);
+    gb(&mut v, 1, 6, 11, 12);
+    gb(&mut v, 2, 7, 8, 13);
+    gb(&mut v, 3, 4, 9, 14);
+
+    indices.iter().zip(v).for_each(|(&index, word)| q.0[index] = word);
+}
+
+/// The mixing function of BLAKE2b, with each addition strengthened by the product of the low halves of its operands
+fn gb(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize) {
+    v[a] = fbla_mka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = fbla_mka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = fbla_mka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = fbla_mka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+fn fbla_mka(x: u64, y: u64) -> u64 {
+    let low_product = (x & 0xFFFFFFFF).wrapping_mul(y & 0xFFFFFFFF);
+    x.wrapping_add(y).wrapping_add(low_product.wrapping_mul(2))
+}
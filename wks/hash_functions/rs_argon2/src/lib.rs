@@ -0,0 +1,363 @@
+//! # Argon2 `rs_argon2` - Memory-Hard Password Hashing Function
+//!
+//! Argon2 is the password hashing function that won the Password Hashing Competition in 2015, specified in RFC 9106. It
+//! fills a large memory area with blocks computed by a BLAKE2b based compression function, each depending on previous
+//! blocks, so that computing it with less memory, or on dedicated hardware, is prohibitively expensive.
+//!
+//! It comes in three variants, which differ in how the blocks to mix are picked:
+//! - Argon2d picks them from the memory content, maximizing resistance to GPU cracking, at the cost of side channels.
+//! - Argon2i picks them independently of the password, resisting side-channel attacks.
+//! - Argon2id uses the Argon2i addressing for the first half of the first pass and the Argon2d one afterwards. It is the
+//!   variant RFC 9106 recommends.
+//!
+//! ## Usage
+//!
+//! `Argon2` is configured with its variant and cost parameters: the memory size `m_cost` in KiB, the count of passes
+//! `t_cost` and the count of lanes `p_cost`. An optional secret key and associated data can be added. The memory is
+//! either lent by the caller through `Argon2::hash_with_memory`, or allocated by `Argon2::hash` with the `alloc`
+//! feature, enabled by default. The `alloc` feature also brings the PHC string format, and the `std` feature lets the
+//! lanes be filled on several threads.
+//!
+//! ### Example
+//!
+//! Here is an example of how to store and check a password with Argon2id in Rust:
+//!
+//! ```rust
+//! # use rs_argon2::{Algorithm, Argon2, Argon2Error};
+//! let argon2 = Argon2::new(Algorithm::Argon2id, 64, 2, 1).unwrap();
+//!
+//! let phc = argon2.hash_phc(b"correct horse battery staple", b"random salt bytes").unwrap();
+//! assert!(phc.starts_with("$argon2id$v=19$m=64,t=2,p=1$"));
+//!
+//! assert_eq!(argon2.verify_phc(&phc, b"correct horse battery staple"), Ok(()));
+//! assert_eq!(argon2.verify_phc(&phc, b"Tr0ub4dor&3"), Err(Argon2Error::Mismatch));
+//! ```
+//!
+//! ## Use Cases
+//!
+//! Argon2 is recommended for a variety of tasks, including:
+//!
+//! - Password storage, with Argon2id as the first choice of RFC 9106.
+//! - Deriving encryption keys out of passphrases.
+//! - Proof-of-work schemes relying on memory-hard computations.
+//!
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use crate::block::Block;
+
+mod block;
+mod memory;
+#[cfg(feature = "alloc")]
+mod phc;
+
+use crate::block::BLOCK_LEN;
+use crate::memory::SYNC_POINTS;
+use core::fmt::{Debug, Display, Formatter};
+pub use rs_blake2::Zeroize;
+use rs_blake2::{Blake2bState, BLAKE2B_MAX_OUTPUT_LEN};
+
+/// Version of Argon2 implemented by this crate, which is 1.3
+pub const ARGON2_VERSION: u32 = 0x13;
+/// Shortest salt accepted by Argon2
+pub const MIN_SALT_LEN: usize = 8;
+/// Shortest output accepted by Argon2
+pub const MIN_OUTPUT_LEN: usize = 4;
+/// Largest count of lanes, of `2^24 - 1`
+const MAX_LANES: u32 = 0xFFFFFF;
+
+/// `Algorithm` lists the variants of Argon2.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Algorithm {
+    /// Data-dependent addressing
+    Argon2d = 0,
+    /// Data-independent addressing
+    Argon2i = 1,
+    /// Data-independent addressing for the first half of the first pass, data-dependent afterwards
+    Argon2id = 2,
+}
+
+impl Algorithm {
+    /// Returns the identifier of the variant in PHC strings, e.g. `argon2id`.
+    pub fn ident(&self) -> &'static str {
+        match self {
+            Algorithm::Argon2d => "argon2d",
+            Algorithm::Argon2i => "argon2i",
+            Algorithm::Argon2id => "argon2id",
+        }
+    }
+}
+
+/// `Argon2Error` lists the reasons for which Argon2 refuses to hash a password, or to verify it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Argon2Error {
+    /// The cost parameters are out of the bounds set by RFC 9106
+    InvalidParams,
+    /// The salt is shorter than `MIN_SALT_LEN`
+    SaltTooShort,
+    /// The requested output is shorter than `MIN_OUTPUT_LEN`, or longer than `2^32 - 1` bytes
+    InvalidOutputLen,
+    /// The password, salt, secret or associated data is longer than `2^32 - 1` bytes
+    InputTooLong,
+    /// The memory lent to `Argon2::hash_with_memory` is shorter than `Argon2::block_count` blocks
+    MemoryTooSmall,
+    /// The memory cost is above the ceiling set by the verifier, or cannot be addressed at all
+    MemoryLimitExceeded,
+    /// The time cost or the count of lanes is above the ceiling set by the verifier
+    CostLimitExceeded,
+    /// The string does not follow the PHC string format of Argon2
+    MalformedPhc,
+    /// The password does not match the hash
+    Mismatch,
+}
+
+impl Display for Argon2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Argon2Error::InvalidParams => f.write_str("invalid Argon2 parameters"),
+            Argon2Error::SaltTooShort => f.write_str("Argon2 salt too short"),
+            Argon2Error::InvalidOutputLen => f.write_str("invalid Argon2 output length"),
+            Argon2Error::InputTooLong => f.write_str("Argon2 input too long"),
+            Argon2Error::MemoryTooSmall => f.write_str("Argon2 memory too small"),
+            Argon2Error::MemoryLimitExceeded => f.write_str("Argon2 memory cost exceeds the limit"),
+            Argon2Error::CostLimitExceeded => f.write_str("Argon2 time cost or lanes exceed the limit"),
+            Argon2Error::MalformedPhc => f.write_str("malformed Argon2 PHC string"),
+            Argon2Error::Mismatch => f.write_str("password does not match"),
+        }
+    }
+}
+
+/// `Argon2` is a struct that provides the Argon2 password hashing function in Rust, for a variant and a set of
+/// validated cost parameters, optionally keyed with a secret and bound to associated data.
+///
+/// The secret and the associated data are left out of its `Debug` output.
+///
+/// ## Examples
+///
+/// Without an allocator, the caller lends the memory, here with the Argon2id test vector of RFC 9106:
+///
+///```rust
+/// # use rs_argon2::{Algorithm, Argon2, Block};
+/// let argon2 = Argon2::new(Algorithm::Argon2id, 32, 3, 4).unwrap().with_secret(&[0x03; 8]).with_associated_data(&[0x04; 12]);
+///
+/// let mut memory = [Block::default(); 32];
+/// let mut tag = [0u8; 32];
+/// argon2.hash_with_memory(&[0x01; 32], &[0x02; 16], &mut memory, &mut tag).unwrap();
+/// assert_eq!(tag[..4], [0x0D, 0x64, 0x0D, 0xF5]);
+///```
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Argon2<'key> {
+    algorithm: Algorithm,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    secret: &'key [u8],
+    associated_data: &'key [u8],
+    #[cfg(feature = "std")]
+    threads: u32,
+}
+
+impl<'key> Argon2<'key> {
+    /// Validates the cost parameters of Argon2.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - The variant of Argon2.
+    /// * `m_cost` - The memory size in KiB, which must be at least `8 * p_cost`. It is rounded down to a multiple of
+    ///   `4 * p_cost`.
+    /// * `t_cost` - The count of passes over the memory, which must be at least 1.
+    /// * `p_cost` - The count of lanes, which must be between 1 and `2^24 - 1`.
+    pub fn new(algorithm: Algorithm, m_cost: u32, t_cost: u32, p_cost: u32) -> Result<Self, Argon2Error> {
+        if t_cost == 0 || p_cost == 0 || p_cost > MAX_LANES || (m_cost as u64) < 8 * p_cost as u64 {
+            return Err(Argon2Error::InvalidParams);
+        }
+        if (m_cost as usize).checked_mul(BLOCK_LEN).is_none() {
+            return Err(Argon2Error::MemoryLimitExceeded);
+        }
+
+        Ok(Self {
+            algorithm,
+            m_cost,
+            t_cost,
+            p_cost,
+            secret: &[],
+            associated_data: &[],
+            #[cfg(feature = "std")]
+            threads: 1,
+        })
+    }
+
+    /// Keys the hash with `secret`, also known as a pepper, which is kept apart from the stored hashes.
+    pub fn with_secret(self, secret: &'key [u8]) -> Self {
+        Self {
+            secret,
+            ..self
+        }
+    }
+
+    /// Binds the hash to `associated_data`.
+    pub fn with_associated_data(self, associated_data: &'key [u8]) -> Self {
+        Self {
+            associated_data,
+            ..self
+        }
+    }
+
+    /// Fills the lanes on up to `threads` threads, which does not change the hash. It is clamped between 1 and the
+    /// count of lanes.
+    #[cfg(feature = "std")]
+    pub fn with_threads(self, threads: u32) -> Self {
+        Self {
+            threads: threads.clamp(1, self.p_cost),
+            ..self
+        }
+    }
+
+    /// Returns the variant of Argon2.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the count of blocks of memory a hash needs, which is `m_cost` rounded down to a multiple of
+    /// `4 * p_cost`.
+    pub fn block_count(&self) -> usize {
+        let segment_count = SYNC_POINTS * self.p_cost as usize;
+        self.m_cost as usize / segment_count * segment_count
+    }
+
+    /// Returns the byte length of the memory a hash needs.
+    pub fn memory_len(&self) -> usize {
+        self.block_count() * BLOCK_LEN
+    }
+
+    /// Fills the whole of `output` with the hash of `password` and `salt`, using the start of `memory` as the blocks
+    /// to fill.
+    ///
+    /// `memory` must be at least `Argon2::block_count` blocks long, and its start is zeroized once the hash is computed.
+    pub fn hash_with_memory(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        memory: &mut [Block],
+        output: &mut [u8],
+    ) -> Result<(), Argon2Error> {
+        if salt.len() < MIN_SALT_LEN {
+            return Err(Argon2Error::SaltTooShort);
+        }
+        if output.len() < MIN_OUTPUT_LEN || output.len() as u64 > u32::MAX as u64 {
+            return Err(Argon2Error::InvalidOutputLen);
+        }
+        if [password, salt, self.secret, self.associated_data].iter().any(|input| input.len() as u64 > u32::MAX as u64)
+        {
+            return Err(Argon2Error::InputTooLong);
+        }
+        let memory = memory.get_mut(..self.block_count()).ok_or(Argon2Error::MemoryTooSmall)?;
+
+        let mut h0 = self.initial_hash(password, salt, output.len());
+        let segment_len = self.segment_len();
+        let mut block_bytes = [0u8; BLOCK_LEN];
+        for lane in 0..self.p_cost as usize {
+            for index in 0..2 {
+                h_prime(&mut block_bytes, &[&h0, &(index as u32).to_le_bytes(), &(lane as u32).to_le_bytes()]);
+                memory[lane * segment_len + index].load(&block_bytes);
+            }
+        }
+
+        self.fill_memory(memory);
+
+        let lanes = self.p_cost as usize;
+        let last_slice = &memory[(SYNC_POINTS - 1) * lanes * segment_len..];
+        let mut final_block = Block::default();
+        last_slice.chunks_exact(segment_len).for_each(|segment| final_block ^= &segment[segment_len - 1]);
+
+        final_block.store(&mut block_bytes);
+        h_prime(output, &[&block_bytes]);
+
+        h0.zeroize();
+        block_bytes.zeroize();
+        final_block.zeroize();
+        memory.zeroize();
+
+        Ok(())
+    }
+
+    /// Fills the whole of `output` with the hash of `password` and `salt`, allocating the memory.
+    #[cfg(feature = "alloc")]
+    pub fn hash(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), Argon2Error> {
+        let mut memory = alloc::vec![Block::default(); self.block_count()];
+        self.hash_with_memory(password, salt, &mut memory, output)
+    }
+
+    /// Computes `H0`, the digest of every input and parameter the memory is seeded with
+    fn initial_hash(&self, password: &[u8], salt: &[u8], output_len: usize) -> [u8; BLAKE2B_MAX_OUTPUT_LEN] {
+        let mut blake2b = Blake2bState::new(BLAKE2B_MAX_OUTPUT_LEN);
+        for parameter in [self.p_cost, output_len as u32, self.m_cost, self.t_cost, ARGON2_VERSION] {
+            blake2b.update(&parameter.to_le_bytes());
+        }
+        blake2b.update(&(self.algorithm as u32).to_le_bytes());
+        for input in [password, salt, self.secret, self.associated_data] {
+            blake2b.update(&(input.len() as u32).to_le_bytes());
+            blake2b.update(input);
+        }
+
+        let mut h0 = [0u8; BLAKE2B_MAX_OUTPUT_LEN];
+        blake2b.finalize_into(&mut h0);
+        h0
+    }
+
+    fn segment_len(&self) -> usize {
+        self.block_count() / (SYNC_POINTS * self.p_cost as usize)
+    }
+}
+
+impl Debug for Argon2<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Argon2")
+            .field("algorithm", &self.algorithm)
+            .field("m_cost", &self.m_cost)
+            .field("t_cost", &self.t_cost)
+            .field("p_cost", &self.p_cost)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The variable-length hash function `H'` of Argon2, filling the whole of `output` out of the concatenation of
+/// `inputs`, by chaining BLAKE2b-512 digests when the output is longer than a single digest.
+fn h_prime(output: &mut [u8], inputs: &[&[u8]]) {
+    const HALF_DIGEST_LEN: usize = BLAKE2B_MAX_OUTPUT_LEN / 2;
+
+    let output_len = output.len();
+    let mut blake2b = Blake2bState::new(output_len.min(BLAKE2B_MAX_OUTPUT_LEN));
+    blake2b.update(&(output_len as u32).to_le_bytes());
+    inputs.iter().for_each(|input| blake2b.update(input));
+
+    if output_len <= BLAKE2B_MAX_OUTPUT_LEN {
+        blake2b.finalize_into(output);
+        return;
+    }
+
+    let mut digest = [0u8; BLAKE2B_MAX_OUTPUT_LEN];
+    blake2b.finalize_into(&mut digest);
+
+    let chained_len = output_len.div_ceil(HALF_DIGEST_LEN) - 2;
+    let (chained_output, last_output) = output.split_at_mut(chained_len * HALF_DIGEST_LEN);
+    for (i, chunk) in chained_output.chunks_exact_mut(HALF_DIGEST_LEN).enumerate() {
+        chunk.clone_from_slice(&digest[..HALF_DIGEST_LEN]);
+
+        if i + 1 < chained_len {
+            let mut blake2b = Blake2bState::new(BLAKE2B_MAX_OUTPUT_LEN);
+            blake2b.update(&digest);
+            blake2b.finalize_into(&mut digest);
+        }
+    }
+
+    let mut blake2b = Blake2bState::new(last_output.len());
+    blake2b.update(&digest);
+    blake2b.finalize_into(last_output);
+    digest.zeroize();
+}
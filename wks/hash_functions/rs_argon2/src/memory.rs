@@ -0,0 +1,228 @@
+use crate::block::Block;
+use crate::{Algorithm, Argon2};
+
+/// Count of slices each lane is split into, whose segments are filled in sync across lanes
+pub(crate) const SYNC_POINTS: usize = 4;
+/// Count of reference indices drawn from each address block
+const ADDRESSES_IN_BLOCK: usize = 128;
+
+/// Position of the segment being filled
+#[derive(Clone, Copy)]
+struct Position {
+    pass: usize,
+    slice: usize,
+    lane: usize,
+}
+
+/// The blocks a segment can read besides its own, which are split in the slices before and after the one being filled,
+/// as memory is laid out slice after slice, each holding one segment per lane.
+#[derive(Clone, Copy)]
+struct Neighbourhood<'a> {
+    before: &'a [Block],
+    after: &'a [Block],
+}
+
+impl Argon2<'_> {
+    /// Fills the memory, whose first two blocks of each lane are already initialized, for every pass.
+    ///
+    /// Memory holds the segments slice by slice rather than lane by lane, so that the segments of a slice, which are
+    /// filled concurrently, are disjoint sub-slices, while all the other slices can be shared for reading.
+    pub(crate) fn fill_memory(&self, memory: &mut [Block]) {
+        let segment_len = self.segment_len();
+        let slice_len = segment_len * self.p_cost as usize;
+
+        for pass in 0..self.t_cost as usize {
+            for slice in 0..SYNC_POINTS {
+                let (before, rest) = memory.split_at_mut(slice * slice_len);
+                let (current, after) = rest.split_at_mut(slice_len);
+                let neighbourhood = Neighbourhood {
+                    before,
+                    after,
+                };
+
+                self.fill_slice(pass, slice, current, neighbourhood);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fill_slice(&self, pass: usize, slice: usize, current: &mut [Block], neighbourhood: Neighbourhood) {
+        for (lane, segment) in current.chunks_exact_mut(self.segment_len()).enumerate() {
+            self.fill_segment(
+                Position {
+                    pass,
+                    slice,
+                    lane,
+                },
+                segment,
+                neighbourhood,
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn fill_slice(&self, pass: usize, slice: usize, current: &mut [Block], neighbourhood: Neighbourhood) {
+        let segment_len = self.segment_len();
+        let lanes_per_thread = (self.p_cost as usize).div_ceil(self.threads as usize);
+
+        if lanes_per_thread == self.p_cost as usize {
+            for (lane, segment) in current.chunks_exact_mut(segment_len).enumerate() {
+                self.fill_segment(
+                    Position {
+                        pass,
+                        slice,
+                        lane,
+                    },
+                    segment,
+                    neighbourhood,
+                );
+            }
+            return;
+        }
+
+        std::thread::scope(|scope| {
+            for (group, segments) in current.chunks_mut(segment_len * lanes_per_thread).enumerate() {
+                scope.spawn(move || {
+                    for (i, segment) in segments.chunks_exact_mut(segment_len).enumerate() {
+                        let lane = group * lanes_per_thread + i;
+                        self.fill_segment(
+                            Position {
+                                pass,
+                                slice,
+                                lane,
+                            },
+                            segment,
+                            neighbourhood,
+                        );
+                    }
+                });
+            }
+        });
+    }
+
+    fn fill_segment(&self, position: Position, segment: &mut [Block], neighbourhood: Neighbourhood) {
+        let segment_len = segment.len();
+        let lane_len = segment_len * SYNC_POINTS;
+        let lanes = self.p_cost as usize;
+
+        let data_independent = match self.algorithm {
+            Algorithm::Argon2d => false,
+            Algorithm::Argon2i => true,
+            Algorithm::Argon2id => position.pass == 0 && position.slice < SYNC_POINTS / 2,
+        };
+
+        let mut address_input = Block::default();
+        let mut address_block = Block::default();
+        if data_independent {
+            address_input.set_word(0, position.pass as u64);
+            address_input.set_word(1, position.lane as u64);
+            address_input.set_word(2, position.slice as u64);
+            address_input.set_word(3, self.block_count() as u64);
+            address_input.set_word(4, self.t_cost as u64);
+            address_input.set_word(5, self.algorithm as u64);
+        }
+
+        let first_index = if position.pass == 0 && position.slice == 0 {
+            2
+        } else {
+            0
+        };
+        if data_independent && first_index != 0 {
+            next_addresses(&mut address_input, &mut address_block);
+        }
+
+        for index in first_index..segment_len {
+            let lane_index = position.slice * segment_len + index;
+            let previous_index = if lane_index == 0 {
+                lane_len - 1
+            } else {
+                lane_index - 1
+            };
+            let previous_block = self.block(position, segment, neighbourhood, position.lane, previous_index);
+
+            let pseudo_random = if data_independent {
+                if index % ADDRESSES_IN_BLOCK == 0 {
+                    next_addresses(&mut address_input, &mut address_block);
+                }
+                address_block.word(index % ADDRESSES_IN_BLOCK)
+            } else {
+                previous_block.word(0)
+            };
+
+            let reference_lane = if position.pass == 0 && position.slice == 0 {
+                position.lane
+            } else {
+                (pseudo_random >> 32) as usize % lanes
+            };
+            let reference_index = reference_index(position, segment_len, index, reference_lane, pseudo_random as u32);
+            let reference_block = self.block(position, segment, neighbourhood, reference_lane, reference_index);
+
+            let new_block = Block::compress(previous_block, reference_block);
+            if position.pass == 0 {
+                segment[index] = new_block;
+            } else {
+                segment[index] ^= &new_block;
+            }
+        }
+    }
+
+    /// Returns the block at `index` of `lane`, wherever it lies in memory.
+    fn block<'a>(
+        &self,
+        position: Position,
+        segment: &'a [Block],
+        neighbourhood: Neighbourhood<'a>,
+        lane: usize,
+        index: usize,
+    ) -> &'a Block {
+        let segment_len = segment.len();
+        let (slice, offset) = (index / segment_len, index % segment_len);
+        let lanes = self.p_cost as usize;
+
+        if slice == position.slice {
+            &segment[offset]
+        } else if slice < position.slice {
+            &neighbourhood.before[(slice * lanes + lane) * segment_len + offset]
+        } else {
+            &neighbourhood.after[((slice - position.slice - 1) * lanes + lane) * segment_len + offset]
+        }
+    }
+}
+
+/// Maps the low half `j1` of the pseudo-random value onto the index of the reference block within its lane, among the
+/// blocks already filled and not being filled concurrently.
+fn reference_index(position: Position, segment_len: usize, index: usize, reference_lane: usize, j1: u32) -> usize {
+    let lane_len = segment_len * SYNC_POINTS;
+    let same_lane = reference_lane == position.lane;
+    let filled_len = if position.pass == 0 {
+        position.slice * segment_len
+    } else {
+        lane_len - segment_len
+    };
+
+    let reference_area_len = match (same_lane, index) {
+        (true, _) => filled_len + index - 1,
+        (false, 0) => filled_len - 1,
+        (false, _) => filled_len,
+    };
+
+    let x = (j1 as u64 * j1 as u64) >> 32;
+    let y = (reference_area_len as u64 * x) >> 32;
+    let relative_index = reference_area_len - 1 - y as usize;
+
+    let start_index = if position.pass == 0 || position.slice == SYNC_POINTS - 1 {
+        0
+    } else {
+        (position.slice + 1) * segment_len
+    };
+
+    (start_index + relative_index) % lane_len
+}
+
+/// Draws the next block of reference indices for data-independent addressing.
+fn next_addresses(address_input: &mut Block, address_block: &mut Block) {
+    address_input.set_word(6, address_input.word(6) + 1);
+
+    let zero_block = Block::default();
+    *address_block = Block::compress(&zero_block, &Block::compress(&zero_block, address_input));
+}
@@ -0,0 +1,172 @@
+use crate::{Algorithm, Argon2, Argon2Error, ARGON2_VERSION, MIN_OUTPUT_LEN, MIN_SALT_LEN};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use rs_constant_time::ConstantTimeEq;
+
+/// Byte length of the hashes written by `Argon2::hash_phc`
+const PHC_OUTPUT_LEN: usize = 32;
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl Argon2<'_> {
+    /// Hashes `password` and `salt` into a 32 bytes hash, encoded in the PHC string format along with the variant, the
+    /// version and the cost parameters, e.g. `$argon2id$v=19$m=65536,t=3,p=4$<salt>$<hash>`.
+    ///
+    /// The secret and the associated data are not part of the string, so they must be given again for verification.
+    pub fn hash_phc(&self, password: &[u8], salt: &[u8]) -> Result<String, Argon2Error> {
+        let mut hash = [0u8; PHC_OUTPUT_LEN];
+        self.hash(password, salt, &mut hash)?;
+
+        let mut phc = String::new();
+        write!(
+            phc,
+            "${}$v={ARGON2_VERSION}$m={},t={},p={}$",
+            self.algorithm.ident(),
+            self.m_cost,
+            self.t_cost,
+            self.p_cost
+        )
+        .unwrap();
+        encode_base64(salt, &mut phc);
+        phc.push('$');
+        encode_base64(&hash, &mut phc);
+
+        Ok(phc)
+    }
+
+    /// Checks `password` against the PHC string `phc`, comparing the hashes in constant time.
+    ///
+    /// The variant and the cost parameters are read from `phc`, while the secret, the associated data and the threads
+    /// are taken from `self`. The memory cost, the time cost and the count of lanes of `self` are ceilings, so that a
+    /// tampered string can neither exhaust memory nor keep the verifier busy.
+    pub fn verify_phc(&self, phc: &str, password: &[u8]) -> Result<(), Argon2Error> {
+        let parsed = ParsedPhc::parse(phc)?;
+        if parsed.m_cost > self.m_cost {
+            return Err(Argon2Error::MemoryLimitExceeded);
+        }
+        if parsed.t_cost > self.t_cost || parsed.p_cost > self.p_cost {
+            return Err(Argon2Error::CostLimitExceeded);
+        }
+
+        let argon2 = Argon2::new(parsed.algorithm, parsed.m_cost, parsed.t_cost, parsed.p_cost)
+            .map_err(|_| Argon2Error::MalformedPhc)?;
+        let argon2 = Self {
+            secret: self.secret,
+            associated_data: self.associated_data,
+            ..argon2
+        };
+        #[cfg(feature = "std")]
+        let argon2 = argon2.with_threads(self.threads);
+
+        let mut hash = alloc::vec![0u8; parsed.hash.len()];
+        argon2.hash(password, &parsed.salt, &mut hash)?;
+
+        if !bool::from(hash[..].ct_eq(&parsed.hash[..])) {
+            return Err(Argon2Error::Mismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// Fields of an Argon2 PHC string
+struct ParsedPhc {
+    algorithm: Algorithm,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    salt: Vec<u8>,
+    hash: Vec<u8>,
+}
+
+impl ParsedPhc {
+    fn parse(phc: &str) -> Result<Self, Argon2Error> {
+        let mut fields = phc.strip_prefix('$').ok_or(Argon2Error::MalformedPhc)?.split('$');
+
+        let algorithm = match fields.next() {
+            Some("argon2d") => Algorithm::Argon2d,
+            Some("argon2i") => Algorithm::Argon2i,
+            Some("argon2id") => Algorithm::Argon2id,
+            _ => return Err(Argon2Error::MalformedPhc),
+        };
+
+        let version = fields.next().and_then(|field| field.strip_prefix("v=")).and_then(parse_decimal);
+        if version != Some(ARGON2_VERSION) {
+            return Err(Argon2Error::MalformedPhc);
+        }
+
+        let mut params = fields.next().ok_or(Argon2Error::MalformedPhc)?.split(',');
+        let mut param = |name: &str| {
+            params
+                .next()
+                .and_then(|param| param.strip_prefix(name))
+                .and_then(parse_decimal)
+                .ok_or(Argon2Error::MalformedPhc)
+        };
+        let (m_cost, t_cost, p_cost) = (param("m=")?, param("t=")?, param("p=")?);
+        if params.next().is_some() {
+            return Err(Argon2Error::MalformedPhc);
+        }
+
+        let salt = fields.next().and_then(decode_base64).ok_or(Argon2Error::MalformedPhc)?;
+        let hash = fields.next().and_then(decode_base64).ok_or(Argon2Error::MalformedPhc)?;
+        if fields.next().is_some() || salt.len() < MIN_SALT_LEN || hash.len() < MIN_OUTPUT_LEN {
+            return Err(Argon2Error::MalformedPhc);
+        }
+
+        Ok(Self {
+            algorithm,
+            m_cost,
+            t_cost,
+            p_cost,
+            salt,
+            hash,
+        })
+    }
+}
+
+/// Parses a decimal integer without sign nor leading zeros
+fn parse_decimal(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) || (digits.len() > 1 && digits.starts_with('0'))
+    {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
+/// Appends `bytes` encoded in the unpadded standard Base64 alphabet, as used by PHC strings
+fn encode_base64(bytes: &[u8], output: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].clone_from_slice(chunk);
+        let bits = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+
+        (0..=chunk.len()).for_each(|i| output.push(BASE64_ALPHABET[(bits >> (18 - 6 * i)) as usize & 0x3F] as char));
+    }
+}
+
+/// Decodes unpadded standard Base64, rejecting non canonical encodings
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    if encoded.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(encoded.len() * 3 / 4);
+    for chunk in encoded.as_bytes().chunks(4) {
+        let mut bits = 0u32;
+        for (i, &symbol) in chunk.iter().enumerate() {
+            let value = BASE64_ALPHABET.iter().position(|&b| b == symbol)? as u32;
+            bits |= value << (18 - 6 * i);
+        }
+
+        let group = bits.to_be_bytes();
+        let len = chunk.len() - 1;
+        if group[1 + len..].iter().any(|&b| b != 0) {
+            return None;
+        }
+        bytes.extend_from_slice(&group[1..1 + len]);
+    }
+
+    Some(bytes)
+}
@@ -0,0 +1,81 @@
+use rs_argon2::{Algorithm, Argon2, Argon2Error};
+
+fn verifier() -> Argon2<'static> {
+    Argon2::new(Algorithm::Argon2id, 65536, 2, 1).unwrap()
+}
+
+#[test]
+fn assert_reference_argon2i_phc_string() {
+    let phc = "$argon2i$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$wWKIMhR9lyDFvRz9YTZweHKfbftvj+qf+YFY4NeBbtA";
+
+    assert_eq!(verifier().verify_phc(phc, b"password"), Ok(()));
+}
+
+#[test]
+fn assert_reference_argon2id_phc_string() {
+    let phc = "$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc";
+
+    assert_eq!(verifier().verify_phc(phc, b"password"), Ok(()));
+}
+
+#[test]
+fn assert_phc_round_trip_with_secret() {
+    let argon2 = Argon2::new(Algorithm::Argon2d, 64, 2, 2).unwrap().with_secret(b"pepper");
+
+    let phc = argon2.hash_phc(b"password", b"somesalt").unwrap();
+    assert_eq!(phc, format!("$argon2d$v=19$m=64,t=2,p=2$c29tZXNhbHQ${}", phc.rsplit('$').next().unwrap()));
+    assert_eq!(phc.rsplit('$').next().unwrap().len(), 43);
+
+    assert_eq!(argon2.verify_phc(&phc, b"password"), Ok(()));
+    assert_eq!(
+        Argon2::new(Algorithm::Argon2d, 64, 2, 2).unwrap().verify_phc(&phc, b"password"),
+        Err(Argon2Error::Mismatch)
+    );
+}
+
+#[test]
+fn assert_memory_ceiling_is_enforced() {
+    let phc = "$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc";
+    let verifier = Argon2::new(Algorithm::Argon2id, 65535, 1, 1).unwrap();
+
+    assert_eq!(verifier.verify_phc(phc, b"password"), Err(Argon2Error::MemoryLimitExceeded));
+}
+
+#[test]
+fn assert_time_cost_ceiling_is_enforced() {
+    let phc = "$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc";
+    let verifier = Argon2::new(Algorithm::Argon2id, 65536, 1, 1).unwrap();
+
+    assert_eq!(verifier.verify_phc(phc, b"password"), Err(Argon2Error::CostLimitExceeded));
+}
+
+#[test]
+fn assert_lanes_ceiling_is_enforced() {
+    let phc = "$argon2id$v=19$m=65536,t=2,p=4$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc";
+
+    assert_eq!(verifier().verify_phc(phc, b"password"), Err(Argon2Error::CostLimitExceeded));
+}
+
+#[test]
+fn assert_malformed_phc_strings_are_rejected() {
+    let malformed_phc_strings = [
+        "",
+        "argon2id$v=19$m=64,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2x$v=19$m=64,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=16$m=64,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$m=64,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$t=1,m=64,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$m=064,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$m=+64,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$m=64,t=1,p=1,x=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$m=64,t=0,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$m=64,t=1,p=1$c29tZXNhbHR$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$m=64,t=1,p=1$c29tZQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc",
+        "$argon2id$v=19$m=64,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc=",
+        "$argon2id$v=19$m=64,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc$",
+    ];
+
+    for phc in malformed_phc_strings {
+        assert_eq!(verifier().verify_phc(phc, b"password"), Err(Argon2Error::MalformedPhc), "{phc}");
+    }
+}
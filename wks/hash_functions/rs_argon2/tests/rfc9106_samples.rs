@@ -0,0 +1,77 @@
+use rs_argon2::{Algorithm, Argon2, Argon2Error, Block};
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn rfc9106_tag(algorithm: Algorithm) -> String {
+    let argon2 = Argon2::new(algorithm, 32, 3, 4).unwrap().with_secret(&[0x03; 8]).with_associated_data(&[0x04; 12]);
+
+    let mut tag = [0u8; 32];
+    argon2.hash(&[0x01; 32], &[0x02; 16], &mut tag).unwrap();
+    bytes_to_hex(&tag)
+}
+
+#[test]
+fn assert_rfc9106_argon2d_sample() {
+    assert_eq!(rfc9106_tag(Algorithm::Argon2d), "512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb");
+}
+
+#[test]
+fn assert_rfc9106_argon2i_sample() {
+    assert_eq!(rfc9106_tag(Algorithm::Argon2i), "c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8");
+}
+
+#[test]
+fn assert_rfc9106_argon2id_sample() {
+    assert_eq!(rfc9106_tag(Algorithm::Argon2id), "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659");
+}
+
+#[test]
+fn assert_lent_memory_matches_allocated_memory() {
+    let argon2 = Argon2::new(Algorithm::Argon2id, 35, 3, 4).unwrap();
+    assert_eq!(argon2.block_count(), 32);
+    assert_eq!(argon2.memory_len(), 32 * 1024);
+
+    let mut allocated_tag = [0u8; 100];
+    argon2.hash(b"password", b"somesalt", &mut allocated_tag).unwrap();
+
+    let mut memory = vec![Block::default(); 33];
+    let mut lent_tag = [0u8; 100];
+    argon2.hash_with_memory(b"password", b"somesalt", &mut memory, &mut lent_tag).unwrap();
+    assert_eq!(lent_tag, allocated_tag);
+
+    assert_eq!(
+        argon2.hash_with_memory(b"password", b"somesalt", &mut memory[..31], &mut lent_tag),
+        Err(Argon2Error::MemoryTooSmall)
+    );
+}
+
+#[test]
+fn assert_invalid_params_and_inputs_are_rejected() {
+    assert_eq!(Argon2::new(Algorithm::Argon2id, 31, 3, 4), Err(Argon2Error::InvalidParams));
+    assert_eq!(Argon2::new(Algorithm::Argon2id, 32, 0, 4), Err(Argon2Error::InvalidParams));
+    assert_eq!(Argon2::new(Algorithm::Argon2id, 32, 3, 0), Err(Argon2Error::InvalidParams));
+    assert_eq!(Argon2::new(Algorithm::Argon2id, u32::MAX, 1, 1 << 24), Err(Argon2Error::InvalidParams));
+
+    let argon2 = Argon2::new(Algorithm::Argon2id, 8, 1, 1).unwrap();
+    let mut tag = [0u8; 32];
+    assert_eq!(argon2.hash(b"password", b"short", &mut tag), Err(Argon2Error::SaltTooShort));
+    assert_eq!(argon2.hash(b"password", b"somesalt", &mut tag[..3]), Err(Argon2Error::InvalidOutputLen));
+    assert_eq!(argon2.hash(b"password", b"somesalt", &mut tag[..4]), Ok(()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn assert_threads_do_not_change_the_hash() {
+    let argon2 = Argon2::new(Algorithm::Argon2id, 256, 2, 4).unwrap();
+
+    let mut single_threaded_tag = [0u8; 32];
+    argon2.hash(b"password", b"somesalt", &mut single_threaded_tag).unwrap();
+
+    for threads in [2, 3, 4, 16] {
+        let mut multi_threaded_tag = [0u8; 32];
+        argon2.with_threads(threads).hash(b"password", b"somesalt", &mut multi_threaded_tag).unwrap();
+        assert_eq!(multi_threaded_tag, single_threaded_tag);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::Blake2bState;
+use core::hash::Hasher;
+use rs_hasher_ctx::{ByteArrayWrapper, HasherContext, Zeroize};
+
+/// `Blake2bHasher` is a type that provides the BLAKE2b hashing algorithm in RustyShield, for a digest of `OUTPUT_SIZE`
+/// bytes, between 1 and 64.
+///
+/// Besides plain hashing, BLAKE2b is keyed natively: `Blake2bHasher::new_keyed` turns it into a message authentication
+/// code without the nested construction HMAC needs.
+///
+/// The `Blake2bHasher` struct adheres to Rust's `Hasher` trait, enabling you to use it interchangeably with other
+/// hashers in Rust.
+///
+/// ## Examples
+///
+/// The following example reproduces the BLAKE2b-512 sample of RFC 7693:
+///
+///```rust
+/// # use std::hash::Hasher;
+/// # use rs_blake2::{Blake2b512Hasher, HasherContext};
+/// let mut blake2b512hasher = Blake2b512Hasher::default();
+/// blake2b512hasher.write(b"abc");
+///
+/// assert_eq!(blake2b512hasher.finish(), 0xBA80A53F981C4D0D);
+///
+/// let result = HasherContext::finish(&mut blake2b512hasher);
+/// assert_eq!(result[..4], [0xBA, 0x80, 0xA5, 0x3F]);
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Blake2bHasher<const OUTPUT_SIZE: usize>(Blake2bState);
+
+impl<const OUTPUT_SIZE: usize> Blake2bHasher<OUTPUT_SIZE> {
+    /// Creates a BLAKE2b hasher keyed with `key`, of at most 64 bytes.
+    pub fn new_keyed(key: &[u8]) -> Self {
+        Self(Blake2bState::new_keyed(key, OUTPUT_SIZE))
+    }
+
+    fn digest(&self) -> [u8; OUTPUT_SIZE] {
+        let mut digest = [0u8; OUTPUT_SIZE];
+        self.0.clone().finalize_into(&mut digest);
+        digest
+    }
+}
+
+impl<const OUTPUT_SIZE: usize> Default for Blake2bHasher<OUTPUT_SIZE> {
+    fn default() -> Self {
+        Self(Blake2bState::new(OUTPUT_SIZE))
+    }
+}
+
+impl<const OUTPUT_SIZE: usize> From<Blake2bHasher<OUTPUT_SIZE>> for Blake2bState {
+    fn from(value: Blake2bHasher<OUTPUT_SIZE>) -> Self {
+        value.0
+    }
+}
+
+impl<const OUTPUT_SIZE: usize> Hasher for Blake2bHasher<OUTPUT_SIZE> {
+    fn finish(&self) -> u64 {
+        let mut leading_bytes = [0u8; 8];
+        let digest = self.digest();
+        let len = OUTPUT_SIZE.min(leading_bytes.len());
+        leading_bytes[..len].clone_from_slice(&digest[..len]);
+
+        u64::from_be_bytes(leading_bytes)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes)
+    }
+}
+
+impl<const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for Blake2bHasher<OUTPUT_SIZE> {
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn finish(&mut self) -> Self::Output {
+        self.digest().into()
+    }
+}
+
+impl<const OUTPUT_SIZE: usize> Zeroize for Blake2bHasher<OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
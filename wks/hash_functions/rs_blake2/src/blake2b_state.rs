@@ -0,0 +1,197 @@
+use core::fmt::{Debug, Formatter};
+use rs_hasher_ctx::Zeroize;
+
+/// Byte length of the blocks BLAKE2b compresses
+pub(crate) const BLOCK_LEN: usize = 128;
+/// Longest digest, and longest key, of BLAKE2b
+pub const BLAKE2B_MAX_OUTPUT_LEN: usize = 64;
+
+const IV: [u64; 8] = [
+    0x6A09E667F3BCC908,
+    0xBB67AE8584CAA73B,
+    0x3C6EF372FE94F82B,
+    0xA54FF53A5F1D36F1,
+    0x510E527FADE682D1,
+    0x9B05688C2B3E6C1F,
+    0x1F83D9ABFB41BD6B,
+    0x5BE0CD19137E2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// `Blake2bState` is the BLAKE2b core, as specified in RFC 7693, with a digest length and an optional key chosen at
+/// runtime.
+///
+/// It backs `Blake2bHasher`, whose digest length is fixed at compile time, and serves constructions that pick the
+/// digest length on the fly, such as the variable-length hash function of Argon2.
+///
+/// As a keyed state holds the key in its buffer until the first block is compressed, it is zeroized on drop, and its
+/// `Debug` output only shows the digest length.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_blake2::Blake2bState;
+/// let mut blake2b = Blake2bState::new(20);
+/// blake2b.update(b"abc");
+///
+/// let mut digest = [0u8; 20];
+/// blake2b.finalize_into(&mut digest);
+/// assert_eq!(digest[..4], [0x38, 0x42, 0x64, 0xF6]);
+///```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Blake2bState {
+    h: [u64; 8],
+    bytes_count: u128,
+    buffer: [u8; BLOCK_LEN],
+    buffer_len: usize,
+    output_len: usize,
+}
+
+impl Blake2bState {
+    /// Creates an unkeyed BLAKE2b state producing `output_len` bytes, which must be between 1 and 64.
+    pub fn new(output_len: usize) -> Self {
+        Self::new_keyed(&[], output_len)
+    }
+
+    /// Creates a BLAKE2b state keyed with `key`, of at most 64 bytes, producing `output_len` bytes, which must be
+    /// between 1 and 64.
+    pub fn new_keyed(key: &[u8], output_len: usize) -> Self {
+        assert!(
+            (1..=BLAKE2B_MAX_OUTPUT_LEN).contains(&output_len),
+            "BLAKE2b output length must be between 1 and {BLAKE2B_MAX_OUTPUT_LEN} bytes"
+        );
+        assert!(
+            key.len() <= BLAKE2B_MAX_OUTPUT_LEN,
+            "BLAKE2b key cannot be longer than {BLAKE2B_MAX_OUTPUT_LEN} bytes"
+        );
+
+        let mut h = IV;
+        h[0] ^= 0x01010000 ^ ((key.len() as u64) << 8) ^ output_len as u64;
+
+        let mut state = Self {
+            h,
+            bytes_count: 0,
+            buffer: [0u8; BLOCK_LEN],
+            buffer_len: 0,
+            output_len,
+        };
+        if !key.is_empty() {
+            state.buffer[..key.len()].clone_from_slice(key);
+            state.buffer_len = BLOCK_LEN;
+        }
+
+        state
+    }
+
+    /// Returns the byte length of the digest.
+    pub fn output_len(&self) -> usize {
+        self.output_len
+    }
+
+    /// Absorbs `bytes`. The last block is kept buffered, as it is compressed differently once known to be the last.
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            if self.buffer_len == BLOCK_LEN {
+                self.bytes_count += BLOCK_LEN as u128;
+                let block = self.buffer;
+                self.compress(&block, false);
+                self.buffer_len = 0;
+            }
+
+            let len = bytes.len().min(BLOCK_LEN - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + len].clone_from_slice(&bytes[..len]);
+            self.buffer_len += len;
+            bytes = &bytes[len..];
+        }
+    }
+
+    /// Compresses the last block and writes the digest into `output`, whose length must be `Blake2bState::output_len`.
+    pub fn finalize_into(mut self, output: &mut [u8]) {
+        assert_eq!(output.len(), self.output_len, "BLAKE2b output buffer must be {} bytes long", self.output_len);
+
+        self.bytes_count += self.buffer_len as u128;
+        self.buffer[self.buffer_len..].fill(0);
+        let block = self.buffer;
+        self.compress(&block, true);
+
+        let mut digest = [0u8; BLAKE2B_MAX_OUTPUT_LEN];
+        digest.chunks_exact_mut(8).zip(self.h).for_each(|(bytes, word)| bytes.clone_from_slice(&word.to_le_bytes()));
+        output.clone_from_slice(&digest[..self.output_len]);
+        digest.zeroize();
+    }
+
+    fn compress(&mut self, block: &[u8; BLOCK_LEN], last_block: bool) {
+        let mut m = [0u64; 16];
+        m.iter_mut()
+            .zip(block.chunks_exact(8))
+            .for_each(|(m, bytes)| *m = u64::from_le_bytes(bytes.try_into().unwrap()));
+
+        let mut v = [0u64; 16];
+        v[..8].clone_from_slice(&self.h);
+        v[8..].clone_from_slice(&IV);
+        v[12] ^= self.bytes_count as u64;
+        v[13] ^= (self.bytes_count >> 64) as u64;
+        if last_block {
+            v[14] = !v[14];
+        }
+
+        for round in 0..12 {
+            let s = &SIGMA[round % 10];
+            g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+
+        self.h.iter_mut().zip(v[..8].iter().zip(&v[8..])).for_each(|(h, (v_low, v_high))| *h ^= v_low ^ v_high);
+    }
+}
+
+impl Debug for Blake2bState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Blake2bState").field("output_len", &self.output_len).finish_non_exhaustive()
+    }
+}
+
+impl Drop for Blake2bState {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for Blake2bState {
+    fn zeroize(&mut self) {
+        self.h.zeroize();
+        self.bytes_count.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+    }
+}
+
+/// The mixing function of BLAKE2b
+fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
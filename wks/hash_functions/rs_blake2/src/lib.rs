@@ -1,15 +1,57 @@
 //! # BLAKE2 - `rs_blake2` - Cryptographic Hash Function
 //!
-//! **Important Note: This crate is still a work in progress and is not ready for consumption.**
-//!
 //! BLAKE2 is a cryptographic hash function that is faster than MD5, SHA-1, SHA-2, and SHA-3, yet is at least as secure
-//! as the latest standard, SHA-3. Published by Aumasson, Neves, Wilcox-O'Hearn, and Winnerlein in 2012, it is used in
-//! various applications such as:
+//! as the latest standard, SHA-3. Published by Aumasson, Neves, Wilcox-O'Hearn, and Winnerlein in 2012, and specified
+//! in RFC 7693, it is used in various applications such as:
 //! - Cryptographic libraries and frameworks, for instance, libsodium.
 //! - File integrity checking and deduplication.
-//! - Secure communication protocols like Transport Layer Security (TLS), Secure Shell (SSH), Internet Protocol Security
-//! (IPSec), etc.
+//! - Password hashing, as the core of Argon2.
+//!
+//! This crate implements BLAKE2b, the variant optimized for 64-bit platforms, with digests of 1 up to 64 bytes and an
+//! optional key of up to 64 bytes, as part of the [RustyShield](https://docs.rs/rs_shield/latest/rs_shield/) project.
+//! BLAKE2s is not implemented yet.
+//!
+//! ## Usage
+//!
+//! `Blake2bHasher` fixes its digest length at compile time, and follows the `Hasher` and `HasherContext` traits like
+//! every other hasher of the project. `Blake2bState` picks it at runtime instead.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use BLAKE2b-256 in Rust:
+//!
+//! ```rust
+//! # use std::hash::Hasher;
+//! # use rs_blake2::{Blake2b256Hasher, HasherContext};
+//! let mut blake2b256hasher = Blake2b256Hasher::default();
+//! blake2b256hasher.write(b"abc");
+//!
+//! let result = HasherContext::finish(&mut blake2b256hasher);
+//! assert_eq!(format!("{result:02x}"), "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319");
+//! ```
+//!
+//! ## Use Cases
+//!
+//! BLAKE2b is recommended for a variety of tasks, including:
+//!
+//! - Fast hashing of large files and content addressed storage.
+//! - Message authentication, through its native keyed mode.
+//! - Building blocks of other constructions, such as Argon2.
 //!
-//! This crate implements BLAKE2 as part of the [RustyShield](https://docs.rs/rs_shield/latest/rs_shield/) project.
 
 #![no_std]
+
+pub use crate::blake2b_hasher::Blake2bHasher;
+pub use crate::blake2b_state::{Blake2bState, BLAKE2B_MAX_OUTPUT_LEN};
+pub use rs_hasher_ctx::{HasherContext, Zeroize};
+
+mod blake2b_hasher;
+mod blake2b_state;
+
+#[cfg(test)]
+mod unit_tests;
+
+/// BLAKE2b with a 32 bytes digest
+pub type Blake2b256Hasher = Blake2bHasher<32>;
+/// BLAKE2b with a 64 bytes digest
+pub type Blake2b512Hasher = Blake2bHasher<64>;
@@ -0,0 +1,73 @@
+extern crate alloc;
+
+use crate::{Blake2b256Hasher, Blake2b512Hasher, Blake2bHasher, Blake2bState};
+use alloc::format;
+use alloc::vec::Vec;
+use core::hash::Hasher;
+use rs_hasher_ctx::{ByteArrayWrapper, HasherContext};
+
+#[test]
+fn assert_rfc7693_blake2b_512_sample() {
+    let mut blake2b512hasher = Blake2b512Hasher::default();
+    blake2b512hasher.write(b"abc");
+
+    assert_eq!(
+        format!("{:02x}", HasherContext::finish(&mut blake2b512hasher)),
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf19\
+        25ab92386edd4009923"
+    );
+}
+
+#[test]
+fn assert_empty_message() {
+    let mut blake2b512hasher = Blake2b512Hasher::default();
+
+    assert_eq!(
+        format!("{:02x}", HasherContext::finish(&mut blake2b512hasher)),
+        "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b\
+        755d56f701afe9be2ce"
+    );
+}
+
+#[test]
+fn assert_exactly_one_block_message() {
+    let mut blake2b512hasher = Blake2b512Hasher::default();
+    blake2b512hasher.write(&[0u8; 128]);
+
+    assert_eq!(
+        format!("{:02x}", HasherContext::finish(&mut blake2b512hasher)),
+        "865939e120e6805438478841afb739ae4250cf372653078a065cdcfffca4caf798e6d462b65d658fc165782640eded70963449ae1500f\
+        b0f24981d7727e22c41"
+    );
+}
+
+#[test]
+fn assert_keyed_blake2b_across_write_boundaries() {
+    let key: Vec<u8> = (0..64).collect();
+    let message: Vec<u8> = (0..=255).chain(0..=255).collect();
+
+    let mut blake2b512hasher = Blake2bHasher::<64>::new_keyed(&key);
+    message.chunks(37).for_each(|chunk| blake2b512hasher.write(chunk));
+
+    assert_eq!(
+        format!("{:02x}", HasherContext::finish(&mut blake2b512hasher)),
+        "31952478e1b6229b6bca296c643a3fdbe4aa2c2f7fea466675453d5f7f09427021a77b8625c78070c0f7ce564d8d257d7eb36495be769\
+        50c31a1a7d80ff8b1b4"
+    );
+}
+
+#[test]
+fn assert_digest_length_is_bound_to_the_parameter_block() {
+    let mut blake2b256hasher = Blake2b256Hasher::default();
+    blake2b256hasher.write(b"abc");
+    assert_eq!(
+        format!("{:02x}", HasherContext::finish(&mut blake2b256hasher)),
+        "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+    );
+
+    let mut blake2b = Blake2bState::new(20);
+    blake2b.update(b"abc");
+    let mut digest = [0u8; 20];
+    blake2b.finalize_into(&mut digest);
+    assert_eq!(format!("{:02x}", ByteArrayWrapper::from(digest)), "384264f676f39536840523f284921cdc68b6846b");
+}
@@ -0,0 +1,163 @@
+use crate::{bytepad, encode_string, CShakeState};
+use core::hash::Hasher;
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Midstate, MidstateError};
+use rs_internal_hasher::{HashAlgorithm, HashMidstate, HasherPadOps};
+use rs_internal_state::{ExtendedOutputFunction, KeccakXofReader, Zeroize};
+
+/// Domain separator of SHAKE, which cSHAKE falls back to when no function name nor customization string is given
+const SHAKE_DELIMITER: u8 = 0x1F;
+const LAST_BIT_PAD: u8 = 0x80;
+
+/// `CShakeHasher` is a type that provides the cSHAKE customizable extendable-output function, as specified in NIST
+/// SP 800-185, for the rate given by `RATE` in bytes.
+///
+/// On construction, the function name and the customization string are encoded with `encode_string`, padded with
+/// `bytepad` and absorbed, so that outputs of differently customized instances are unrelated even for equal inputs.
+/// When both strings are empty, cSHAKE is defined to be SHAKE, which is what `CShakeHasher::default()` computes.
+///
+/// The `CShakeHasher` struct adheres to Rust's `Hasher` trait, enabling you to use it interchangeably with other
+/// hashers in Rust.
+///
+/// ## Examples
+///
+/// The following example reproduces the first cSHAKE128 sample published by NIST:
+///
+///```rust
+/// # use std::hash::Hasher;
+/// # use rs_cshake::{CShake128Hasher, HasherContext};
+/// let mut cshake128hasher = CShake128Hasher::<32>::new(b"", b"Email Signature");
+/// cshake128hasher.write(&[0x00, 0x01, 0x02, 0x03]);
+///
+/// let result = HasherContext::finish(&mut cshake128hasher);
+/// assert_eq!(format!("{result:02x}"), "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5");
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CShakeHasher<const RATE: usize, const OUTPUT_SIZE: usize> {
+    hasher: GenericHasher<CShakeState<RATE, OUTPUT_SIZE>, OUTPUT_SIZE>,
+    customized: bool,
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> CShakeHasher<RATE, OUTPUT_SIZE> {
+    /// Creates a new cSHAKE hasher for the given function name and customization string.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_name` - The function name `N`, reserved by NIST for functions defined on top of cSHAKE, such as
+    ///   `b"KMAC"`. Applications are expected to leave it empty.
+    /// * `customization` - The customization string `S`, chosen by the application to domain separate its outputs.
+    pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+        let mut cshake_hasher = Self::default();
+
+        if function_name.is_empty() && customization.is_empty() {
+            return cshake_hasher;
+        }
+
+        let mut block = [0u8; RATE];
+        for (i, byte) in bytepad(encode_string(function_name).chain(encode_string(customization)), RATE).enumerate() {
+            block[i % RATE] = byte;
+
+            if i % RATE == RATE - 1 {
+                cshake_hasher.hasher.state.hash_block(&block);
+            }
+        }
+        cshake_hasher.customized = true;
+
+        cshake_hasher
+    }
+
+    /// Finishes the absorbing phase and returns a reader over the unbounded cSHAKE output stream.
+    pub fn finish_xof(&mut self) -> KeccakXofReader<u64, RATE> {
+        self.finish_absorbing().into()
+    }
+
+    fn finish_absorbing(&mut self) -> CShakeState<RATE, OUTPUT_SIZE> {
+        if self.customized {
+            return HasherContext::finish(&mut self.hasher);
+        }
+
+        let tail_len = self.hasher.padding.size_mod_pad();
+        let mut last_block = [0u8; RATE];
+
+        last_block[..tail_len].clone_from_slice(&self.hasher.padding[..tail_len]);
+        last_block[tail_len] ^= SHAKE_DELIMITER;
+        last_block[RATE - 1] ^= LAST_BIT_PAD;
+        self.hasher.state.hash_block(&last_block);
+
+        self.hasher.state.clone()
+    }
+
+    /// Tags customized instances apart from the plain ones, which are finalized as SHAKE.
+    fn midstate_tag(customized: bool) -> &'static str {
+        if customized {
+            CShakeState::<RATE, OUTPUT_SIZE>::ALGORITHM
+        } else {
+            "cSHAKE/SHAKE"
+        }
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Default for CShakeHasher<RATE, OUTPUT_SIZE> {
+    fn default() -> Self {
+        Self {
+            hasher: GenericHasher::default(),
+            customized: false,
+        }
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> From<CShakeHasher<RATE, OUTPUT_SIZE>>
+    for CShakeState<RATE, OUTPUT_SIZE>
+{
+    fn from(value: CShakeHasher<RATE, OUTPUT_SIZE>) -> Self {
+        value.hasher.state
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Hasher for CShakeHasher<RATE, OUTPUT_SIZE> {
+    fn finish(&self) -> u64 {
+        self.clone().finish_absorbing().state_to_u64()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes)
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for CShakeHasher<RATE, OUTPUT_SIZE> {
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn finish(&mut self) -> Self::Output {
+        self.finish_absorbing().squeeze().into()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Midstate for CShakeHasher<RATE, OUTPUT_SIZE> {
+    fn midstate_len(&self) -> usize {
+        self.hasher.tagged_midstate_len(Self::midstate_tag(self.customized))
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.hasher.export_tagged_midstate(Self::midstate_tag(self.customized), bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        match GenericHasher::import_tagged_midstate(Self::midstate_tag(true), bytes) {
+            Err(MidstateError::AlgorithmMismatch) => {
+                GenericHasher::import_tagged_midstate(Self::midstate_tag(false), bytes).map(|hasher| Self {
+                    hasher,
+                    customized: false,
+                })
+            }
+            hasher => hasher.map(|hasher| Self {
+                hasher,
+                customized: true,
+            }),
+        }
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Zeroize for CShakeHasher<RATE, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.hasher.zeroize();
+    }
+}
@@ -0,0 +1,110 @@
+use crate::CShakeHasher;
+use core::hash::BuildHasher;
+use rs_hasher_ctx::ByteArrayWrapper;
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, KeccakU128Size};
+use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge, KeccakXofReader, Zeroize};
+
+/// Domain separator appended by cSHAKE whenever a function name or a customization string is in use
+const CSHAKE_DELIMITER: u8 = 0x04;
+
+/// `CShakeState` represents the state of a cSHAKE hashing process, for the rate given by `RATE` in bytes.
+///
+/// `CShake128State` and `CShake256State` are the instances with the rates defined by NIST SP 800-185, and the ones
+/// that should be used in practice.
+///
+/// It holds intermediate hash calculations. However, it's important to note that starting a hashing process from an
+/// arbitrary `CShakeState` is not equivalent to resuming the original process that produced that state, as neither the
+/// pad contents nor the function name and customization string choice are part of it.
+///
+/// # Example
+///
+/// A default `CShakeState` builds a hasher with empty function name and customization string, which SP 800-185
+/// defines to be equivalent to SHAKE:
+///
+/// ```rust
+/// # use std::hash::{BuildHasher, Hasher};
+/// # use rs_cshake::{CShake128State, HasherContext};
+/// let mut cshake128hasher = CShake128State::<10>::default().build_hasher();
+/// cshake128hasher.write(b"abc");
+///
+/// let result = HasherContext::finish(&mut cshake128hasher);
+/// assert_eq!(format!("{result:02x}"), "5881092dd818bf5cf8a3");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CShakeState<const RATE: usize, const OUTPUT_SIZE: usize> {
+    sponge: KeccakSponge<u64, RATE, OUTPUT_SIZE>,
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> BuildHasher for CShakeState<RATE, OUTPUT_SIZE> {
+    type Hasher = CShakeHasher<RATE, OUTPUT_SIZE>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        CShakeHasher::default()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> BytesLen for CShakeState<RATE, OUTPUT_SIZE> {
+    fn len() -> usize {
+        RATE
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> ExtendedOutputFunction<OUTPUT_SIZE>
+    for CShakeState<RATE, OUTPUT_SIZE>
+{
+    fn squeeze_u64(&self) -> u64 {
+        self.sponge.squeeze_u64()
+    }
+
+    fn squeeze(&mut self) -> [u8; OUTPUT_SIZE] {
+        self.sponge.squeeze()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> From<CShakeState<RATE, OUTPUT_SIZE>>
+    for ByteArrayWrapper<OUTPUT_SIZE>
+{
+    fn from(mut value: CShakeState<RATE, OUTPUT_SIZE>) -> Self {
+        value.squeeze().into()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> From<CShakeState<RATE, OUTPUT_SIZE>> for KeccakXofReader<u64, RATE> {
+    fn from(value: CShakeState<RATE, OUTPUT_SIZE>) -> Self {
+        value.sponge.into()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> HashAlgorithm for CShakeState<RATE, OUTPUT_SIZE> {
+    type Padding = GenericPad<KeccakU128Size, RATE, CSHAKE_DELIMITER>;
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn hash_block(&mut self, bytes: &[u8]) {
+        self.sponge.absorb(bytes);
+    }
+
+    fn state_to_u64(&self) -> u64 {
+        self.squeeze_u64()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> HashMidstate for CShakeState<RATE, OUTPUT_SIZE> {
+    const ALGORITHM: &'static str = "cSHAKE";
+    const MIDSTATE_LEN: usize = KeccakSponge::<u64, RATE, OUTPUT_SIZE>::LANES_BYTES_LEN;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        self.sponge.export_lanes(bytes)
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        Self {
+            sponge: KeccakSponge::import_lanes(bytes),
+        }
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Zeroize for CShakeState<RATE, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+    }
+}
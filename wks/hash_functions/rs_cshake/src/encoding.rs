@@ -0,0 +1,145 @@
+use core::array::IntoIter;
+use core::iter::{Chain, Copied};
+use core::slice::Iter;
+
+const MAX_ENCODED_LEN: usize = 9;
+
+/// `IntegerEncoding` holds the output of `left_encode` or `right_encode`, as defined in NIST SP 800-185, section 2.3.1.
+///
+/// The integer is encoded as the minimal big endian byte string representing it, prefixed (`left_encode`) or suffixed
+/// (`right_encode`) by a single byte holding that string length. As every length handled by this crate fits in an
+/// `u64`, the encoding is at most 9 bytes long.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IntegerEncoding {
+    bytes: [u8; MAX_ENCODED_LEN],
+    len: usize,
+}
+
+impl AsRef<[u8]> for IntegerEncoding {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl IntoIterator for IntegerEncoding {
+    type Item = u8;
+    type IntoIter = core::iter::Take<IntoIter<u8, MAX_ENCODED_LEN>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes.into_iter().take(self.len)
+    }
+}
+
+fn significant_bytes(x: u64) -> usize {
+    (8 - x.leading_zeros() as usize / 8).max(1)
+}
+
+/// Encodes `x` as a byte string that can be unambiguously parsed from the beginning of the string.
+///
+/// # Example
+///
+/// ```rust
+/// # use rs_cshake::left_encode;
+/// assert_eq!(left_encode(0).as_ref(), [0x01, 0x00]);
+/// assert_eq!(left_encode(168).as_ref(), [0x01, 0xA8]);
+/// assert_eq!(left_encode(256).as_ref(), [0x02, 0x01, 0x00]);
+/// ```
+pub fn left_encode(x: u64) -> IntegerEncoding {
+    let n = significant_bytes(x);
+    let mut bytes = [0u8; MAX_ENCODED_LEN];
+
+    bytes[0] = n as u8;
+    bytes[1..=n].clone_from_slice(&x.to_be_bytes()[8 - n..]);
+
+    IntegerEncoding {
+        bytes,
+        len: n + 1,
+    }
+}
+
+/// Encodes `x` as a byte string that can be unambiguously parsed from the end of the string.
+///
+/// # Example
+///
+/// ```rust
+/// # use rs_cshake::right_encode;
+/// assert_eq!(right_encode(0).as_ref(), [0x00, 0x01]);
+/// assert_eq!(right_encode(256).as_ref(), [0x01, 0x00, 0x02]);
+/// ```
+pub fn right_encode(x: u64) -> IntegerEncoding {
+    let n = significant_bytes(x);
+    let mut bytes = [0u8; MAX_ENCODED_LEN];
+
+    bytes[..n].clone_from_slice(&x.to_be_bytes()[8 - n..]);
+    bytes[n] = n as u8;
+
+    IntegerEncoding {
+        bytes,
+        len: n + 1,
+    }
+}
+
+/// The byte iterator returned by `encode_string`
+pub type EncodedString<'a> = Chain<<IntegerEncoding as IntoIterator>::IntoIter, Copied<Iter<'a, u8>>>;
+
+/// Encodes a byte string as `left_encode(len(s)) || s`, with the length expressed in bits.
+///
+/// # Example
+///
+/// ```rust
+/// # use rs_cshake::encode_string;
+/// assert!(encode_string(b"").eq([0x01, 0x00]));
+/// assert!(encode_string(b"KMAC").eq([0x01, 0x20, b'K', b'M', b'A', b'C']));
+/// ```
+pub fn encode_string(s: &[u8]) -> EncodedString<'_> {
+    left_encode(s.len() as u64 * 8).into_iter().chain(s.iter().copied())
+}
+
+/// `Bytepad` is the byte iterator returned by `bytepad`.
+#[derive(Clone, Debug)]
+pub struct Bytepad<I> {
+    iter: Chain<<IntegerEncoding as IntoIterator>::IntoIter, I>,
+    w: usize,
+    count: usize,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Bytepad<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = match self.iter.next() {
+            Some(byte) => byte,
+            None if !self.count.is_multiple_of(self.w) => 0,
+            None => return None,
+        };
+        self.count += 1;
+
+        Some(byte)
+    }
+}
+
+/// Prepends `left_encode(w)` to `x` and pads the result with zeros up to a multiple of `w` bytes.
+///
+/// # Panics
+///
+/// Panics if `w` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// # use rs_cshake::{bytepad, encode_string};
+/// let padded = bytepad(encode_string(b"KMAC"), 8);
+/// assert!(padded.eq([0x01, 0x08, 0x01, 0x20, b'K', b'M', b'A', b'C']));
+///
+/// let padded = bytepad(encode_string(b"KMAC").chain(encode_string(b"")), 8);
+/// assert!(padded.eq([0x01, 0x08, 0x01, 0x20, b'K', b'M', b'A', b'C', 0x01, 0x00, 0, 0, 0, 0, 0, 0]));
+/// ```
+pub fn bytepad<I: Iterator<Item = u8>>(x: I, w: usize) -> Bytepad<I> {
+    assert!(w > 0, "bytepad width must be greater than zero");
+
+    Bytepad {
+        iter: left_encode(w as u64).into_iter().chain(x),
+        w,
+        count: 0,
+    }
+}
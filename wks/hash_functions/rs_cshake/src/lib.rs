@@ -0,0 +1,75 @@
+//! # cSHAKE `rs_cshake` - Customizable SHAKE function
+//!
+//! cSHAKE is the customizable variant of the SHAKE extendable-output functions, specified by the National Institute of
+//! Standards and Technology (NIST) in SP 800-185. Besides the input data, cSHAKE takes a function name and a
+//! customization string, so that distinct applications sharing the same input obtain unrelated outputs.
+//!
+//! This crate also provides the encoding primitives defined in SP 800-185 - `left_encode`, `right_encode`,
+//! `encode_string` and `bytepad` - which KMAC, TupleHash and ParallelHash are built upon.
+//!
+//! ## Usage
+//!
+//! The crate offers a straightforward API. Users can create a new cSHAKE hasher instance with a function name and a
+//! customization string, update it with input data, and finalize to get the resultant hash of arbitrary length.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use the cSHAKE256 function in Rust:
+//!
+//! ```rust
+//! # use std::hash::Hasher;
+//! # use rs_cshake::{CShake256Hasher, HasherContext, XofReader};
+//! let mut cshake256hasher = CShake256Hasher::<64>::new(b"", b"Email Signature");
+//! cshake256hasher.write(&[0x00, 0x01, 0x02, 0x03]);
+//!
+//! let mut reader = cshake256hasher.clone().finish_xof();
+//! let result = HasherContext::finish(&mut cshake256hasher);
+//! assert_eq!(
+//!     format!("{result:02x}"),
+//!     "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+//!     64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+//! );
+//!
+//! let mut streamed_result = [0u8; 64];
+//! reader.read(&mut streamed_result);
+//! assert_eq!(result, streamed_result);
+//! ```
+//!
+//! ## Use Cases
+//!
+//! cSHAKE is recommended whenever an extendable-output function has to be domain separated, such as:
+//!
+//! - Deriving independent outputs for different purposes from the same input.
+//! - Building other NIST SP 800-185 functions, like KMAC, TupleHash and ParallelHash.
+//!
+
+#![no_std]
+
+pub use crate::{
+    cshake_hasher::CShakeHasher,
+    cshake_state::CShakeState,
+    encoding::{bytepad, encode_string, left_encode, right_encode, Bytepad, EncodedString, IntegerEncoding},
+};
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
+pub use rs_internal_state::XofReader;
+
+mod cshake_hasher;
+mod cshake_state;
+mod encoding;
+
+#[cfg(test)]
+mod unit_tests;
+
+/// Rate in bytes of the 128 bits security strength instances of SP 800-185 functions
+pub const RATE_128: usize = 168;
+/// Rate in bytes of the 256 bits security strength instances of SP 800-185 functions
+pub const RATE_256: usize = 136;
+
+/// cSHAKE128 hasher, with a 128 bits security strength
+pub type CShake128Hasher<const OUTPUT_SIZE: usize> = CShakeHasher<RATE_128, OUTPUT_SIZE>;
+/// cSHAKE256 hasher, with a 256 bits security strength
+pub type CShake256Hasher<const OUTPUT_SIZE: usize> = CShakeHasher<RATE_256, OUTPUT_SIZE>;
+/// cSHAKE128 state, with a 128 bits security strength
+pub type CShake128State<const OUTPUT_SIZE: usize> = CShakeState<RATE_128, OUTPUT_SIZE>;
+/// cSHAKE256 state, with a 256 bits security strength
+pub type CShake256State<const OUTPUT_SIZE: usize> = CShakeState<RATE_256, OUTPUT_SIZE>;
@@ -0,0 +1,61 @@
+extern crate alloc;
+
+use crate::{bytepad, encode_string, left_encode, right_encode};
+use alloc::vec::Vec;
+
+#[test]
+fn assert_left_encode_correctness() {
+    assert_eq!(left_encode(0).as_ref(), [0x01, 0x00]);
+    assert_eq!(left_encode(136).as_ref(), [0x01, 0x88]);
+    assert_eq!(left_encode(255).as_ref(), [0x01, 0xFF]);
+    assert_eq!(left_encode(256).as_ref(), [0x02, 0x01, 0x00]);
+    assert_eq!(left_encode(u64::MAX).as_ref(), [0x08, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+}
+
+#[test]
+fn assert_right_encode_correctness() {
+    assert_eq!(right_encode(0).as_ref(), [0x00, 0x01]);
+    assert_eq!(right_encode(256).as_ref(), [0x01, 0x00, 0x02]);
+    assert_eq!(right_encode(512).as_ref(), [0x02, 0x00, 0x02]);
+    assert_eq!(right_encode(u64::MAX).as_ref(), [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x08]);
+}
+
+#[test]
+fn assert_encoded_integer_iterates_over_its_significant_bytes_only() {
+    assert_eq!(left_encode(0x0102).into_iter().collect::<Vec<u8>>(), [0x02, 0x01, 0x02]);
+    assert_eq!(right_encode(0x0102).into_iter().collect::<Vec<u8>>(), [0x01, 0x02, 0x02]);
+}
+
+#[test]
+fn assert_encode_string_correctness() {
+    assert_eq!(encode_string(b"").collect::<Vec<u8>>(), [0x01, 0x00]);
+    assert_eq!(
+        encode_string(b"Email Signature").collect::<Vec<u8>>(),
+        [0x01, 0x78, 0x45, 0x6D, 0x61, 0x69, 0x6C, 0x20, 0x53, 0x69, 0x67, 0x6E, 0x61, 0x74, 0x75, 0x72, 0x65]
+    );
+}
+
+#[test]
+fn assert_bytepad_pads_to_multiple_of_width() {
+    let padded = bytepad(encode_string(b"").chain(encode_string(b"Email Signature")), 168).collect::<Vec<u8>>();
+
+    assert_eq!(padded.len(), 168);
+    assert_eq!(padded[..6], [0x01, 0xA8, 0x01, 0x00, 0x01, 0x78]);
+    assert_eq!(padded[6..21], *b"Email Signature");
+    assert!(padded[21..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn assert_bytepad_does_not_pad_aligned_input() {
+    let padded = bytepad([0xAA; 6].into_iter(), 8).collect::<Vec<u8>>();
+
+    assert_eq!(padded, [0x01, 0x08, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA]);
+}
+
+#[test]
+fn assert_bytepad_spans_multiple_widths() {
+    let padded = bytepad([0xAA; 7].into_iter(), 8).collect::<Vec<u8>>();
+
+    assert_eq!(padded.len(), 16);
+    assert!(padded[9..].iter().all(|&b| b == 0));
+}
@@ -0,0 +1,2 @@
+mod encoding;
+mod use_cases;
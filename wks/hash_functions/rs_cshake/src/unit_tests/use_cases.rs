@@ -0,0 +1,138 @@
+extern crate alloc;
+
+use crate::{CShake128Hasher, CShake128State, CShake256Hasher, HasherContext, Midstate, MidstateError, XofReader};
+use alloc::{format, vec::Vec};
+use core::hash::{BuildHasher, Hasher};
+
+const EMAIL_SIGNATURE: &[u8; 15] = b"Email Signature";
+
+fn sample_data(len: u8) -> Vec<u8> {
+    (0..len).collect()
+}
+
+#[test]
+fn assert_cshake128_nist_sample_1() {
+    let mut cshake128hasher = CShake128Hasher::<32>::new(b"", EMAIL_SIGNATURE);
+    cshake128hasher.write(&sample_data(4));
+
+    let result = HasherContext::finish(&mut cshake128hasher);
+    assert_eq!(format!("{result:02x}"), "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5");
+}
+
+#[test]
+fn assert_cshake128_nist_sample_2() {
+    let mut cshake128hasher = CShake128Hasher::<32>::new(b"", EMAIL_SIGNATURE);
+    cshake128hasher.write(&sample_data(200));
+
+    let result = HasherContext::finish(&mut cshake128hasher);
+    assert_eq!(format!("{result:02x}"), "c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b");
+}
+
+#[test]
+fn assert_cshake256_nist_sample_3() {
+    let mut cshake256hasher = CShake256Hasher::<64>::new(b"", EMAIL_SIGNATURE);
+    cshake256hasher.write(&sample_data(4));
+
+    let result = HasherContext::finish(&mut cshake256hasher);
+    assert_eq!(
+        format!("{result:02x}"),
+        "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd164020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+    );
+}
+
+#[test]
+fn assert_cshake256_nist_sample_4() {
+    let mut cshake256hasher = CShake256Hasher::<64>::new(b"", EMAIL_SIGNATURE);
+    cshake256hasher.write(&sample_data(200));
+
+    let result = HasherContext::finish(&mut cshake256hasher);
+    assert_eq!(
+        format!("{result:02x}"),
+        "07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac86430273091727f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb"
+    );
+}
+
+#[test]
+fn assert_empty_function_name_and_customization_fall_back_to_shake() {
+    let mut default_hasher = CShake128State::<32>::default().build_hasher();
+    let mut uncustomized_hasher = CShake128Hasher::<32>::new(b"", b"");
+
+    let default_result = HasherContext::finish(&mut default_hasher);
+    let uncustomized_result = HasherContext::finish(&mut uncustomized_hasher);
+
+    assert_eq!(default_result, uncustomized_result);
+    assert_eq!(format!("{default_result:02x}"), "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26");
+}
+
+#[test]
+fn assert_function_name_domain_separates_outputs() {
+    let mut kmac_named_hasher = CShake128Hasher::<32>::new(b"KMAC", b"");
+    let mut shake_hasher = CShake128Hasher::<32>::default();
+    kmac_named_hasher.write(b"abc");
+    shake_hasher.write(b"abc");
+
+    let kmac_named_result = HasherContext::finish(&mut kmac_named_hasher);
+    let shake_result = HasherContext::finish(&mut shake_hasher);
+
+    assert_ne!(kmac_named_result, shake_result);
+    assert_eq!(format!("{kmac_named_result:02x}"), "80b57b58ecfc9733aebe9cb050e7ceb2f97a78dddde4caa729507f60edb15632");
+}
+
+#[test]
+fn assert_split_writes_match_single_write() {
+    let data = sample_data(200);
+    let mut single_write_hasher = CShake128Hasher::<32>::new(b"", EMAIL_SIGNATURE);
+    let mut split_writes_hasher = CShake128Hasher::<32>::new(b"", EMAIL_SIGNATURE);
+
+    single_write_hasher.write(&data);
+    data.chunks(13).for_each(|chunk| split_writes_hasher.write(chunk));
+
+    assert_eq!(single_write_hasher.finish(), split_writes_hasher.finish());
+    assert_eq!(HasherContext::finish(&mut single_write_hasher), HasherContext::finish(&mut split_writes_hasher));
+}
+
+#[test]
+fn assert_u64_finish_matches_output_prefix() {
+    let mut cshake128hasher = CShake128Hasher::<32>::new(b"", EMAIL_SIGNATURE);
+    cshake128hasher.write(&sample_data(4));
+
+    assert_eq!(cshake128hasher.finish(), 0xC1C36925B6409A04);
+}
+
+#[test]
+fn assert_xof_reader_extends_fixed_size_output() {
+    let mut cshake128hasher = CShake128Hasher::<32>::new(b"", EMAIL_SIGNATURE);
+    cshake128hasher.write(&sample_data(200));
+
+    let fixed_size_result = HasherContext::finish(&mut cshake128hasher.clone());
+    let mut reader = cshake128hasher.finish_xof();
+    let mut streamed_result = [0u8; 400];
+    reader.read(&mut streamed_result);
+
+    assert_eq!(fixed_size_result, <[u8; 32]>::try_from(&streamed_result[..32]).unwrap());
+}
+
+#[test]
+fn assert_cshake_midstate_keeps_the_customization() {
+    for customization in [&b""[..], EMAIL_SIGNATURE] {
+        let mut cshake128hasher = CShake128Hasher::<32>::new(b"", customization);
+        cshake128hasher.write(&sample_data(100));
+
+        let mut midstate = [0u8; 512];
+        let len = cshake128hasher.export_midstate(&mut midstate).unwrap();
+        let mut resumed = CShake128Hasher::<32>::import_midstate(&midstate[..len]).unwrap();
+
+        cshake128hasher.write(&sample_data(200));
+        resumed.write(&sample_data(200));
+        assert_eq!(HasherContext::finish(&mut resumed), HasherContext::finish(&mut cshake128hasher));
+    }
+}
+
+#[test]
+fn assert_cshake_midstate_is_bound_to_the_rate() {
+    let cshake128hasher = CShake128Hasher::<32>::new(b"", EMAIL_SIGNATURE);
+
+    let mut midstate = [0u8; 512];
+    let len = cshake128hasher.export_midstate(&mut midstate).unwrap();
+    assert_eq!(CShake256Hasher::<32>::import_midstate(&midstate[..len]), Err(MidstateError::AlgorithmMismatch));
+}
@@ -0,0 +1,121 @@
+use crate::{DrbgError, DrbgMechanism, EntropySource, RngCore, MAX_REQUEST_LEN, MAX_SECURITY_STRENGTH};
+use rs_hasher_ctx::Zeroize;
+
+/// `Drbg<M: DrbgMechanism, E: EntropySource>` runs the DRBG mechanism `M` on the entropy drawn from the source `E`.
+///
+/// It is instantiated with a full security strength of entropy plus a nonce of half of it, both drawn from `E`. Once
+/// the reseed interval of the mechanism is reached, it reseeds on its own before generating more bits. With prediction
+/// resistance, it rather reseeds before every generate request.
+///
+/// ## Examples
+///
+/// Any `EntropySource` can seed the generator, which then serves as an `RngCore`:
+///
+///```rust
+/// # use rs_drbg::{Drbg, DrbgError, EntropySource, HmacDrbg, RngCore};
+/// # use rs_sha256::Sha256State;
+/// struct CounterEntropy(u8);
+///
+/// impl EntropySource for CounterEntropy {
+///     fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), DrbgError> {
+///         dest.iter_mut().for_each(|byte| {
+///             self.0 = self.0.wrapping_add(1);
+///             *byte = self.0;
+///         });
+///         Ok(())
+///     }
+/// }
+///
+/// let mut drbg = Drbg::<HmacDrbg<Sha256State, 32>, _>::new(CounterEntropy(0), b"app").unwrap().with_reseed_interval(2);
+///
+/// let mut key = [0u8; 32];
+/// drbg.fill_bytes(&mut key);
+/// drbg.fill_bytes(&mut key);
+/// assert_eq!(drbg.reseed_counter(), 3);
+///
+/// drbg.fill_bytes(&mut key);
+/// assert_eq!(drbg.reseed_counter(), 2);
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Drbg<M: DrbgMechanism, E: EntropySource> {
+    mechanism: M,
+    entropy_source: E,
+    prediction_resistance: bool,
+}
+
+impl<M: DrbgMechanism, E: EntropySource> Drbg<M, E> {
+    /// Instantiates the mechanism with entropy and a nonce drawn from `entropy_source`, and the optional
+    /// `personalization` string.
+    pub fn new(mut entropy_source: E, personalization: &[u8]) -> Result<Self, DrbgError> {
+        let mut entropy_input = [0u8; MAX_SECURITY_STRENGTH];
+        let mut nonce = [0u8; MAX_SECURITY_STRENGTH / 2];
+        let mechanism = entropy_source
+            .fill_entropy(&mut entropy_input[..M::SECURITY_STRENGTH])
+            .and_then(|_| entropy_source.fill_entropy(&mut nonce[..M::SECURITY_STRENGTH / 2]))
+            .and_then(|_| {
+                let nonce = &nonce[..M::SECURITY_STRENGTH / 2];
+                M::instantiate(&entropy_input[..M::SECURITY_STRENGTH], nonce, personalization)
+            });
+        entropy_input.zeroize();
+        nonce.zeroize();
+
+        Ok(Self {
+            mechanism: mechanism?,
+            entropy_source,
+            prediction_resistance: false,
+        })
+    }
+
+    /// Sets whether every generate request first reseeds the mechanism with fresh entropy.
+    pub fn with_prediction_resistance(mut self, prediction_resistance: bool) -> Self {
+        self.prediction_resistance = prediction_resistance;
+        self
+    }
+
+    /// Sets the count of generate requests allowed between two reseeds, clamped between 1 and `MAX_RESEED_INTERVAL`.
+    pub fn with_reseed_interval(mut self, reseed_interval: u64) -> Self {
+        self.mechanism.set_reseed_interval(reseed_interval);
+        self
+    }
+
+    /// Returns the count of generate requests since the last instantiation or reseed, plus one.
+    pub fn reseed_counter(&self) -> u64 {
+        self.mechanism.reseed_counter()
+    }
+
+    /// Reseeds the mechanism with entropy drawn from the entropy source and the optional `additional_input`.
+    pub fn reseed(&mut self, additional_input: &[u8]) -> Result<(), DrbgError> {
+        let mut entropy_input = [0u8; MAX_SECURITY_STRENGTH];
+        let result = self
+            .entropy_source
+            .fill_entropy(&mut entropy_input[..M::SECURITY_STRENGTH])
+            .and_then(|_| self.mechanism.reseed(&entropy_input[..M::SECURITY_STRENGTH], additional_input));
+        entropy_input.zeroize();
+
+        result
+    }
+
+    /// Fills the whole of `output`, of at most `MAX_REQUEST_LEN` bytes, with random bits bound to the optional
+    /// `additional_input`, reseeding first if prediction resistance is on or if the reseed interval is reached.
+    pub fn generate(&mut self, additional_input: &[u8], output: &mut [u8]) -> Result<(), DrbgError> {
+        if self.prediction_resistance {
+            self.reseed(additional_input)?;
+            return self.mechanism.generate(&[], output);
+        }
+
+        match self.mechanism.generate(additional_input, output) {
+            Err(DrbgError::ReseedRequired) => {
+                self.reseed(additional_input)?;
+                self.mechanism.generate(&[], output)
+            }
+            result => result,
+        }
+    }
+}
+
+impl<M: DrbgMechanism, E: EntropySource> RngCore for Drbg<M, E> {
+    /// Fills `dest` with random bytes, split into as many generate requests as its length needs.
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), DrbgError> {
+        dest.chunks_mut(MAX_REQUEST_LEN).try_for_each(|chunk| self.generate(&[], chunk))
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    check_entropy_input, check_input, check_request, security_strength, DrbgError, DrbgMechanism, MAX_RESEED_INTERVAL,
+};
+use core::fmt::{Debug, Formatter};
+use core::hash::Hasher;
+use core::marker::PhantomData;
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Zeroize};
+use rs_internal_hasher::HashAlgorithm;
+use rs_internal_state::BytesLen;
+
+/// Longest seed length of Hash_DRBG, of 888 bits, used with hash outputs longer than 256 bits
+const MAX_SEED_LEN: usize = 111;
+
+/// `HashDrbg<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize>` is the Hash_DRBG mechanism of SP 800-90A, over the
+/// hash function `H` whose output is `OUTPUT_SIZE` bytes long.
+///
+/// Its values `V` and `C` are `HashDrbg::SEED_LEN` bytes long, which is 55 bytes for hash outputs of up to 256 bits,
+/// and 111 bytes otherwise. Both are wiped on drop, and left out of the `Debug` output, which only shows the reseed
+/// counter.
+///
+/// ## Examples
+///
+/// The additional input of a generate request changes the bits returned, and all the following ones:
+///
+///```rust
+/// # use rs_drbg::{DrbgMechanism, HashDrbg};
+/// # use rs_sha512::Sha512State;
+/// let mut hash_drbg = HashDrbg::<Sha512State, 64>::instantiate(&[0x11; 32], &[0x22; 16], b"app").unwrap();
+/// let mut other_hash_drbg = hash_drbg.clone();
+///
+/// let (mut bits, mut other_bits) = ([0u8; 100], [0u8; 100]);
+/// hash_drbg.generate(b"", &mut bits).unwrap();
+/// other_hash_drbg.generate(b"request 1", &mut other_bits).unwrap();
+/// assert_ne!(bits, other_bits);
+///
+/// hash_drbg.generate(b"", &mut bits).unwrap();
+/// other_hash_drbg.generate(b"", &mut other_bits).unwrap();
+/// assert_ne!(bits, other_bits);
+///```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct HashDrbg<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> {
+    v: [u8; MAX_SEED_LEN],
+    c: [u8; MAX_SEED_LEN],
+    reseed_counter: u64,
+    reseed_interval: u64,
+    hash_function: PhantomData<H>,
+}
+
+impl<H, const OUTPUT_SIZE: usize> HashDrbg<H, OUTPUT_SIZE>
+where
+    H: BytesLen + Default + HashAlgorithm<Output = ByteArrayWrapper<OUTPUT_SIZE>>,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    /// Byte length of the values `V` and `C`, of 440 bits for hash outputs of up to 256 bits, and 888 bits otherwise
+    pub const SEED_LEN: usize = if OUTPUT_SIZE <= 32 {
+        55
+    } else {
+        MAX_SEED_LEN
+    };
+
+    /// Hashes the concatenation of `parts`
+    fn hash(parts: &[&[u8]]) -> [u8; OUTPUT_SIZE] {
+        let mut hasher = GenericHasher::<H, OUTPUT_SIZE>::default();
+        parts.iter().for_each(|part| hasher.write(part));
+        ByteArrayWrapper::from(HasherContext::finish(&mut hasher)).into()
+    }
+
+    /// The derivation function Hash_df, filling the whole of `output` out of the concatenation of `inputs`
+    fn hash_df(inputs: &[&[u8]], output: &mut [u8]) {
+        let bits_to_return = (output.len() as u32 * 8).to_be_bytes();
+        for (i, output_block) in output.chunks_mut(OUTPUT_SIZE).enumerate() {
+            let counter = [i as u8 + 1];
+            let mut hasher = GenericHasher::<H, OUTPUT_SIZE>::default();
+            hasher.write(&counter);
+            hasher.write(&bits_to_return);
+            inputs.iter().for_each(|input| hasher.write(input));
+
+            let mut block: [u8; OUTPUT_SIZE] = ByteArrayWrapper::from(HasherContext::finish(&mut hasher)).into();
+            output_block.clone_from_slice(&block[..output_block.len()]);
+            block.zeroize();
+        }
+    }
+
+    /// Derives `V` out of `seed_material`, then `C` out of `V`
+    fn seed(&mut self, seed_material: &[&[u8]]) {
+        let seed_len = Self::SEED_LEN;
+        Self::hash_df(seed_material, &mut self.v[..seed_len]);
+        Self::hash_df(&[&[0x00], &self.v[..seed_len]], &mut self.c[..seed_len]);
+        self.reseed_counter = 1;
+    }
+
+    /// The hash based generation of Hash_DRBG, filling the whole of `output` out of `V`
+    fn hashgen(&self, output: &mut [u8]) {
+        let mut data = self.v;
+        for output_block in output.chunks_mut(OUTPUT_SIZE) {
+            let mut block = Self::hash(&[&data[..Self::SEED_LEN]]);
+            output_block.clone_from_slice(&block[..output_block.len()]);
+            block.zeroize();
+            add_assign(&mut data[..Self::SEED_LEN], &[0x01]);
+        }
+        data.zeroize();
+    }
+}
+
+impl<H, const OUTPUT_SIZE: usize> DrbgMechanism for HashDrbg<H, OUTPUT_SIZE>
+where
+    H: BytesLen + Default + HashAlgorithm<Output = ByteArrayWrapper<OUTPUT_SIZE>>,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    const SECURITY_STRENGTH: usize = security_strength(OUTPUT_SIZE);
+
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self, DrbgError> {
+        check_entropy_input(entropy_input, Self::SECURITY_STRENGTH)?;
+        check_input(personalization)?;
+
+        let mut hash_drbg = Self {
+            v: [0u8; MAX_SEED_LEN],
+            c: [0u8; MAX_SEED_LEN],
+            reseed_counter: 1,
+            reseed_interval: MAX_RESEED_INTERVAL,
+            hash_function: PhantomData,
+        };
+        hash_drbg.seed(&[entropy_input, nonce, personalization]);
+
+        Ok(hash_drbg)
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+        check_entropy_input(entropy_input, Self::SECURITY_STRENGTH)?;
+        check_input(additional_input)?;
+
+        let mut v = self.v;
+        self.seed(&[&[0x01], &v[..Self::SEED_LEN], entropy_input, additional_input]);
+        v.zeroize();
+
+        Ok(())
+    }
+
+    fn generate(&mut self, additional_input: &[u8], output: &mut [u8]) -> Result<(), DrbgError> {
+        check_request(output)?;
+        check_input(additional_input)?;
+        if self.reseed_counter > self.reseed_interval {
+            return Err(DrbgError::ReseedRequired);
+        }
+
+        let seed_len = Self::SEED_LEN;
+        if !additional_input.is_empty() {
+            let mut w = Self::hash(&[&[0x02], &self.v[..seed_len], additional_input]);
+            add_assign(&mut self.v[..seed_len], &w);
+            w.zeroize();
+        }
+        self.hashgen(output);
+
+        let mut h = Self::hash(&[&[0x03], &self.v[..seed_len]]);
+        add_assign(&mut self.v[..seed_len], &h);
+        h.zeroize();
+        add_assign(&mut self.v[..seed_len], &self.c[..seed_len]);
+        add_assign(&mut self.v[..seed_len], &self.reseed_counter.to_be_bytes());
+        self.reseed_counter += 1;
+
+        Ok(())
+    }
+
+    fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    fn set_reseed_interval(&mut self, reseed_interval: u64) {
+        self.reseed_interval = reseed_interval.clamp(1, MAX_RESEED_INTERVAL);
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Debug for HashDrbg<H, OUTPUT_SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HashDrbg").field("reseed_counter", &self.reseed_counter).finish_non_exhaustive()
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Drop for HashDrbg<H, OUTPUT_SIZE> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Zeroize for HashDrbg<H, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.v.zeroize();
+        self.c.zeroize();
+    }
+}
+
+/// Adds the big-endian integer `addend` to the big-endian integer `accumulator`, modulo 2 to the power of the bit
+/// length of `accumulator`, which is at least as long as `addend`.
+fn add_assign(accumulator: &mut [u8], addend: &[u8]) {
+    let mut carry = 0u16;
+    let addend_bytes = addend.iter().rev().copied().chain(core::iter::repeat(0));
+    for (byte, addend_byte) in accumulator.iter_mut().rev().zip(addend_bytes) {
+        let sum = *byte as u16 + addend_byte as u16 + carry;
+        *byte = sum as u8;
+        carry = sum >> 8;
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    check_entropy_input, check_input, check_request, security_strength, DrbgError, DrbgMechanism, MAX_RESEED_INTERVAL,
+};
+use core::fmt::{Debug, Formatter};
+use rs_hasher_ctx::{ByteArrayWrapper, Zeroize};
+use rs_hmac::{Hmac, Mac};
+use rs_internal_hasher::HashAlgorithm;
+use rs_internal_state::BytesLen;
+
+/// `HmacDrbg<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize>` is the HMAC_DRBG mechanism of SP 800-90A, over the
+/// hash function `H` whose output is `OUTPUT_SIZE` bytes long.
+///
+/// It holds the HMAC context keyed with the current key `K`, along with the value `V`, so that the key is not expanded
+/// again for each block of output.
+///
+/// Both `K` and `V` are wiped on drop, and left out of the `Debug` output, which only shows the reseed counter.
+///
+/// ## Examples
+///
+/// Two instances seeded alike generate the same bits until one of them is reseeded:
+///
+///```rust
+/// # use rs_drbg::{DrbgMechanism, HmacDrbg};
+/// # use rs_sha256::Sha256State;
+/// let mut hmac_drbg = HmacDrbg::<Sha256State, 32>::instantiate(&[0x11; 32], &[0x22; 16], b"app").unwrap();
+/// let mut same_hmac_drbg = hmac_drbg.clone();
+///
+/// let (mut bits, mut same_bits) = ([0u8; 32], [0u8; 32]);
+/// hmac_drbg.generate(b"", &mut bits).unwrap();
+/// same_hmac_drbg.generate(b"", &mut same_bits).unwrap();
+/// assert_eq!(bits, same_bits);
+///
+/// same_hmac_drbg.reseed(&[0x33; 32], b"").unwrap();
+/// hmac_drbg.generate(b"", &mut bits).unwrap();
+/// same_hmac_drbg.generate(b"", &mut same_bits).unwrap();
+/// assert_ne!(bits, same_bits);
+/// assert_eq!(same_hmac_drbg.reseed_counter(), 2);
+///```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct HmacDrbg<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> {
+    key_hmac: Hmac<H, OUTPUT_SIZE>,
+    v: [u8; OUTPUT_SIZE],
+    reseed_counter: u64,
+    reseed_interval: u64,
+}
+
+impl<H, const OUTPUT_SIZE: usize> HmacDrbg<H, OUTPUT_SIZE>
+where
+    H: BytesLen + Default + HashAlgorithm<Output = ByteArrayWrapper<OUTPUT_SIZE>>,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    /// The update function of HMAC_DRBG, whose provided data is the concatenation of `provided_data`
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        for separator in [0x00, 0x01] {
+            let mut hmac = self.key_hmac.clone();
+            hmac.update(&self.v);
+            hmac.update(&[separator]);
+            provided_data.iter().for_each(|data| hmac.update(data));
+
+            let mut key = hmac.finalize();
+            self.key_hmac = Hmac::new(key.as_ref());
+            key.zeroize();
+            self.refresh_v();
+
+            if provided_data.iter().all(|data| data.is_empty()) {
+                break;
+            }
+        }
+    }
+
+    /// Replaces `V` with `HMAC(K, V)`
+    fn refresh_v(&mut self) {
+        let mut hmac = self.key_hmac.clone();
+        hmac.update(&self.v);
+        self.v = hmac.finalize().into();
+    }
+}
+
+impl<H, const OUTPUT_SIZE: usize> DrbgMechanism for HmacDrbg<H, OUTPUT_SIZE>
+where
+    H: BytesLen + Default + HashAlgorithm<Output = ByteArrayWrapper<OUTPUT_SIZE>>,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    const SECURITY_STRENGTH: usize = security_strength(OUTPUT_SIZE);
+
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self, DrbgError> {
+        check_entropy_input(entropy_input, Self::SECURITY_STRENGTH)?;
+        check_input(personalization)?;
+
+        let mut hmac_drbg = Self {
+            key_hmac: Hmac::new(&[0x00; OUTPUT_SIZE]),
+            v: [0x01; OUTPUT_SIZE],
+            reseed_counter: 1,
+            reseed_interval: MAX_RESEED_INTERVAL,
+        };
+        hmac_drbg.update(&[entropy_input, nonce, personalization]);
+
+        Ok(hmac_drbg)
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), DrbgError> {
+        check_entropy_input(entropy_input, Self::SECURITY_STRENGTH)?;
+        check_input(additional_input)?;
+
+        self.update(&[entropy_input, additional_input]);
+        self.reseed_counter = 1;
+
+        Ok(())
+    }
+
+    fn generate(&mut self, additional_input: &[u8], output: &mut [u8]) -> Result<(), DrbgError> {
+        check_request(output)?;
+        check_input(additional_input)?;
+        if self.reseed_counter > self.reseed_interval {
+            return Err(DrbgError::ReseedRequired);
+        }
+
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for output_block in output.chunks_mut(OUTPUT_SIZE) {
+            self.refresh_v();
+            output_block.clone_from_slice(&self.v[..output_block.len()]);
+        }
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+
+        Ok(())
+    }
+
+    fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    fn set_reseed_interval(&mut self, reseed_interval: u64) {
+        self.reseed_interval = reseed_interval.clamp(1, MAX_RESEED_INTERVAL);
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Debug for HmacDrbg<H, OUTPUT_SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HmacDrbg").field("reseed_counter", &self.reseed_counter).finish_non_exhaustive()
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Drop for HmacDrbg<H, OUTPUT_SIZE> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Zeroize for HmacDrbg<H, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.key_hmac.zeroize();
+        self.v.zeroize();
+    }
+}
@@ -0,0 +1,215 @@
+//! # DRBG `rs_drbg` - Deterministic Random Bit Generators
+//!
+//! A deterministic random bit generator (DRBG) stretches a seed of true entropy into an arbitrarily long stream of
+//! bits indistinguishable from random, as long as its internal state remains secret. NIST SP 800-90A specifies how
+//! such a generator is instantiated out of entropy, a nonce and an optional personalization string, how it is reseeded
+//! with fresh entropy, and how it generates bits, optionally bound to some additional input.
+//!
+//! This crate implements two of the mechanisms of SP 800-90A, generic over any hash function present in the
+//! encompassing project:
+//!
+//! - `HmacDrbg`, which is built upon `rs_hmac`.
+//! - `HashDrbg`, which is built upon the hash function alone.
+//!
+//! CTR_DRBG, the third mechanism of the recommendation, is built upon AES, and will join them once `rs_aes` is ready.
+//!
+//! ## Usage
+//!
+//! The mechanisms are deterministic: they are given their entropy explicitly through the `DrbgMechanism` trait, which
+//! is what validation against known answers requires. Applications rather wrap them into a `Drbg`, which draws the
+//! entropy from an `EntropySource`, reseeds automatically once the reseed interval is reached, optionally provides
+//! prediction resistance, and implements `RngCore`. With the `std` feature, `OsEntropy` draws the entropy from the
+//! operating system.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use HMAC_DRBG with SHA-256 in Rust, with the first test case of the DRBGVS response
+//! file for HMAC_DRBG, without prediction resistance:
+//!
+//! ```rust
+//! # use rs_drbg::{DrbgMechanism, HmacDrbg};
+//! # use rs_sha256::Sha256State;
+//! let entropy_input = [
+//!     0xCA, 0x85, 0x19, 0x11, 0x34, 0x93, 0x84, 0xBF, 0xFE, 0x89, 0xDE, 0x1C, 0xBD, 0xC4, 0x6E, 0x68, 0x31, 0xE4,
+//!     0x4D, 0x34, 0xA4, 0xFB, 0x93, 0x5E, 0xE2, 0x85, 0xDD, 0x14, 0xB7, 0x1A, 0x74, 0x88,
+//! ];
+//! let nonce = [0x65, 0x9B, 0xA9, 0x6C, 0x60, 0x1D, 0xC6, 0x9F, 0xC9, 0x02, 0x94, 0x08, 0x05, 0xEC, 0x0C, 0xA8];
+//! let mut hmac_drbg = HmacDrbg::<Sha256State, 32>::instantiate(&entropy_input, &nonce, &[]).unwrap();
+//!
+//! let mut returned_bits = [0u8; 128];
+//! hmac_drbg.generate(&[], &mut returned_bits).unwrap();
+//! hmac_drbg.generate(&[], &mut returned_bits).unwrap();
+//! assert_eq!(returned_bits[..8], [0xE5, 0x28, 0xE9, 0xAB, 0xF2, 0xDE, 0xCE, 0x54]);
+//! ```
+//!
+//! ## Use Cases
+//!
+//! DRBGs are recommended for a variety of tasks, including:
+//!
+//! - Generating keys, nonces and initialization vectors.
+//! - Deterministic signatures, such as the nonces of RFC 6979, drawn from HMAC_DRBG.
+//! - Expanding the entropy of a slow source into as many random bytes as an application needs.
+//!
+
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod drbg;
+mod hash_drbg;
+mod hmac_drbg;
+#[cfg(feature = "std")]
+mod os_entropy;
+
+pub use crate::drbg::Drbg;
+pub use crate::hash_drbg::HashDrbg;
+pub use crate::hmac_drbg::HmacDrbg;
+#[cfg(feature = "std")]
+pub use crate::os_entropy::OsEntropy;
+use core::fmt::{Display, Formatter};
+pub use rs_hasher_ctx::Zeroize;
+
+/// Largest count of generate requests between two reseeds allowed by SP 800-90A, which is also the default one
+pub const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+/// Largest count of bytes a single generate request can return, of 2^19 bits
+pub const MAX_REQUEST_LEN: usize = 1 << 16;
+/// Largest byte length of the entropy input, the personalization string and the additional input, of 2^35 bits
+const MAX_INPUT_LEN: u64 = 1 << 32;
+/// Highest security strength, in bytes, provided by the mechanisms of SP 800-90A
+const MAX_SECURITY_STRENGTH: usize = 32;
+
+/// `DrbgError` lists the reasons for which a DRBG refuses to be instantiated, reseeded or to generate bits.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DrbgError {
+    /// The entropy source failed to provide entropy
+    EntropyUnavailable,
+    /// The entropy input is shorter than the security strength of the mechanism
+    EntropyTooShort,
+    /// The entropy input, the personalization string or the additional input is longer than 2^35 bits
+    InputTooLong,
+    /// The generate request is longer than `MAX_REQUEST_LEN`
+    RequestTooLong,
+    /// The reseed interval is reached, so the mechanism must be reseeded before generating more bits
+    ReseedRequired,
+}
+
+impl Display for DrbgError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DrbgError::EntropyUnavailable => f.write_str("DRBG entropy source unavailable"),
+            DrbgError::EntropyTooShort => f.write_str("DRBG entropy input shorter than the security strength"),
+            DrbgError::InputTooLong => f.write_str("DRBG input too long"),
+            DrbgError::RequestTooLong => f.write_str("DRBG generate request too long"),
+            DrbgError::ReseedRequired => f.write_str("DRBG reseed required"),
+        }
+    }
+}
+
+/// `RngCore` is the interface of the random number generators of this project.
+///
+/// Only `RngCore::try_fill_bytes` must be provided, as the other methods are derived from it.
+pub trait RngCore {
+    /// Returns the next random `u32`, made of four random bytes in little-endian order.
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    /// Returns the next random `u64`, made of eight random bytes in little-endian order.
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Fills `dest` with random bytes, panicking if the generator fails.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(error) = self.try_fill_bytes(dest) {
+            panic!("random bytes generation failed: {error}");
+        }
+    }
+
+    /// Fills `dest` with random bytes.
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), DrbgError>;
+}
+
+/// `EntropySource` provides the entropy a `Drbg` is instantiated and reseeded with.
+///
+/// Every byte written by `EntropySource::fill_entropy` is expected to hold a full byte of entropy.
+pub trait EntropySource {
+    /// Fills the whole of `dest` with entropy, or fails with `DrbgError::EntropyUnavailable`.
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), DrbgError>;
+}
+
+/// `DrbgMechanism` is the interface shared by the DRBG mechanisms of SP 800-90A, whose entropy is given explicitly.
+pub trait DrbgMechanism: Sized {
+    /// Security strength of the mechanism, in bytes, which is also the shortest entropy input it accepts
+    const SECURITY_STRENGTH: usize;
+
+    /// Instantiates the mechanism out of `entropy_input`, `nonce` and the optional `personalization` string.
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self, DrbgError>;
+
+    /// Mixes `entropy_input` and the optional `additional_input` into the state, and resets the reseed counter.
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), DrbgError>;
+
+    /// Fills the whole of `output`, of at most `MAX_REQUEST_LEN` bytes, with random bits bound to the optional
+    /// `additional_input`.
+    ///
+    /// Fails with `DrbgError::ReseedRequired`, leaving the state untouched, once the reseed interval is reached.
+    fn generate(&mut self, additional_input: &[u8], output: &mut [u8]) -> Result<(), DrbgError>;
+
+    /// Reseeds with `entropy_input` and `additional_input`, then fills the whole of `output` with random bits, so that
+    /// they do not depend on any former state.
+    fn generate_with_prediction_resistance(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), DrbgError> {
+        self.reseed(entropy_input, additional_input)?;
+        self.generate(&[], output)
+    }
+
+    /// Returns the count of generate requests since the last instantiation or reseed, plus one.
+    fn reseed_counter(&self) -> u64;
+
+    /// Sets the count of generate requests allowed between two reseeds, clamped between 1 and `MAX_RESEED_INTERVAL`.
+    fn set_reseed_interval(&mut self, reseed_interval: u64);
+}
+
+/// Security strength, in bytes, of the mechanisms built upon a hash function producing `output_size` bytes
+const fn security_strength(output_size: usize) -> usize {
+    if output_size <= 20 {
+        16
+    } else if output_size <= 28 {
+        24
+    } else {
+        32
+    }
+}
+
+fn check_entropy_input(entropy_input: &[u8], security_strength: usize) -> Result<(), DrbgError> {
+    if entropy_input.len() < security_strength {
+        return Err(DrbgError::EntropyTooShort);
+    }
+
+    check_input(entropy_input)
+}
+
+fn check_input(input: &[u8]) -> Result<(), DrbgError> {
+    if input.len() as u64 > MAX_INPUT_LEN {
+        return Err(DrbgError::InputTooLong);
+    }
+
+    Ok(())
+}
+
+fn check_request(output: &[u8]) -> Result<(), DrbgError> {
+    if output.len() > MAX_REQUEST_LEN {
+        return Err(DrbgError::RequestTooLong);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::{DrbgError, EntropySource};
+
+/// `OsEntropy` draws entropy from the operating system, through `/dev/urandom` on Unix-like systems.
+///
+/// On other systems, it fails with `DrbgError::EntropyUnavailable`.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_drbg::{Drbg, HashDrbg, OsEntropy, RngCore};
+/// # use rs_sha256::Sha256State;
+/// let mut drbg = Drbg::<HashDrbg<Sha256State, 32>, _>::new(OsEntropy, b"app").unwrap();
+///
+/// let mut key = [0u8; 32];
+/// drbg.fill_bytes(&mut key);
+///```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct OsEntropy;
+
+impl EntropySource for OsEntropy {
+    #[cfg(unix)]
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), DrbgError> {
+        use std::io::Read;
+
+        std::fs::File::open("/dev/urandom")
+            .and_then(|mut urandom| urandom.read_exact(dest))
+            .map_err(|_| DrbgError::EntropyUnavailable)
+    }
+
+    #[cfg(not(unix))]
+    fn fill_entropy(&mut self, _dest: &mut [u8]) -> Result<(), DrbgError> {
+        Err(DrbgError::EntropyUnavailable)
+    }
+}
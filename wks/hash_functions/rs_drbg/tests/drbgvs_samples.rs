@@ -0,0 +1,522 @@
+use rs_drbg::{Drbg, DrbgError, DrbgMechanism, EntropySource, HashDrbg, HmacDrbg, RngCore, MAX_REQUEST_LEN};
+use rs_sha1::Sha1State;
+use rs_sha256::Sha256State;
+use rs_sha512::Sha512State;
+
+/// Sample of the DRBGVS response files, without prediction resistance nor reseed, whose returned bits are those of the
+/// second generate request
+struct DrbgvsSample {
+    entropy_input: &'static str,
+    nonce: &'static str,
+    additional_inputs: [&'static str; 2],
+    returned_bits: &'static str,
+}
+
+/// Sample going through a generate request, a reseed, another generate request, and a generate request with
+/// prediction resistance, along with the bits each request returns
+struct ReseedSample {
+    entropy_input: &'static str,
+    nonce: &'static str,
+    personalization: &'static str,
+    additional_inputs: [&'static str; 4],
+    entropy_input_reseed: &'static str,
+    entropy_input_prediction_resistance: &'static str,
+    returned_bits: [&'static str; 3],
+}
+
+/// Entropy source replaying the entropy inputs it is given, in order
+struct ReplayedEntropy(Vec<Vec<u8>>);
+
+impl EntropySource for ReplayedEntropy {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), DrbgError> {
+        if self.0.is_empty() || self.0[0].len() != dest.len() {
+            return Err(DrbgError::EntropyUnavailable);
+        }
+
+        dest.clone_from_slice(&self.0.remove(0));
+        Ok(())
+    }
+}
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn check_reseed_sample<M: DrbgMechanism>(sample: &ReseedSample) {
+    let mut drbg = M::instantiate(
+        &hex_to_bytes(sample.entropy_input),
+        &hex_to_bytes(sample.nonce),
+        &hex_to_bytes(sample.personalization),
+    )
+    .unwrap();
+    let mut returned_bits = vec![0u8; sample.returned_bits[0].len() / 2];
+
+    drbg.generate(&hex_to_bytes(sample.additional_inputs[0]), &mut returned_bits).unwrap();
+    assert_eq!(bytes_to_hex(&returned_bits), sample.returned_bits[0]);
+    assert_eq!(drbg.reseed_counter(), 2);
+
+    drbg.reseed(&hex_to_bytes(sample.entropy_input_reseed), &hex_to_bytes(sample.additional_inputs[1])).unwrap();
+    assert_eq!(drbg.reseed_counter(), 1);
+    drbg.generate(&hex_to_bytes(sample.additional_inputs[2]), &mut returned_bits).unwrap();
+    assert_eq!(bytes_to_hex(&returned_bits), sample.returned_bits[1]);
+
+    drbg.generate_with_prediction_resistance(
+        &hex_to_bytes(sample.entropy_input_prediction_resistance),
+        &hex_to_bytes(sample.additional_inputs[3]),
+        &mut returned_bits,
+    )
+    .unwrap();
+    assert_eq!(bytes_to_hex(&returned_bits), sample.returned_bits[2]);
+    assert_eq!(drbg.reseed_counter(), 2);
+}
+
+/// Replays `sample` through a `Drbg` with prediction resistance, whose entropy source provides the entropy inputs
+/// and the nonce of the sample
+fn check_prediction_resistance_sample<M: DrbgMechanism>(sample: &ReseedSample) {
+    let entropy_source = ReplayedEntropy(vec![
+        hex_to_bytes(sample.entropy_input),
+        hex_to_bytes(sample.nonce),
+        hex_to_bytes(sample.entropy_input_reseed),
+        hex_to_bytes(sample.entropy_input_prediction_resistance),
+    ]);
+    let mut drbg = Drbg::<M, _>::new(entropy_source, &hex_to_bytes(sample.personalization))
+        .unwrap()
+        .with_prediction_resistance(true);
+    let mut returned_bits = vec![0u8; sample.returned_bits[0].len() / 2];
+
+    drbg.reseed(&hex_to_bytes(sample.additional_inputs[0])).unwrap();
+    drbg.generate(&hex_to_bytes(sample.additional_inputs[3]), &mut returned_bits).unwrap();
+
+    let mut expected_drbg = M::instantiate(
+        &hex_to_bytes(sample.entropy_input),
+        &hex_to_bytes(sample.nonce),
+        &hex_to_bytes(sample.personalization),
+    )
+    .unwrap();
+    let mut expected_bits = returned_bits.clone();
+    expected_drbg
+        .reseed(&hex_to_bytes(sample.entropy_input_reseed), &hex_to_bytes(sample.additional_inputs[0]))
+        .unwrap();
+    expected_drbg
+        .generate_with_prediction_resistance(
+            &hex_to_bytes(sample.entropy_input_prediction_resistance),
+            &hex_to_bytes(sample.additional_inputs[3]),
+            &mut expected_bits,
+        )
+        .unwrap();
+    assert_eq!(returned_bits, expected_bits);
+
+    assert_eq!(drbg.generate(&[], &mut returned_bits), Err(DrbgError::EntropyUnavailable));
+}
+
+#[test]
+fn hmac_drbg_sha256_drbgvs_samples() {
+    for sample in HMAC_DRBG_SHA256_SAMPLES.iter() {
+        let mut hmac_drbg = HmacDrbg::<Sha256State, 32>::instantiate(
+            &hex_to_bytes(sample.entropy_input),
+            &hex_to_bytes(sample.nonce),
+            &[],
+        )
+        .unwrap();
+
+        let mut returned_bits = [0u8; 128];
+        hmac_drbg.generate(&hex_to_bytes(sample.additional_inputs[0]), &mut returned_bits).unwrap();
+        hmac_drbg.generate(&hex_to_bytes(sample.additional_inputs[1]), &mut returned_bits).unwrap();
+        assert_eq!(bytes_to_hex(&returned_bits), sample.returned_bits);
+    }
+}
+
+#[test]
+fn hmac_drbg_sha1_reseed_and_prediction_resistance() {
+    check_reseed_sample::<HmacDrbg<Sha1State, 20>>(&HMAC_DRBG_SHA1_SAMPLE);
+    check_prediction_resistance_sample::<HmacDrbg<Sha1State, 20>>(&HMAC_DRBG_SHA1_SAMPLE);
+}
+
+#[test]
+fn hmac_drbg_sha512_reseed_and_prediction_resistance() {
+    check_reseed_sample::<HmacDrbg<Sha512State, 64>>(&HMAC_DRBG_SHA512_SAMPLE);
+    check_prediction_resistance_sample::<HmacDrbg<Sha512State, 64>>(&HMAC_DRBG_SHA512_SAMPLE);
+}
+
+#[test]
+fn hash_drbg_sha1_reseed_and_prediction_resistance() {
+    check_reseed_sample::<HashDrbg<Sha1State, 20>>(&HASH_DRBG_SHA1_SAMPLE);
+    check_prediction_resistance_sample::<HashDrbg<Sha1State, 20>>(&HASH_DRBG_SHA1_SAMPLE);
+}
+
+#[test]
+fn hash_drbg_sha256_reseed_and_prediction_resistance() {
+    check_reseed_sample::<HashDrbg<Sha256State, 32>>(&HASH_DRBG_SHA256_SAMPLE);
+    check_prediction_resistance_sample::<HashDrbg<Sha256State, 32>>(&HASH_DRBG_SHA256_SAMPLE);
+}
+
+#[test]
+fn hash_drbg_sha512_reseed_and_prediction_resistance() {
+    check_reseed_sample::<HashDrbg<Sha512State, 64>>(&HASH_DRBG_SHA512_SAMPLE);
+    check_prediction_resistance_sample::<HashDrbg<Sha512State, 64>>(&HASH_DRBG_SHA512_SAMPLE);
+}
+
+#[test]
+fn mechanisms_reject_short_entropy_and_long_requests() {
+    assert_eq!(
+        HmacDrbg::<Sha256State, 32>::instantiate(&[0x00; 31], &[0x00; 16], &[]),
+        Err(DrbgError::EntropyTooShort)
+    );
+    assert_eq!(HashDrbg::<Sha1State, 20>::instantiate(&[0x00; 15], &[0x00; 8], &[]), Err(DrbgError::EntropyTooShort));
+
+    let mut hash_drbg = HashDrbg::<Sha1State, 20>::instantiate(&[0x00; 16], &[0x00; 8], &[]).unwrap();
+    assert_eq!(hash_drbg.reseed(&[0x00; 15], &[]), Err(DrbgError::EntropyTooShort));
+
+    let mut output = vec![0u8; MAX_REQUEST_LEN + 1];
+    assert_eq!(hash_drbg.generate(&[], &mut output), Err(DrbgError::RequestTooLong));
+    assert_eq!(hash_drbg.generate(&[], &mut output[..MAX_REQUEST_LEN]), Ok(()));
+}
+
+#[test]
+fn mechanisms_require_reseed_once_the_interval_is_reached() {
+    let mut hmac_drbg = HmacDrbg::<Sha256State, 32>::instantiate(&[0x00; 32], &[0x00; 16], &[]).unwrap();
+    hmac_drbg.set_reseed_interval(2);
+
+    let mut output = [0u8; 32];
+    hmac_drbg.generate(&[], &mut output).unwrap();
+    hmac_drbg.generate(&[], &mut output).unwrap();
+
+    let state = hmac_drbg.clone();
+    assert_eq!(hmac_drbg.generate(&[], &mut output), Err(DrbgError::ReseedRequired));
+    assert_eq!(hmac_drbg, state);
+
+    hmac_drbg.reseed(&[0x01; 32], &[]).unwrap();
+    assert_eq!(hmac_drbg.generate(&[], &mut output), Ok(()));
+}
+
+#[test]
+fn drbg_reseeds_once_the_interval_is_reached() {
+    let entropy_source = ReplayedEntropy(vec![vec![0x01; 32], vec![0x02; 16], vec![0x03; 32]]);
+    let mut drbg =
+        Drbg::<HashDrbg<Sha256State, 32>, _>::new(entropy_source, b"personalization").unwrap().with_reseed_interval(1);
+
+    let mut hash_drbg = HashDrbg::<Sha256State, 32>::instantiate(&[0x01; 32], &[0x02; 16], b"personalization").unwrap();
+    let (mut returned_bits, mut expected_bits) = ([0u8; 48], [0u8; 48]);
+
+    drbg.generate(b"additional input", &mut returned_bits).unwrap();
+    hash_drbg.generate(b"additional input", &mut expected_bits).unwrap();
+    assert_eq!(returned_bits, expected_bits);
+
+    drbg.generate(b"additional input", &mut returned_bits).unwrap();
+    hash_drbg.reseed(&[0x03; 32], b"additional input").unwrap();
+    hash_drbg.generate(&[], &mut expected_bits).unwrap();
+    assert_eq!(returned_bits, expected_bits);
+    assert_eq!(drbg.reseed_counter(), 2);
+
+    assert_eq!(drbg.try_fill_bytes(&mut returned_bits), Err(DrbgError::EntropyUnavailable));
+}
+
+#[test]
+fn drbg_splits_long_requests() {
+    let entropy_source = ReplayedEntropy(vec![vec![0x01; 32], vec![0x02; 16]]);
+    let mut drbg = Drbg::<HmacDrbg<Sha256State, 32>, _>::new(entropy_source, &[]).unwrap();
+    let mut hmac_drbg = HmacDrbg::<Sha256State, 32>::instantiate(&[0x01; 32], &[0x02; 16], &[]).unwrap();
+
+    let mut returned_bits = vec![0u8; MAX_REQUEST_LEN + 8];
+    drbg.fill_bytes(&mut returned_bits);
+    assert_eq!(drbg.reseed_counter(), 3);
+
+    let mut expected_bits = vec![0u8; MAX_REQUEST_LEN + 8];
+    let (first_request, second_request) = expected_bits.split_at_mut(MAX_REQUEST_LEN);
+    hmac_drbg.generate(&[], first_request).unwrap();
+    hmac_drbg.generate(&[], second_request).unwrap();
+    assert_eq!(returned_bits, expected_bits);
+
+    let mut next_bits = [0u8; 8];
+    hmac_drbg.generate(&[], &mut next_bits).unwrap();
+    assert_eq!(drbg.next_u64(), u64::from_le_bytes(next_bits));
+}
+
+const HMAC_DRBG_SHA256_SAMPLES: [DrbgvsSample; 10] = [
+    DrbgvsSample {
+        entropy_input: "ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488",
+        nonce: "659ba96c601dc69fc902940805ec0ca8",
+        additional_inputs: ["", ""],
+        returned_bits: concat!(
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89d54fbb978a15b5c443c9ec21036d2460",
+            "b6f73ebad0dc2aba6e624abf07745bc107694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668",
+            "961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "79737479ba4e7642a221fcfd1b820b134e9e3540a35bb48ffae29c20f5418ea3",
+        nonce: "3593259c092bef4129bc2c6c9e19f343",
+        additional_inputs: ["", ""],
+        returned_bits: concat!(
+            "cf5ad5984f9e43917aa9087380dac46e410ddc8a7731859c84e9d0f31bd43655b924159413e2293b17610f211e09f770",
+            "f172b8fb693a35b85d3b9e5e63b1dc252ac0e115002e9bedfb4b5b6fd43f33b8e0eafb2d072e1a6fee1f159df9b51e6c",
+            "8da737e60d5032dd30544ec51558c6f080bdbdab1de8a939e961e06b5f1aca37",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "b340907445b97a8b589264de4a17c0bea11bb53ad72f9f33297f05d2879d898d",
+        nonce: "65cb27735d83c0708f72684ea58f7ee5",
+        additional_inputs: ["", ""],
+        returned_bits: concat!(
+            "75183aaaf3574bc68003352ad655d0e9ce9dd17552723b47fab0e84ef903694a32987eeddbdc48efd24195dbdac8a46b",
+            "a2d972f5808f23a869e71343140361f58b243e62722088fe10a98e43372d252b144e00c89c215a76a121734bdc485486",
+            "f65c0b16b8963524a3a70e6f38f169c12f6cbdd169dd48fe4421a235847a23ff",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "8e159f60060a7d6a7e6fe7c9f769c30b98acb1240b25e7ee33f1da834c0858e7",
+        nonce: "c39d35052201bdcce4e127a04f04d644",
+        additional_inputs: ["", ""],
+        returned_bits: concat!(
+            "62910a77213967ea93d6457e255af51fc79d49629af2fccd81840cdfbb4910991f50a477cbd29edd8a47c4fec9d141f5",
+            "0dfde7c4d8fcab473eff3cc2ee9e7cc90871f180777a97841597b0dd7e779eff9784b9cc33689fd7d48c0dcd341515ac",
+            "8fecf5c55a6327aea8d58f97220b7462373e84e3b7417a57e80ce946d6120db5",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "74755f196305f7fb6689b2fe6835dc1d81484fc481a6b8087f649a1952f4df6a",
+        nonce: "c36387a544a5f2b78007651a7b74b749",
+        additional_inputs: ["", ""],
+        returned_bits: concat!(
+            "b2896f3af4375dab67e8062d82c1a005ef4ed119d13a9f18371b1b873774418684805fd659bfd69964f83a5cfe08667d",
+            "dad672cafd16befffa9faed49865214f703951b443e6dca22edb636f3308380144b9333de4bcb0735710e4d926678634",
+            "2fc53babe7bdbe3c01a3addb7f23c63ce2834729fabbd419b47beceb4a460236",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "d3cc4d1acf3dde0c4bd2290d262337042dc632948223d3a2eaab87da44295fbd",
+        nonce: "0109b0e729f457328aa18569a9224921",
+        additional_inputs: [
+            "3c311848183c9a212a26f27f8c6647e40375e466a0857cc39c4e47575d53f1f6",
+            "fcb9abd19ccfbccef88c9c39bfb3dd7b1c12266c9808992e305bc3cff566e4e4",
+        ],
+        returned_bits: concat!(
+            "9c7b758b212cd0fcecd5daa489821712e3cdea4467b560ef5ddc24ab47749a1f1ffdbbb118f4e62fcfca3371b8fbfc5b",
+            "0646b83e06bfbbab5fac30ea09ea2bc76f1ea568c9be0444b2cc90517b20ca825f2d0eccd88e7175538b85d90ab39018",
+            "3ca6395535d34473af6b5a5b88f5a59ee7561573337ea819da0dcc3573a22974",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "f97a3cfd91faa046b9e61b9493d436c4931f604b22f1081521b3419151e8ff06",
+        nonce: "11f3a7d43595357d58120bd1e2dd8aed",
+        additional_inputs: [
+            "517289afe444a0fe5ed1a41dbbb5eb17150079bdd31e29cf2ff30034d8268e3b",
+            "88028d29ef80b4e6f0fe12f91d7449fe75062682e89c571440c0c9b52c42a6e0",
+        ],
+        returned_bits: concat!(
+            "c6871cff0824fe55ea7689a52229886730450e5d362da5bf590dcf9acd67fed4cb32107df5d03969a66b1f6494fdf5d6",
+            "3d5b4d0d34ea7399a07d0116126d0d518c7c55ba46e12f62efc8fe28a51c9d428e6d371d7397ab319fc73ded4722e5b4",
+            "f30004032a6128df5e7497ecf82ca7b0a50e867ef6728a4f509a8c859087039c",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "0f2f23d64f481cabec7abb01db3aabf125c3173a044b9bf26844300b69dcac8b",
+        nonce: "9a5ae13232b43aa19cfe8d7958b4b590",
+        additional_inputs: [
+            "ec4c7a62acab73385f567da10e892ff395a0929f959231a5628188ce0c26e818",
+            "6b97b8c6b6bb8935e676c410c17caa8042aa3145f856d0a32b641e4ae5298648",
+        ],
+        returned_bits: concat!(
+            "7480a361058bd9afa3db82c9d7586e42269102013f6ec5c269b6d05f17987847748684766b44918fd4b65e1648622fc0",
+            "e0954178b0279dfc9fa99b66c6f53e51c4860131e9e0644287a4afe4ca8e480417e070db68008a97c3397e4b320b5d1a",
+            "1d7e1d18a95cfedd7d1e74997052bf649d132deb9ec53aae7dafdab55e6dae93",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "53c56660c78481be9c63284e005fcc14fbc7fb27732c9bf1366d01a426765a31",
+        nonce: "dc7a14d0eb5b0b3534e717a0b3c64614",
+        additional_inputs: [
+            "3aa848706ecb877f5bedf4ffc332d57c22e08747a47e75cff6f0fd1316861c95",
+            "9a401afa739b8f752fddacd291e0b854f5eff4a55b515e20cb319852189d3722",
+        ],
+        returned_bits: concat!(
+            "5c0eb420e0bf41ce9323e815310e4e8303cd677a8a8b023f31f0d79f0ca15aeb636099a369fd074d69889865eac1b72a",
+            "b3cbfebdb8cf460b00072802e2ec648b1349a5303be4ccaadd729f1a9ea17482fd026aaeb93f1602bc1404b9853adde4",
+            "0d6c34b844cf148bc088941ecfc1642c8c0b9778e45f3b07e06e21ee2c9e0300",
+        ),
+    },
+    DrbgvsSample {
+        entropy_input: "f63c804404902db334c54bb298fc271a21d7acd9f770278e089775710bf4fdd7",
+        nonce: "3e45009ea9cb2a36ba1aa4bf39178200",
+        additional_inputs: [
+            "d165a13dc8cc43f3f0952c3f5d3de4136954d983683d4a3e6d2dc4c89bf23423",
+            "75106bc86d0336df85097f6af8e80e2da59046a03fa65b06706b8bbc7ffc6785",
+        ],
+        returned_bits: concat!(
+            "6363139bba32c22a0f5cd23ca6d437b5669b7d432f786b8af445471bee0b2d24c9d5f2f93717cbe00d1f010cc3b9c515",
+            "fc9f7336d53d4d26ba5c0d76a90186663c8582eb739c7b6578a3328bf68dc2cec2cd89b3a90201f6993adcc854df0f5c",
+            "6974d0f5570765a15fe03dbce28942dd2fd16ba2027e68abac83926969349af8",
+        ),
+    },
+];
+
+const HASH_DRBG_SHA1_SAMPLE: ReseedSample = ReseedSample {
+    entropy_input: "15c5997be97af619267e678f8026423d",
+    nonce: "c32e81859daf5dec",
+    personalization: "7bc7b2147795ac9582b7d1e9f99ca342",
+    additional_inputs: [
+        "4e437d018dcf98c34c9e7eb414251616",
+        "d927f79ac8bf5da30d25026133821756",
+        "25f16e43a0b8e38c763a020d39cf1503",
+        "5b2960499c936373cc9e49196b739fb9",
+    ],
+    entropy_input_reseed: "0f0b8b197b98005404e45ed2dfb7c471",
+    entropy_input_prediction_resistance: "5f788e552d294873546c10d9239b9bf4",
+    returned_bits: [
+        concat!(
+            "0774f5275b8fa82d7f8a3dad6af1d4903c2528279be24b5042b79b3b1aed3340b549e353ca507e71b7f46489d9a4b594",
+            "d7de63113ee679a1d1e70daaff94ed84abcd5468263b574089d4bc0937b9f87e",
+        ),
+        concat!(
+            "bee5f480d8211eb26b528bcbea17fd6a25bebc2f1a2108bacf1c9fd568c1eebe5273ffad182d9e349f2cfad62c85336d",
+            "1c3297f68c9179571bcd24dbba5924fd473cb7043034462f01fc4ba4b3ce9c31",
+        ),
+        concat!(
+            "9dbd4631be4b13a1cdedffe35fb5e3b0596d061c33668f91ac5bb58ac11b56f1bd0c741fa91f8c3259ec382702d2964e",
+            "0a6c682427554660a7d8c3aa2e0d3f38668b39c88168aedc806757333646b961",
+        ),
+    ],
+};
+
+const HASH_DRBG_SHA256_SAMPLE: ReseedSample = ReseedSample {
+    entropy_input: "23ff9d796022b6b49941cea8a1bad3b858a4e2a79639fb99697c0d2a770820d3",
+    nonce: "67b7cb923ba0c08ba6a1a270d1767856",
+    personalization: "1698f3a03a739f8c38c9f7ad89da7620498fe9abf8529443795e659e3b5c96f4",
+    additional_inputs: [
+        "a2c3f2993c9b6427effdd32e9cbc907241ee7e4b1e4eec263a5c69cf181cf848",
+        "62cb5b1a630bdf93489d1a1d625d1986343466f9b42308587ca276417a421098",
+        "2960a8ae003b9dd49f744555fb5832c37fac6e19aa96fd5d9fe4a7fdbeaf64d5",
+        "171182f97ded11b16c36b54aff60780d0968c1297e50626f4810729514da868b",
+    ],
+    entropy_input_reseed: "70e09586e5c16ed3e7ae2c54735758c329f5e098d13a99ad0ef0c67efc529b1e",
+    entropy_input_prediction_resistance: "5adbdc3b72fe425cc9700f6f52ba6623c6eafe7d01f4b1375959da642ca3863e",
+    returned_bits: [
+        concat!(
+            "ffb7d9ffb39713398b21eb25bbc4d66b20b8a8ee1003c350bb813c4023b28fd20282236ecfff651ec73833fe4a4c9b4b",
+            "cae553723a82538c3e02bfc54e97dbd32e1932045b303dc43a961653f36e8c81bfc44e8fd41e03f912abe6900b350ae1",
+            "a34d8e3bede40b9bfacfac7a136caf062e79b3e264cd02df73a3aafe3af190ac",
+        ),
+        concat!(
+            "9b2b3f5afed53aa2183be837e61a3b474c593067124e0c89f488e256d212155e5e219c24ac3a535a8387095c890673e3",
+            "c697f79b72ad846b830e7e8313ef6c5461f102e57c0ec7878dbdb6fb5b96894c0bfd66b3f58939defa017d00ebdc07a7",
+            "a8ecfb2114b015e50054df3deef08365a82790e3282c2d528f6a6b3b12910184",
+        ),
+        concat!(
+            "3fa3a7decf15f752ff177131d4af2c16110d7cbd051b61ab604920421ff016604ec256c36011ca535c52d1dccb21000a",
+            "274943f5ba548087d1f9114720b9bef78fa13fe3c0e9a2c98613fd794ea837ea1a02e0c46f8dc2df4657be9d4b394bfd",
+            "13a3428d4cc82aec772832c538104b725a7ad50a054db6f250b6e8523ae5e429",
+        ),
+    ],
+};
+
+const HASH_DRBG_SHA512_SAMPLE: ReseedSample = ReseedSample {
+    entropy_input: "1bda89a5fe3fb9e257426a73758312c29930892add2c4f9b955ae8dde3eb2021",
+    nonce: "f483e77c15a45331b2a437dd1112b658",
+    personalization: "ab71fe647c383a29f0f97513f3b47937d151bcb90cda7ac66604213ee18e496d",
+    additional_inputs: [
+        "6ad8d7727dda7d54b6dc56d59487cb09a566d7dd2b328e22a9bfa6dc874d83a6",
+        "76d4c78667b6c4256c4f9dc2bb9f3c76c0df4b8333980267de76137a393a4682",
+        "d2dde641fdd5587918985ef82c91f172b69cf15fbae3713b8f34322d8efebd61",
+        "6027429f1a3227456b5773b5a9d693a5697c084eb24286b8bbc09685ab9a3307",
+    ],
+    entropy_input_reseed: "0bdc176ea7719fdef1b060e0fefa366f14f042382336eea3d824f4f1542fe6da",
+    entropy_input_prediction_resistance: "b22bed13371f13e651d25ee18a5ba548e4c8a6371958ce60d787aba545a1a802",
+    returned_bits: [
+        concat!(
+            "1be34b876df576b16e11995646b50c433830f21c9f5fc61f895c7e242b687fa524174f294c329cb4c794f95f7a1c6d31",
+            "b8d77bda5544aec5165ede538c34e17d215b408491287e617cc7205094edd2803a8972301ef85cee81bb37fe4df0d0e6",
+            "6795d2fd55879fe3ed9018da0ae96d5053099693d2059c2b14d86a9a6b7d0b55eaafafdfe344f8715e1e72e1cebe5b41",
+            "7f5aa816ab2a2f67145e07f1ed58006c1e64f649eb742b0bd4d6b7e1d0278adc14cabdf0c770c76209aea854301a0b7e",
+            "12f4e802831e4f22440a273d0e95567bf9316100a7f5b74ecb0f29b3a23f5a5b33a18e5a580ed702a3489760f058f829",
+            "c6ccd6ac2588efbad6dd4158ece07ba4",
+        ),
+        concat!(
+            "ea556ae84d82fc6f10003a134aec6d2a570bb001eb1847a46af34f2102bbff24e877e15c24b5cf153ae675a3fd667687",
+            "cce21f85034d9fe5ebddfade122a1f7ad35b6ace7b28336b892b9f9f057efa4932290b2c2c1e172b01436ea02c48b0bc",
+            "69ae8ee43c602072271aa961fe9c7231c7699f3482f56f7cfa09beb693cbee7642526e78329805551677e4dfeece4ce4",
+            "a33bb5bd5466765d0e8e45e17b634f5200962cab521ff23aea4a38e734056f3c7d8102486f799b68c50866aefeb5cf4c",
+            "82e9173a5426ee2e01b219b2f61be5e78383bc8f31a56ed39af716f8bc2b03aae349d86c15bd92a2e49511303cf66495",
+            "c6d4a9a89ddc24e36e69499ea4768471",
+        ),
+        concat!(
+            "8df73241abeb2ebfdbd5b25984d177726d7c2b8352cf8e015be263dbe0de2b8c819234f17fb9d6fe612ab22b7a2ee246",
+            "268c9dc5a3cec11354a88c4d0dcbad21919627970b123dceddf98fd3e54db7d6f8c2cf874d4cd6e2c1495c15088d2d5e",
+            "aa0930ff57b273f65713093cbdf93efd7f1afb8c416b8c4aa317d4f886d0374fa8609174d35cd147c80a1a7a456b2231",
+            "b7c11d6f4ea7d85b52826bd8e4c1e1a5070a50217bfaf0c51f578cbb1a7afad755f68230a24f248f625785f167d15c6d",
+            "bca4c909dc0a97b6055f49bec9319e47ebf4ddf66a23b75b46755e9843f738c60dd1997bb9cbc2458ad303742c89fdc4",
+            "e93fe4175f71507ee2394a907287a688",
+        ),
+    ],
+};
+
+const HMAC_DRBG_SHA1_SAMPLE: ReseedSample = ReseedSample {
+    entropy_input: "3ceca7063dac2d722828773554b0f6cc",
+    nonce: "556fa52848d32814",
+    personalization: "a5aaf0b775529399a380e2ee1c7620f9",
+    additional_inputs: [
+        "e7dee0769ad84ff6b07a98ae4cf208d1",
+        "21dcca9b75d4318d3ae13a1e7754d368",
+        "22d5b1486b4cf6f73237b263c9fb3dde",
+        "55218dbec01dde11c3ed454add9785e7",
+    ],
+    entropy_input_reseed: "0e0e9a100a4f817b3fc2890e43ceb964",
+    entropy_input_prediction_resistance: "31b3f3800a4f753e4ff3861b9630f34e",
+    returned_bits: [
+        concat!(
+            "28743107f34fe645d4b48162674c780ddd4eab13593121bffe598aed05eb9108131280325c575efe345fcee0deffcb66",
+            "a3afaa1c3dfe98e7200be0fea705025dc68cab98e22a56b2392a39abf7da382b",
+        ),
+        concat!(
+            "90bb21d68a231926d9364366242d3f56517f274dec7d724c7e0af28b5dd64b022cd3fcd7b397901249786e1feae36771",
+            "730d19fa551e360f2bd1d2a63e49ca513f4468233c43fe8f8e882fa0674f18fd",
+        ),
+        concat!(
+            "c14bd3547a4b3229e7dd4b15cb23859c37581dfea5ed4362d48ff4d5c5dc24bd30ce8a4f08969cef43265341e001e133",
+            "80ae39bb2cfb01caddc6090398ef69c8d8e2c632e9d56cd6fd6c499a65247ee2",
+        ),
+    ],
+};
+
+const HMAC_DRBG_SHA512_SAMPLE: ReseedSample = ReseedSample {
+    entropy_input: "c31ea39b73bc8b064589e7cde32854b3abb7e8346127303b2ea31b0107e1380f",
+    nonce: "7035fc67185b9560933f97f3090cc4f4",
+    personalization: "2c4030b6cab30bbfe98c55dedf5a37f1251ca3df2ceef000c9ad80cf9d25b2ea",
+    additional_inputs: [
+        "9543b938578dd096a8f863d3bbd734e45defad55483a66db06d8cd4a1ecd2146",
+        "2ed72c603c5a91ce992af0b75d6d7af330e3252c19be01481f1ba1a6e8f30912",
+        "83926580c4a6270d2607f5f4ee12c97cfff1171086c4ead8b43a193df81b92fe",
+        "579698a0da31d64ba931a0e189d1cb224571a0b2013d25ffc074df9390c43eee",
+    ],
+    entropy_input_reseed: "e0eb038562f64e3d5ba8a0c0d217bed293b9dd55c4cc24243a91f362f011efef",
+    entropy_input_prediction_resistance: "91d38b5329644eddc726e645d99321cab956ce36cf55e83e8ac2d60d172f3085",
+    returned_bits: [
+        concat!(
+            "3bd2f3ef623bd4080d91bcec93d058fe783f8b78895d662b1f448db786647f7ae95d4166add704bf5f32019ede8d264c",
+            "dc5c57f54d55f23cc7338dd45f183860615a03e7cbaabfc3158aec99e5d37c6beb2d795a709e84f2ed99aeac743bf97f",
+            "eb85c0110af8c5a858f80911b83c4692a1abeb434e124bfdbf7213db46b14039fd46eb5df7757a11227aed05b9253030",
+            "2f0416a6c278faa5dd7216ff5c4c0209d9f255474614ca2c106da1741f15a2c85e436c5ac1978073d7116cb0b4dd1e51",
+            "eec7482f57f360a2c660d5db432ea85940127db6915b29abc1cf72d5ff57f98eb440eca39167839950a10319c40e2461",
+            "858878813ab82323c355715cc9fdc89e",
+        ),
+        concat!(
+            "24d90100829ca941cae277e5a675a08d059634286f8cca4c4dd7674c367698a51de45e85ae5a7155e13b99a051f63468",
+            "a0741938f777661c42e50884ac57a574f9d0fac947aee67d1369c64d6d3dd75613451bff58a2a1c5d020ef148730bbda",
+            "5f3935796db7465e1918f2b1c25496ae34d5551e8302ac1349356e45993aae6aafa97da2992350f0fae66a03b7f57ab7",
+            "a6fffd7e6d429eb46b4b6f63cd8bca544437c454ccb3b64293f5b9ba029a8e3533a081e695289be380c9accd4d144627",
+            "80c6cb2d54afd4dbe213cb133be8ad5310d30ca3651f71aaa79f531cdbfdd391f64eb5cd8a000c4da0484bc28c229542",
+            "a231587b0cd2120c530b6bb76c2a4f13",
+        ),
+        concat!(
+            "240e69a7184b996cdbe8713043ab6e2cb130b9b3b37bfbbf13f97fd32ed777289cf8ef28ac6ce472b3849f57031f22f6",
+            "806c57a86c4e5e79e0910b868ad325b0fbba59b794c555db9bff12f73bcf6d49b5a1e28781eb75740dd08b4172371aa5",
+            "a484a973257ec0bed09d1c4d3b36746a3e15fbc49c7cfcd4e1a22bd10cb44b01d34a79ef227ebd35ef8e01ba04145e54",
+            "1d3b27451da796349db9969e32dbaae33365e6c21025e3c44f124c6988fe53856dc77521db0b82ccfe891aa163660bdc",
+            "2d41cb2cf41a9665edab44b7aa5833616475d9615bebf345e231ffc3f4a72b878e8fc48e8e2d872e6368616550a678dd",
+            "bbd6057c307dfd0d1924b37d55efc325",
+        ),
+    ],
+};
@@ -0,0 +1,178 @@
+//! # HKDF `rs_hkdf` - HMAC-based Extract-and-Expand Key Derivation Function
+//!
+//! HKDF is a key derivation function, specified in RFC 5869, built on top of HMAC. It works in two steps: the extract
+//! step concentrates the possibly dispersed entropy of some input key material into a pseudorandom key (PRK), and the
+//! expand step stretches the PRK into as many output bytes as needed, bound to some context information.
+//!
+//! The HKDF implemented in this crate is generic over any hash function present in the encompassing project, through
+//! `rs_hmac`. Derived keys are written into caller provided buffers, so no allocation is ever needed.
+//!
+//! ## Usage
+//!
+//! Users can either run both steps at once with `Hkdf::new`, or run them separately through `Hkdf::extract` and
+//! `Hkdf::from_prk`, then expand as many keys as needed out of the same PRK.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use HKDF with SHA-256 in Rust, with the first test case of RFC 5869:
+//!
+//! ```rust
+//! # use rs_hkdf::Hkdf;
+//! # use rs_sha256::Sha256State;
+//! let ikm = [0x0B; 22];
+//! let salt = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C];
+//! let info = [0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8, 0xF9];
+//!
+//! let prk = Hkdf::<Sha256State, 32>::extract(&salt, &ikm);
+//! assert_eq!(format!("{prk:02x}"), "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+//!
+//! let mut okm = [0u8; 42];
+//! Hkdf::<Sha256State, 32>::from_prk(prk.as_ref()).unwrap().expand(&info, &mut okm).unwrap();
+//! assert_eq!(okm[..8], [0x3C, 0xB2, 0x5F, 0x25, 0xFA, 0xAC, 0xD5, 0x7A]);
+//! ```
+//!
+//! ## Use Cases
+//!
+//! HKDF is recommended for a variety of tasks, including:
+//!
+//! - Deriving session keys from the shared secret of a key agreement.
+//! - Turning a single master key into several independent keys, separated by their context information.
+//! - Key schedules of protocols such as TLS 1.3.
+//!
+
+#![no_std]
+
+use core::fmt::{Display, Formatter};
+use rs_hasher_ctx::ByteArrayWrapper;
+pub use rs_hmac::Zeroize;
+use rs_hmac::{Hmac, Mac};
+use rs_internal_hasher::HashAlgorithm;
+use rs_internal_state::BytesLen;
+
+/// Largest count of blocks the expand step can produce, as its block counter is a single byte
+const MAX_BLOCKS_COUNT: usize = 255;
+
+/// `HkdfError` lists the reasons for which HKDF refuses to derive a key.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HkdfError {
+    /// The requested output is longer than `Hkdf::MAX_OUTPUT_LEN`
+    OutputTooLong,
+    /// The pseudorandom key given to `Hkdf::from_prk` is shorter than the hash output
+    PrkTooShort,
+}
+
+impl Display for HkdfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HkdfError::OutputTooLong => f.write_str("HKDF output too long"),
+            HkdfError::PrkTooShort => f.write_str("HKDF pseudorandom key too short"),
+        }
+    }
+}
+
+/// `Hkdf<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize>` is a generic struct that provides HKDF in Rust, over the
+/// hash function `H` whose output is `OUTPUT_SIZE` bytes long.
+///
+/// It holds the HMAC context keyed with the pseudorandom key, so that any number of keys can be expanded out of it.
+///
+/// ## Examples
+///
+/// TLS 1.3 builds the context information of HKDF-Expand-Label out of several fields, which can be given without
+/// concatenating them first:
+///
+///```rust
+/// # use rs_hkdf::Hkdf;
+/// # use rs_sha256::Sha256State;
+/// let hkdf = Hkdf::<Sha256State, 32>::new(b"", b"shared secret");
+/// let label = b"tls13 key";
+///
+/// let mut key = [0u8; 16];
+/// hkdf.expand_multi_info(&[&[0x00, 0x10], &[label.len() as u8], label, &[0x00]], &mut key).unwrap();
+///
+/// let mut same_key = [0u8; 16];
+/// hkdf.expand(b"\x00\x10\x09tls13 key\x00", &mut same_key).unwrap();
+/// assert_eq!(key, same_key);
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Hkdf<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> {
+    prk_hmac: Hmac<H, OUTPUT_SIZE>,
+}
+
+impl<H, const OUTPUT_SIZE: usize> Hkdf<H, OUTPUT_SIZE>
+where
+    H: BytesLen + Default + HashAlgorithm<Output = ByteArrayWrapper<OUTPUT_SIZE>>,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    /// Longest output the expand step can produce, of 255 hash outputs
+    pub const MAX_OUTPUT_LEN: usize = MAX_BLOCKS_COUNT * OUTPUT_SIZE;
+
+    /// Runs the extract step over the input key material `ikm`, and keeps the resulting pseudorandom key for the
+    /// expand step.
+    ///
+    /// An empty `salt` is equivalent to the default salt of RFC 5869, made of `OUTPUT_SIZE` zeros.
+    pub fn new(salt: &[u8], ikm: &[u8]) -> Self {
+        let mut prk = Self::extract(salt, ikm);
+        let prk_hmac = Hmac::new(prk.as_ref());
+        prk.zeroize();
+
+        Self {
+            prk_hmac,
+        }
+    }
+
+    /// Runs the extract step over the input key material `ikm`, returning the pseudorandom key.
+    ///
+    /// An empty `salt` is equivalent to the default salt of RFC 5869, made of `OUTPUT_SIZE` zeros.
+    pub fn extract(salt: &[u8], ikm: &[u8]) -> ByteArrayWrapper<OUTPUT_SIZE> {
+        Hmac::<H, OUTPUT_SIZE>::digest(salt, ikm)
+    }
+
+    /// Prepares the expand step out of an existing pseudorandom key, which must be at least `OUTPUT_SIZE` bytes long.
+    pub fn from_prk(prk: &[u8]) -> Result<Self, HkdfError> {
+        if prk.len() < OUTPUT_SIZE {
+            return Err(HkdfError::PrkTooShort);
+        }
+
+        Ok(Self {
+            prk_hmac: Hmac::new(prk),
+        })
+    }
+
+    /// Runs the expand step, filling the whole of `okm` with key material bound to the context information `info`.
+    ///
+    /// `okm` may be empty, and cannot be longer than `Hkdf::MAX_OUTPUT_LEN`.
+    pub fn expand(&self, info: &[u8], okm: &mut [u8]) -> Result<(), HkdfError> {
+        self.expand_multi_info(&[info], okm)
+    }
+
+    /// Runs the expand step with context information made of the concatenation of `infos`, filling the whole of `okm`.
+    ///
+    /// `okm` may be empty, and cannot be longer than `Hkdf::MAX_OUTPUT_LEN`.
+    pub fn expand_multi_info(&self, infos: &[&[u8]], okm: &mut [u8]) -> Result<(), HkdfError> {
+        if okm.len() > Self::MAX_OUTPUT_LEN {
+            return Err(HkdfError::OutputTooLong);
+        }
+
+        let mut previous_block = [0u8; OUTPUT_SIZE];
+        for (i, okm_block) in okm.chunks_mut(OUTPUT_SIZE).enumerate() {
+            let mut hmac = self.prk_hmac.clone();
+            if i > 0 {
+                hmac.update(&previous_block);
+            }
+            infos.iter().for_each(|info| hmac.update(info));
+            hmac.update(&[i as u8 + 1]);
+
+            previous_block.clone_from_slice(hmac.finalize().as_ref());
+            okm_block.clone_from_slice(&previous_block[..okm_block.len()]);
+        }
+        previous_block.zeroize();
+
+        Ok(())
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Zeroize for Hkdf<H, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.prk_hmac.zeroize();
+    }
+}
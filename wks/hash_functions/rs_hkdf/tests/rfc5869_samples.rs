@@ -0,0 +1,146 @@
+use rs_hkdf::{Hkdf, HkdfError};
+use rs_sha1::Sha1State;
+use rs_sha256::Sha256State;
+use rs_sha3_256::Sha3_256State;
+use rs_sha512::Sha512State;
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn range(bytes: core::ops::RangeInclusive<u8>) -> Vec<u8> {
+    bytes.collect()
+}
+
+fn sha256_hkdf(salt: &[u8], ikm: &[u8], info: &[u8], okm_len: usize) -> (String, String) {
+    let prk = Hkdf::<Sha256State, 32>::extract(salt, ikm);
+    let mut okm = vec![0u8; okm_len];
+    Hkdf::<Sha256State, 32>::from_prk(prk.as_ref()).unwrap().expand(info, &mut okm).unwrap();
+
+    (format!("{prk:02x}"), bytes_to_hex(&okm))
+}
+
+fn sha1_hkdf(salt: &[u8], ikm: &[u8], info: &[u8], okm_len: usize) -> (String, String) {
+    let prk = Hkdf::<Sha1State, 20>::extract(salt, ikm);
+    let mut okm = vec![0u8; okm_len];
+    Hkdf::<Sha1State, 20>::from_prk(prk.as_ref()).unwrap().expand(info, &mut okm).unwrap();
+
+    (format!("{prk:02x}"), bytes_to_hex(&okm))
+}
+
+#[test]
+fn assert_rfc5869_test_case_1() {
+    let (prk, okm) = sha256_hkdf(&range(0x00..=0x0C), &[0x0B; 22], &range(0xF0..=0xF9), 42);
+
+    assert_eq!(prk, "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+    assert_eq!(okm, "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865");
+}
+
+#[test]
+fn assert_rfc5869_test_case_2() {
+    let (prk, okm) = sha256_hkdf(&range(0x60..=0xAF), &range(0x00..=0x4F), &range(0xB0..=0xFF), 82);
+
+    assert_eq!(prk, "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244");
+    assert_eq!(
+        okm,
+        "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f\
+        09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87"
+    );
+}
+
+#[test]
+fn assert_rfc5869_test_case_3() {
+    let (prk, okm) = sha256_hkdf(&[], &[0x0B; 22], &[], 42);
+
+    assert_eq!(prk, "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04");
+    assert_eq!(okm, "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8");
+}
+
+#[test]
+fn assert_rfc5869_test_case_4() {
+    let (prk, okm) = sha1_hkdf(&range(0x00..=0x0C), &[0x0B; 11], &range(0xF0..=0xF9), 42);
+
+    assert_eq!(prk, "9b6c18c432a7bf8f0e71c8eb88f4b30baa2ba243");
+    assert_eq!(okm, "085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9cdd4f155fda2c22e422478d305f3f896");
+}
+
+#[test]
+fn assert_rfc5869_test_case_5() {
+    let (prk, okm) = sha1_hkdf(&range(0x60..=0xAF), &range(0x00..=0x4F), &range(0xB0..=0xFF), 82);
+
+    assert_eq!(prk, "8adae09a2a307059478d309b26c4115a224cfaf6");
+    assert_eq!(
+        okm,
+        "0bd770a74d1160f7c9f12cd5912a06ebff6adcae899d92191fe4305673ba2ffe8fa3f1a4e5ad79f3f334b3b202b2173c486ea37ce3d3\
+        97ed034c7f9dfeb15c5e927336d0441f4c4300e2cff0d0900b52d3b4"
+    );
+}
+
+#[test]
+fn assert_rfc5869_test_case_6() {
+    let (prk, okm) = sha1_hkdf(&[], &[0x0B; 22], &[], 42);
+
+    assert_eq!(prk, "da8c8a73c7fa77288ec6f5e7c297786aa0d32d01");
+    assert_eq!(okm, "0ac1af7002b3d761d1e55298da9d0506b9ae52057220a306e07b6b87e8df21d0ea00033de03984d34918");
+}
+
+#[test]
+fn assert_rfc5869_test_case_7() {
+    let (prk, okm) = sha1_hkdf(&[0x00; 20], &[0x0C; 22], &[], 42);
+
+    assert_eq!(prk, "2adccada18779e7c2077ad2eb19d3f3e731385dd");
+    assert_eq!(okm, "2c91117204d745f3500d636a62f64f0ab3bae548aa53d423b0d1f27ebba6f5e5673a081d70cce7acfc48");
+    assert_eq!(sha1_hkdf(&[], &[0x0C; 22], &[], 42), (prk, okm));
+}
+
+#[test]
+fn assert_sha3_256_and_sha512_hkdf_correctness() {
+    let mut okm = [0u8; 100];
+
+    Hkdf::<Sha3_256State, 32>::new(b"salt", b"input key material").expand(b"info", &mut okm).unwrap();
+    assert_eq!(
+        bytes_to_hex(&okm),
+        "ef04deed25415f876f439a4aeb00195e6e98a11304719f4a7a8ec18ab18b445c0d57e8bf5877877d4f97b04cc129de9a3bbb739a41ea\
+        7ac40ef175d64d8599475b9de01e8c420d975b506b52472200d08d12ddcdbbd77a7a9a00bf00eb993c1155b759d2"
+    );
+
+    Hkdf::<Sha512State, 64>::new(b"salt", b"input key material").expand(b"info", &mut okm).unwrap();
+    assert_eq!(
+        bytes_to_hex(&okm),
+        "57026b6a13014b870f39e8b46105c12f296eb0515a81afd6fb419b0e63c5b5777501cb46175423ba1b9ff4c7fbea2e47ab9c84a306b3\
+        5cd71156af466f323976f97de335286e7e24b90560302fa9504c30674b9d9ae6841177732471ee4bc8d1f166d911"
+    );
+}
+
+#[test]
+fn assert_expand_multi_info_matches_the_concatenated_info() {
+    let hkdf = Hkdf::<Sha256State, 32>::new(&range(0x00..=0x0C), &[0x0B; 22]);
+
+    let mut okm = [0u8; 42];
+    hkdf.expand_multi_info(&[&[0xF0, 0xF1, 0xF2], &[], &range(0xF3..=0xF9)], &mut okm).unwrap();
+    assert_eq!(
+        bytes_to_hex(&okm),
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+    );
+}
+
+#[test]
+fn assert_output_length_limits() {
+    let hkdf = Hkdf::<Sha256State, 32>::new(b"salt", b"input key material");
+    assert_eq!(Hkdf::<Sha256State, 32>::MAX_OUTPUT_LEN, 8160);
+
+    let mut longest_okm = vec![0u8; 8160];
+    assert_eq!(hkdf.expand(b"", &mut longest_okm), Ok(()));
+
+    let mut too_long_okm = vec![0u8; 8161];
+    assert_eq!(hkdf.expand(b"", &mut too_long_okm), Err(HkdfError::OutputTooLong));
+    assert_eq!(too_long_okm, vec![0u8; 8161]);
+
+    assert_eq!(hkdf.expand(b"", &mut []), Ok(()));
+}
+
+#[test]
+fn assert_from_prk_rejects_short_keys() {
+    assert_eq!(Hkdf::<Sha256State, 32>::from_prk(&[0x01; 31]), Err(HkdfError::PrkTooShort));
+    assert!(Hkdf::<Sha256State, 32>::from_prk(&[0x01; 64]).is_ok());
+}
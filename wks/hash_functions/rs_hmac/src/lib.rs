@@ -27,7 +27,22 @@
 //! let mut hmac = Hmac::<Sha3_256State, 32>::new(b"my secret and secure key");
 //! hmac.write(b"hello world");
 //! let result = hmac.finish();
-//! assert_eq!(result, 0xE50FF3D282C9C0F9);
+//! assert_eq!(result, 0x10552522FC4FB9FE);
+//! ```
+//!
+//! Tags received along with a message are checked through the `Mac` trait, which compares them in constant time and
+//! accepts truncated tags down to half the full tag length:
+//!
+//! ```rust
+//! # use rs_hmac::{Hmac, Mac, MacError};
+//! # use rs_sha256::Sha256State;
+//! let tag = Hmac::<Sha256State, 32>::digest(b"key", b"hello world");
+//!
+//! let mut hmac = Hmac::<Sha256State, 32>::new(b"key");
+//! hmac.update(b"hello world");
+//! assert_eq!(hmac.clone().verify(tag.as_ref()), Ok(()));
+//! assert_eq!(hmac.clone().verify(&tag.as_ref()[..16]), Ok(()));
+//! assert_eq!(hmac.verify(&tag.as_ref()[..8]), Err(MacError::TagLength));
 //! ```
 //!
 //! ## Use Cases
@@ -44,9 +59,10 @@
 
 #![no_std]
 
+use core::fmt::{Debug, Formatter};
 use core::hash::{Hash, Hasher};
-pub use rs_hasher_ctx::HasherContext;
 use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher};
+pub use rs_hasher_ctx::{HasherContext, Mac, MacError, Zeroize};
 use rs_internal_hasher::{HashAlgorithm, LenPad};
 use rs_internal_state::BytesLen;
 
@@ -98,8 +114,12 @@ const OUTER_PAD: u8 = 0x5c;
 ///
 /// Note that in the context of HMAC, the hash operation applies a different set of operations than the hasher's
 /// `write`, which results in different outcomes.
-#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+///
+/// The hashers of `Hmac` hold states derived from the key, so they are wiped on drop, or earlier through
+/// `Zeroize::zeroize`, and its `Debug` output leaves them out.
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
 pub struct Hmac<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> {
+    keyed_inner_hasher: GenericHasher<H, OUTPUT_SIZE>,
     inner_hasher: GenericHasher<H, OUTPUT_SIZE>,
     outer_hasher: GenericHasher<H, OUTPUT_SIZE>,
 }
@@ -142,7 +162,7 @@ where
     /// let u64result = hmac.finish();
     /// let bytes_result = HasherContext::finish(&mut hmac);
     ///
-    /// assert_eq!(u64result, 0xCD06EBA676832C12);
+    /// assert_eq!(u64result, 0xC14C21F65ACA4519);
     /// assert_eq!(
     ///     bytes_result,
     ///     [
@@ -159,10 +179,12 @@ where
         if key.len() > H::Padding::len() {
             let mut hasher: GenericHasher<H, OUTPUT_SIZE> = GenericHasher::default();
             hasher.write(key);
-            let bytes_output: ByteArrayWrapper<OUTPUT_SIZE> = HasherContext::finish(&mut hasher).into();
+            let mut bytes_output: ByteArrayWrapper<OUTPUT_SIZE> = HasherContext::finish(&mut hasher).into();
 
             inner_key.as_mut()[..H::len()].clone_from_slice(bytes_output.as_ref());
             outer_key.as_mut()[..H::len()].clone_from_slice(bytes_output.as_ref());
+            hasher.zeroize();
+            bytes_output.zeroize();
         } else {
             inner_key.as_mut()[..key.len()].clone_from_slice(key);
             outer_key.as_mut()[..key.len()].clone_from_slice(key);
@@ -178,8 +200,11 @@ where
 
         inner_hasher.write(inner_key.as_ref());
         outer_hasher.write(outer_key.as_ref());
+        inner_key.zeroize();
+        outer_key.zeroize();
 
         Self {
+            keyed_inner_hasher: inner_hasher.clone(),
             inner_hasher,
             outer_hasher,
         }
@@ -217,6 +242,40 @@ where
     }
 }
 
+impl<H, const OUTPUT_SIZE: usize> Hmac<H, OUTPUT_SIZE>
+where
+    H: Default + HashAlgorithm,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    /// Computes the tag of the message written so far, leaving the context untouched so that more data can follow.
+    fn tag(&self) -> ByteArrayWrapper<OUTPUT_SIZE> {
+        let mut inner_hasher = self.inner_hasher.clone();
+        let mut inner_result: ByteArrayWrapper<OUTPUT_SIZE> = HasherContext::finish(&mut inner_hasher).into();
+        inner_hasher.zeroize();
+
+        let mut outer_hasher = self.outer_hasher.clone();
+        outer_hasher.write(inner_result.as_ref());
+        inner_result.zeroize();
+
+        let tag = HasherContext::finish(&mut outer_hasher).into();
+        outer_hasher.zeroize();
+
+        tag
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Debug for Hmac<H, OUTPUT_SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Hmac").finish_non_exhaustive()
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Drop for Hmac<H, OUTPUT_SIZE> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 impl<H, const OUTPUT_SIZE: usize> Hasher for Hmac<H, OUTPUT_SIZE>
 where
     H: Default + HashAlgorithm,
@@ -224,7 +283,8 @@ where
     ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
 {
     fn finish(&self) -> u64 {
-        Hasher::finish(&self.inner_hasher)
+        let tag = self.tag();
+        u64::from_be_bytes([tag[0], tag[1], tag[2], tag[3], tag[4], tag[5], tag[6], tag[7]])
     }
 
     fn write(&mut self, bytes: &[u8]) {
@@ -241,9 +301,40 @@ where
     type Output = ByteArrayWrapper<OUTPUT_SIZE>;
 
     fn finish(&mut self) -> Self::Output {
-        let inner_result: ByteArrayWrapper<OUTPUT_SIZE> = HasherContext::finish(&mut self.inner_hasher).into();
+        self.tag()
+    }
+}
+
+impl<H, const OUTPUT_SIZE: usize> Mac<OUTPUT_SIZE> for Hmac<H, OUTPUT_SIZE>
+where
+    H: Default + HashAlgorithm,
+    <H as HashAlgorithm>::Output: From<H>,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    fn update(&mut self, bytes: &[u8]) {
+        self.inner_hasher.write(bytes)
+    }
+
+    fn finalize(self) -> ByteArrayWrapper<OUTPUT_SIZE> {
+        self.tag()
+    }
+
+    fn finalize_reset(&mut self) -> ByteArrayWrapper<OUTPUT_SIZE> {
+        let tag = self.tag();
+        self.reset();
+
+        tag
+    }
+
+    fn reset(&mut self) {
+        self.inner_hasher = self.keyed_inner_hasher.clone();
+    }
+}
 
-        self.outer_hasher.write(inner_result.as_ref());
-        HasherContext::finish(&mut self.outer_hasher).into()
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Zeroize for Hmac<H, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.keyed_inner_hasher.zeroize();
+        self.inner_hasher.zeroize();
+        self.outer_hasher.zeroize();
     }
 }
@@ -0,0 +1,117 @@
+use core::hash::Hasher;
+use rs_hmac::{HasherContext, Hmac, Mac, MacError, Zeroize};
+use rs_sha256::Sha256State;
+use rs_sha512::Sha512State;
+
+const KEY: &[u8; 3] = b"key";
+const MSG: &[u8; 43] = b"The quick brown fox jumps over the lazy dog";
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+#[test]
+fn assert_hmac_finish_is_idempotent() {
+    let mut hmac = Hmac::<Sha256State, 32>::new(KEY);
+    hmac.write(MSG);
+
+    let first_result = HasherContext::finish(&mut hmac);
+    let second_result = HasherContext::finish(&mut hmac);
+    assert_eq!(first_result, second_result);
+    assert_eq!(format!("{first_result:02x}"), "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8");
+}
+
+#[test]
+fn assert_hmac_u64_finish_is_derived_from_the_tag() {
+    let mut hmac = Hmac::<Sha256State, 32>::new(KEY);
+    hmac.write(MSG);
+
+    assert_eq!(hmac.finish(), 0xF7BC83F430538424);
+}
+
+#[test]
+fn assert_hmac_data_can_follow_an_intermediate_tag() {
+    let mut hmac = Hmac::<Sha256State, 32>::new(KEY);
+    hmac.update(&MSG[..10]);
+    let _ = HasherContext::finish(&mut hmac);
+    hmac.update(&MSG[10..]);
+
+    assert_eq!(hmac.finalize(), Hmac::<Sha256State, 32>::digest(KEY, MSG));
+}
+
+#[test]
+fn assert_hmac_finalize_reset_keeps_the_key() {
+    let mut hmac = Hmac::<Sha256State, 32>::new(KEY);
+    hmac.update(b"first message");
+    assert_eq!(hmac.finalize_reset(), Hmac::<Sha256State, 32>::digest(KEY, b"first message"));
+
+    hmac.update(MSG);
+    assert_eq!(hmac.finalize_reset(), Hmac::<Sha256State, 32>::digest(KEY, MSG));
+
+    hmac.update(b"discarded");
+    hmac.reset();
+    assert_eq!(hmac.finalize(), Hmac::<Sha256State, 32>::digest(KEY, b""));
+}
+
+#[test]
+fn assert_hmac_verify_accepts_rfc4231_truncated_tags() {
+    let key = [0x0C; 20];
+    let msg = b"Test With Truncation";
+
+    let mut hmac_sha256 = Hmac::<Sha256State, 32>::new(&key);
+    hmac_sha256.update(msg);
+    assert_eq!(hmac_sha256.verify(&hex_to_bytes("a3b6167473100ee06e0c796c2955552b")), Ok(()));
+
+    let mut hmac_sha512 = Hmac::<Sha512State, 64>::new(&key);
+    hmac_sha512.update(msg);
+    assert_eq!(hmac_sha512.verify(&hex_to_bytes("415fad6271580a531d4179bc891d87a6")), Err(MacError::TagLength));
+
+    let mut hmac_sha512 = Hmac::<Sha512State, 64>::new(&key);
+    hmac_sha512.update(msg);
+    let full_tag = hmac_sha512.clone().finalize();
+    assert_eq!(hmac_sha512.verify(&full_tag.as_ref()[..32]), Ok(()));
+}
+
+#[test]
+fn assert_hmac_verify_rejects_wrong_tags() {
+    let tag = Hmac::<Sha256State, 32>::digest(KEY, MSG);
+    let mut hmac = Hmac::<Sha256State, 32>::new(KEY);
+    hmac.update(MSG);
+
+    for i in 0..32 {
+        let mut tampered_tag = [0u8; 32];
+        tampered_tag.clone_from_slice(tag.as_ref());
+        tampered_tag[i] ^= 0x01;
+        assert_eq!(hmac.clone().verify(&tampered_tag), Err(MacError::Mismatch));
+    }
+
+    assert_eq!(hmac.clone().verify(&tag.as_ref()[..15]), Err(MacError::TagLength));
+    assert_eq!(hmac.clone().verify(&[0u8; 33]), Err(MacError::TagLength));
+    assert_eq!(hmac.clone().verify(&[]), Err(MacError::TagLength));
+    assert_eq!(hmac.verify(tag.as_ref()), Ok(()));
+}
+
+#[test]
+fn assert_mac_min_tag_len_follows_rfc2104() {
+    assert_eq!(<Hmac<Sha256State, 32> as Mac<32>>::MIN_TAG_LEN, 16);
+    assert_eq!(<Hmac<Sha512State, 64> as Mac<64>>::MIN_TAG_LEN, 32);
+}
+
+#[test]
+fn assert_hmac_zeroize_wipes_the_keyed_state() {
+    let mut hmac = Hmac::<Sha256State, 32>::new(KEY);
+    let mut other_hmac = Hmac::<Sha256State, 32>::new(b"another key");
+    other_hmac.update(MSG);
+    assert_ne!(hmac, other_hmac);
+
+    hmac.zeroize();
+    other_hmac.zeroize();
+    assert_eq!(hmac, other_hmac);
+    assert_ne!(hmac, Hmac::<Sha256State, 32>::new(KEY));
+}
+
+#[test]
+fn assert_hmac_debug_is_redacted() {
+    let hmac = Hmac::<Sha256State, 32>::new(KEY);
+    assert_eq!(format!("{hmac:?}"), "Hmac { .. }");
+}
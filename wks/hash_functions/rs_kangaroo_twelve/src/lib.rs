@@ -0,0 +1,235 @@
+//! # KangarooTwelve `rs_kangaroo_twelve` - Tree hashing over TurboSHAKE
+//!
+//! KangarooTwelve is a family of extendable-output functions, specified in RFC 9861, that hashes its input as a tree
+//! of 8 KiB chunks on top of TurboSHAKE. Messages that fit in a single chunk are hashed directly, while longer ones
+//! have every chunk but the first hashed into a chaining value, and the chaining values are absorbed along with the
+//! first chunk by a final node. Since chunks are independent of each other, their hashes may be computed in parallel,
+//! which yields the same result as computing them serially.
+//!
+//! With the `std` feature, `KangarooTwelve::write_parallel` hashes the chunks of its input on all the available
+//! threads, and `KangarooTwelve::hash_file` does so over a file mapped in memory. Otherwise, chunks are hashed serially.
+//!
+//! Both KT128, formerly known as KangarooTwelve or K12, and KT256 are provided. They take an optional customization
+//! string, which domain separates their outputs.
+//!
+//! ## Usage
+//!
+//! Users can create a new KangarooTwelve instance with a customization string, write data in any number of calls, and
+//! finalize to get the resultant hash of arbitrary length.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use KT128 in Rust:
+//!
+//! ```rust
+//! # use rs_kangaroo_twelve::{HasherContext, Kt128};
+//! let mut kt128 = Kt128::<32>::default();
+//!
+//! let result = HasherContext::finish(&mut kt128);
+//! assert_eq!(format!("{result:02x}"), "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5");
+//! ```
+//!
+//! ## Use Cases
+//!
+//! KangarooTwelve is recommended for hashing large amounts of data, such as:
+//!
+//! - Integrity checks of files, disk and container images.
+//! - Content addressing of large objects.
+//!
+
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::hash::Hasher;
+use rs_hasher_ctx::ByteArrayWrapper;
+pub use rs_hasher_ctx::HasherContext;
+pub use rs_turbo_shake::XofReader;
+use rs_turbo_shake::{TurboShakeHasher, TurboShakeReader, RATE_128, RATE_256};
+
+#[cfg(feature = "std")]
+mod parallel;
+
+/// Size, in bytes, of the chunks the input is split into
+pub const CHUNK_SIZE: usize = 8192;
+
+/// Domain separation byte of the final node when the whole input fits in a single chunk
+const SINGLE_NODE_DOMAIN: u8 = 0x07;
+/// Domain separation byte of the final node when the input spans several chunks
+const FINAL_NODE_DOMAIN: u8 = 0x06;
+/// Domain separation byte of the leaves producing chaining values
+const LEAF_DOMAIN: u8 = 0x0B;
+/// Appended to the first chunk to flag that chaining values follow
+const FIRST_CHUNK_SUFFIX: [u8; 8] = [0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+/// Appended to the final node after the number of chaining values
+const FINAL_NODE_SUFFIX: [u8; 2] = [0xFF, 0xFF];
+/// Byte length of the Keccak-p\[1600\] state, of which the capacity is what is left after the rate
+const STATE_SIZE: usize = 200;
+/// Largest chaining value length, produced by KT256
+const MAX_CHAINING_VALUE_SIZE: usize = 64;
+
+/// KT128, with a 128 bits security strength
+pub type Kt128<'a, const OUTPUT_SIZE: usize> = KangarooTwelve<'a, RATE_128, OUTPUT_SIZE>;
+/// KT256, with a 256 bits security strength
+pub type Kt256<'a, const OUTPUT_SIZE: usize> = KangarooTwelve<'a, RATE_256, OUTPUT_SIZE>;
+
+/// `KangarooTwelve<'a, const RATE: usize, const OUTPUT_SIZE: usize>` is a generic struct that provides KangarooTwelve in
+/// Rust, over the TurboSHAKE instance with the rate given by `RATE` in bytes.
+///
+/// The first chunk is held back until either the input ends, in which case it is hashed as a single node, or more
+/// input arrives, in which case it starts the final node and every following chunk is hashed into a chaining value as
+/// soon as it is complete. The customization string is borrowed for the lifetime of the hasher, as it is only absorbed
+/// after the message.
+///
+/// `KangarooTwelve::finish_xof` gives access to the unbounded output stream.
+///
+/// ## Examples
+///
+/// The result does not depend on how the data is split across `write` calls:
+///
+///```rust
+/// # use std::hash::Hasher;
+/// # use rs_kangaroo_twelve::{HasherContext, Kt256};
+/// let data: Vec<u8> = (0..20_000).map(|i| (i % 251) as u8).collect();
+///
+/// let mut kt256 = Kt256::<64>::new(b"My App");
+/// kt256.write(&data);
+///
+/// let mut chunked_kt256 = Kt256::<64>::new(b"My App");
+/// data.chunks(1000).for_each(|chunk| chunked_kt256.write(chunk));
+///
+/// assert_eq!(HasherContext::finish(&mut kt256), HasherContext::finish(&mut chunked_kt256));
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct KangarooTwelve<'a, const RATE: usize, const OUTPUT_SIZE: usize> {
+    customization: &'a [u8],
+    first_chunk: [u8; CHUNK_SIZE],
+    first_chunk_len: usize,
+    final_node: TurboShakeHasher<RATE, 0, FINAL_NODE_DOMAIN>,
+    leaf: TurboShakeHasher<RATE, 0, LEAF_DOMAIN>,
+    leaf_len: usize,
+    leaves_count: u64,
+    tree_mode: bool,
+}
+
+impl<'a, const RATE: usize, const OUTPUT_SIZE: usize> KangarooTwelve<'a, RATE, OUTPUT_SIZE> {
+    /// Creates a new KangarooTwelve context with the given customization string, which may be empty.
+    pub fn new(customization: &'a [u8]) -> Self {
+        Self {
+            customization,
+            first_chunk: [0u8; CHUNK_SIZE],
+            first_chunk_len: 0,
+            final_node: TurboShakeHasher::default(),
+            leaf: TurboShakeHasher::default(),
+            leaf_len: 0,
+            leaves_count: 0,
+            tree_mode: false,
+        }
+    }
+
+    /// Finishes the KangarooTwelve computation and returns a reader over its unbounded output stream.
+    pub fn finish_xof(&mut self) -> TurboShakeReader<RATE> {
+        let customization = self.customization;
+        self.absorb(customization);
+        self.absorb(length_encode(customization.len() as u64, &mut [0u8; 9]));
+
+        if !self.tree_mode {
+            let mut single_node = TurboShakeHasher::<RATE, 0, SINGLE_NODE_DOMAIN>::default();
+            single_node.write(&self.first_chunk[..self.first_chunk_len]);
+            return single_node.finish_xof();
+        }
+
+        if self.leaf_len > 0 {
+            self.absorb_leaf();
+        }
+        self.final_node.write(length_encode(self.leaves_count, &mut [0u8; 9]));
+        self.final_node.write(&FINAL_NODE_SUFFIX);
+
+        self.final_node.finish_xof()
+    }
+
+    fn absorb(&mut self, mut bytes: &[u8]) {
+        if self.first_chunk_len < CHUNK_SIZE {
+            let len = bytes.len().min(CHUNK_SIZE - self.first_chunk_len);
+            self.first_chunk[self.first_chunk_len..self.first_chunk_len + len].clone_from_slice(&bytes[..len]);
+            self.first_chunk_len += len;
+            bytes = &bytes[len..];
+        }
+
+        if bytes.is_empty() {
+            return;
+        }
+
+        self.start_tree();
+        while !bytes.is_empty() {
+            let len = bytes.len().min(CHUNK_SIZE - self.leaf_len);
+            self.leaf.write(&bytes[..len]);
+            self.leaf_len += len;
+            bytes = &bytes[len..];
+
+            if self.leaf_len == CHUNK_SIZE {
+                self.absorb_leaf();
+            }
+        }
+    }
+
+    /// Starts the final node with the first chunk, once input goes beyond it.
+    fn start_tree(&mut self) {
+        if !self.tree_mode {
+            self.final_node.write(&self.first_chunk);
+            self.final_node.write(&FIRST_CHUNK_SUFFIX);
+            self.tree_mode = true;
+        }
+    }
+
+    fn absorb_leaf(&mut self) {
+        let mut chaining_value = [0u8; MAX_CHAINING_VALUE_SIZE];
+        self.leaf.finish_xof().read(&mut chaining_value);
+
+        self.final_node.write(&chaining_value[..STATE_SIZE - RATE]);
+        self.leaf = TurboShakeHasher::default();
+        self.leaf_len = 0;
+        self.leaves_count += 1;
+    }
+}
+
+/// Encodes `x` as its big-endian bytes without leading zeros, followed by the number of such bytes.
+fn length_encode(x: u64, buffer: &mut [u8; 9]) -> &[u8] {
+    let len = (u64::BITS - x.leading_zeros()).div_ceil(8) as usize;
+
+    buffer[..len].clone_from_slice(&x.to_be_bytes()[8 - len..]);
+    buffer[len] = len as u8;
+
+    &buffer[..=len]
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Default for KangarooTwelve<'_, RATE, OUTPUT_SIZE> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Hasher for KangarooTwelve<'_, RATE, OUTPUT_SIZE> {
+    fn finish(&self) -> u64 {
+        let mut u64_be_bytes = [0u8; 8];
+        self.clone().finish_xof().read(&mut u64_be_bytes);
+
+        u64::from_be_bytes(u64_be_bytes)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.absorb(bytes)
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for KangarooTwelve<'_, RATE, OUTPUT_SIZE> {
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn finish(&mut self) -> Self::Output {
+        let mut output = [0u8; OUTPUT_SIZE];
+        self.finish_xof().read(&mut output);
+
+        output.into()
+    }
+}
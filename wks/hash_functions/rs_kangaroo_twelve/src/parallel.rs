@@ -0,0 +1,119 @@
+use crate::{KangarooTwelve, CHUNK_SIZE, LEAF_DOMAIN, MAX_CHAINING_VALUE_SIZE, STATE_SIZE};
+use core::hash::Hasher;
+use rs_hasher_ctx::{ByteArrayWrapper, HasherContext};
+use rs_turbo_shake::{TurboShakeHasher, XofReader};
+use std::{fs::File, io, path::Path, thread, vec};
+
+/// Count of chunks each thread hashes before their chaining values are absorbed, which bounds the memory they take
+const CHUNKS_PER_THREAD: usize = 256;
+
+impl<'a, const RATE: usize, const OUTPUT_SIZE: usize> KangarooTwelve<'a, RATE, OUTPUT_SIZE> {
+    /// Absorbs `bytes` as `Hasher::write` does, but hashes the whole chunks they span on all the available threads.
+    ///
+    /// The chunks are split into contiguous runs, one per thread, whose chaining values are then absorbed in order,
+    /// so that the result is the same as with `Hasher::write`. It may be called any number of times, with buffers
+    /// spanning several chunks to benefit from the threads.
+    ///
+    /// ## Examples
+    ///
+    ///```rust
+    /// # use std::hash::Hasher;
+    /// # use rs_kangaroo_twelve::{HasherContext, Kt128};
+    /// let data: Vec<u8> = (0..1_000_000).map(|i| (i % 251) as u8).collect();
+    ///
+    /// let mut kt128 = Kt128::<32>::new(b"Artifact");
+    /// data.chunks(100_000).for_each(|buffer| kt128.write_parallel(buffer));
+    ///
+    /// let mut serial_kt128 = Kt128::<32>::new(b"Artifact");
+    /// serial_kt128.write(&data);
+    ///
+    /// assert_eq!(HasherContext::finish(&mut kt128), HasherContext::finish(&mut serial_kt128));
+    ///```
+    pub fn write_parallel(&mut self, mut bytes: &[u8]) {
+        // Chunks are only hashed apart from the hasher once the first chunk and the current leaf are complete
+        let head_len = if self.first_chunk_len < CHUNK_SIZE {
+            CHUNK_SIZE - self.first_chunk_len
+        } else {
+            (CHUNK_SIZE - self.leaf_len) % CHUNK_SIZE
+        };
+        let (head, tail) = bytes.split_at(head_len.min(bytes.len()));
+        self.absorb(head);
+        bytes = tail;
+
+        let chunks_len = bytes.len() - bytes.len() % CHUNK_SIZE;
+        let (chunks, tail) = bytes.split_at(chunks_len);
+        if !chunks.is_empty() {
+            self.start_tree();
+            self.absorb_chunks_in_parallel(chunks);
+        }
+        self.absorb(tail);
+    }
+
+    /// Hashes the file at `path`, mapped in memory and absorbed with `KangarooTwelve::write_parallel`, with the
+    /// `customization` string.
+    ///
+    /// The file must not be modified while it is hashed: mapped in memory, its changes would be seen halfway through.
+    ///
+    /// ## Examples
+    ///
+    ///```rust
+    /// # use std::hash::Hasher;
+    /// # use rs_kangaroo_twelve::{HasherContext, Kt128};
+    /// let path = std::env::temp_dir().join("rs_kangaroo_twelve_hash_file_example");
+    /// let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    /// std::fs::write(&path, &data).unwrap();
+    ///
+    /// let digest = Kt128::<32>::hash_file(b"", &path).unwrap();
+    ///
+    /// let mut kt128 = Kt128::<32>::default();
+    /// kt128.write(&data);
+    /// assert_eq!(digest, HasherContext::finish(&mut kt128));
+    /// # std::fs::remove_file(&path).unwrap();
+    ///```
+    pub fn hash_file<P: AsRef<Path>>(customization: &'a [u8], path: P) -> io::Result<ByteArrayWrapper<OUTPUT_SIZE>> {
+        let file = File::open(path)?;
+        let mut kangaroo_twelve = Self::new(customization);
+
+        // Empty files cannot be mapped on every platform, and there is nothing to absorb from them anyway
+        if file.metadata()?.len() > 0 {
+            // SAFETY: the mapping is only read from, and the caller is warned against modifying the file meanwhile
+            let mmap = unsafe { memmap2::Mmap::map(&file)? };
+            kangaroo_twelve.write_parallel(&mmap);
+        }
+
+        Ok(HasherContext::finish(&mut kangaroo_twelve))
+    }
+
+    fn absorb_chunks_in_parallel(&mut self, chunks: &[u8]) {
+        let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+        let chaining_value_len = STATE_SIZE - RATE;
+        let mut chaining_values = vec![[0u8; MAX_CHAINING_VALUE_SIZE]; threads * CHUNKS_PER_THREAD];
+
+        for batch in chunks.chunks(threads * CHUNKS_PER_THREAD * CHUNK_SIZE) {
+            let leaves_count = batch.len() / CHUNK_SIZE;
+            let chunks_per_thread = leaves_count.div_ceil(threads);
+
+            thread::scope(|scope| {
+                let runs = batch.chunks(chunks_per_thread * CHUNK_SIZE);
+                for (run, chaining_values) in runs.zip(chaining_values.chunks_mut(chunks_per_thread)) {
+                    scope.spawn(move || {
+                        for (chunk, chaining_value) in run.chunks_exact(CHUNK_SIZE).zip(chaining_values) {
+                            leaf_chaining_value::<RATE>(chunk, &mut chaining_value[..chaining_value_len]);
+                        }
+                    });
+                }
+            });
+
+            for chaining_value in &chaining_values[..leaves_count] {
+                self.final_node.write(&chaining_value[..chaining_value_len]);
+            }
+            self.leaves_count += leaves_count as u64;
+        }
+    }
+}
+
+fn leaf_chaining_value<const RATE: usize>(chunk: &[u8], chaining_value: &mut [u8]) {
+    let mut leaf = TurboShakeHasher::<RATE, 0, LEAF_DOMAIN>::default();
+    leaf.write(chunk);
+    leaf.finish_xof().read(chaining_value);
+}
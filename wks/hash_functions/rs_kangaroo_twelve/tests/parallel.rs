@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+
+use rs_kangaroo_twelve::{HasherContext, KangarooTwelve, Kt128, Kt256, CHUNK_SIZE};
+use std::hash::Hasher;
+
+/// The `ptn(n)` test pattern of RFC 9861: the byte sequence `00 01 .. F9 FA 00 01 ..` of length `n`
+fn ptn(n: usize) -> Vec<u8> {
+    (0..n).map(|i| (i % 251) as u8).collect()
+}
+
+fn assert_parallel_matches_serial<const RATE: usize, const OUTPUT_SIZE: usize>(msg: &[u8], split: usize) {
+    let mut parallel = KangarooTwelve::<RATE, OUTPUT_SIZE>::new(b"Parallel");
+    msg.chunks(split.max(1)).for_each(|buffer| parallel.write_parallel(buffer));
+
+    let mut serial = KangarooTwelve::<RATE, OUTPUT_SIZE>::new(b"Parallel");
+    serial.write(msg);
+
+    assert_eq!(HasherContext::finish(&mut parallel), HasherContext::finish(&mut serial), "{} bytes", msg.len());
+}
+
+#[test]
+fn assert_write_parallel_matches_write_around_chunk_boundaries() {
+    for chunks in [0, 1, 2, 3, 17] {
+        for delta in [-1isize, 0, 1] {
+            let msg = ptn((chunks * CHUNK_SIZE).saturating_add_signed(delta));
+            for split in [msg.len(), CHUNK_SIZE, CHUNK_SIZE + 1, 5 * CHUNK_SIZE - 3] {
+                assert_parallel_matches_serial::<168, 32>(&msg, split);
+                assert_parallel_matches_serial::<136, 64>(&msg, split);
+            }
+        }
+    }
+}
+
+#[test]
+fn assert_write_parallel_resumes_after_serial_writes() {
+    // Spans more chunks than a batch of a single thread
+    let msg = ptn(300 * CHUNK_SIZE + 123);
+
+    let mut kt128 = Kt128::<32>::default();
+    kt128.write(&msg[..100]);
+    kt128.write_parallel(&msg[100..CHUNK_SIZE + 200]);
+    kt128.write(&msg[CHUNK_SIZE + 200..CHUNK_SIZE + 300]);
+    kt128.write_parallel(&msg[CHUNK_SIZE + 300..]);
+
+    let mut serial_kt128 = Kt128::<32>::default();
+    serial_kt128.write(&msg);
+
+    assert_eq!(HasherContext::finish(&mut kt128), HasherContext::finish(&mut serial_kt128));
+}
+
+#[test]
+fn assert_hash_file_matches_write() {
+    let dir = std::env::temp_dir().join(format!("rs_kangaroo_twelve_hash_file_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for len in [0, 1, CHUNK_SIZE, 100 * CHUNK_SIZE + 7] {
+        let path = dir.join(len.to_string());
+        let msg = ptn(len);
+        std::fs::write(&path, &msg).unwrap();
+
+        let mut kt256 = Kt256::<64>::new(b"File");
+        kt256.write(&msg);
+        assert_eq!(Kt256::<64>::hash_file(b"File", &path).unwrap(), HasherContext::finish(&mut kt256));
+    }
+
+    assert!(Kt128::<32>::hash_file(b"", dir.join("missing")).is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,126 @@
+use rs_kangaroo_twelve::{HasherContext, KangarooTwelve, Kt128, Kt256, XofReader, CHUNK_SIZE};
+use std::hash::Hasher;
+
+/// The `ptn(n)` test pattern of RFC 9861: the byte sequence `00 01 .. F9 FA 00 01 ..` of length `n`
+fn ptn(n: usize) -> Vec<u8> {
+    (0..n).map(|i| (i % 251) as u8).collect()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn kangaroo_twelve<const RATE: usize, const OUTPUT_SIZE: usize>(msg: &[u8], customization: &[u8]) -> String {
+    let mut kangaroo_twelve = KangarooTwelve::<RATE, OUTPUT_SIZE>::new(customization);
+    kangaroo_twelve.write(msg);
+    format!("{:02x}", HasherContext::finish(&mut kangaroo_twelve))
+}
+
+#[test]
+fn assert_kt128_rfc9861_ptn_samples() {
+    let expected = [
+        "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5",
+        "2bda92450e8b147f8a7cb629e784a058efca7cf7d8218e02d345dfaa65244a1f",
+        "6bf75fa2239198db4772e36478f8e19b0f371205f6a9a93a273f51df37122888",
+        "0c315ebcdedbf61426de7dcf8fb725d1e74675d7f5327a5067f367b108ecb67c",
+        "cb552e2ec77d9910701d578b457ddf772c12e322e4ee7fe417f92c758f0d59d0",
+        "8701045e22205345ff4dda05555cbb5c3af1a771c2b89baef37db43d9998b9fe",
+        "844d610933b1b9963cbdeb5ae3b6b05cc7cbd67ceedf883eb678a0a8e0371682",
+    ];
+
+    assert_eq!(kangaroo_twelve::<168, 32>(b"", b""), expected[0]);
+    for (i, expected) in expected.iter().skip(1).enumerate() {
+        assert_eq!(kangaroo_twelve::<168, 32>(&ptn(17usize.pow(i as u32)), b""), *expected);
+    }
+}
+
+#[test]
+fn assert_kt128_rfc9861_customization_samples() {
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(b"", &ptn(1)),
+        "fab658db63e94a246188bf7af69a133045f46ee984c56e3c3328caaf1aa1a583"
+    );
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(&[0xFF], &ptn(41)),
+        "d848c5068ced736f4462159b9867fd4c20b808acc3d5bc48e0b06ba0a3762ec4"
+    );
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(&[0xFF; 3], &ptn(41 * 41)),
+        "c389e5009ae57120854c2e8c64670ac01358cf4c1baf89447a724234dc7ced74"
+    );
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(&[0xFF; 7], &ptn(41 * 41 * 41)),
+        "75d2f86a2e644566726b4fbcfc5657b9dbcf070c7b0dca06450ab291d7443bcf"
+    );
+}
+
+#[test]
+fn assert_kt128_rfc9861_chunk_boundary_samples() {
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(&ptn(CHUNK_SIZE - 1), b""),
+        "1b577636f723643e990cc7d6a659837436fd6a103626600eb8301cd1dbe553d6"
+    );
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(&ptn(CHUNK_SIZE), b""),
+        "48f256f6772f9edfb6a8b661ec92dc93b95ebd05a08a17b39ae3490870c926c3"
+    );
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(&ptn(CHUNK_SIZE), &ptn(CHUNK_SIZE - 1)),
+        "7d65682e84874049e95c53d333841cb03a6e13dfc58a2b0ef9efcc1d55be0c96"
+    );
+    assert_eq!(
+        kangaroo_twelve::<168, 32>(&ptn(CHUNK_SIZE), &ptn(CHUNK_SIZE)),
+        "90e81832ff83e1c18f4c3d10dcc644688a077e272718bbb2e01c41785982d697"
+    );
+}
+
+#[test]
+fn assert_kt256_rfc9861_ptn_samples() {
+    let expected = [
+        "b23d2e9cea9f4904e02bec06817fc10ce38ce8e93ef4c89e6537076af8646404e3e8b68107b8833a5d30490aa33482353fd4adc7148ecb782855003aaebde4a9",
+        "0d005a194085360217128cf17f91e1f71314efa5564539d444912e3437efa17f82db6f6ffe76e781eaa068bce01f2bbf81eacb983d7230f2fb02834a21b1ddd0",
+        "1ba3c02b1fc514474f06c8979978a9056c8483f4a1b63d0dccefe3a28a2f323e1cdcca40ebf006ac76ef0397152346837b1277d3e7faa9c9653b19075098527b",
+        "de8ccbc63e0f133ebb4416814d4c66f691bbf8b6a61ec0a7700f836b086cb029d54f12ac7159472c72db118c35b4e6aa213c6562caaa9dcc518959e69b10f3ba",
+        "647efb49fe9d717500171b41e7f11bd491544443209997ce1c2530d15eb1ffbb598935ef954528ffc152b1e4d731ee2683680674365cd191d562bae753b84aa5",
+        "b06275d284cd1cf205bcbe57dccd3ec1ff6686e3ed15776383e1f2fa3c6ac8f08bf8a162829db1a44b2a43ff83dd89c3cf1ceb61ede659766d5ccf817a62ba8d",
+        "9473831d76a4c7bf77ace45b59f1458b1673d64bcd877a7c66b2664aa6dd149e60eab71b5c2bab858c074ded81ddce2b4022b5215935c0d4d19bf511aeeb0772",
+    ];
+
+    assert_eq!(kangaroo_twelve::<136, 64>(b"", b""), expected[0]);
+    for (i, expected) in expected.iter().skip(1).enumerate() {
+        assert_eq!(kangaroo_twelve::<136, 64>(&ptn(17usize.pow(i as u32)), b""), *expected);
+    }
+}
+
+#[test]
+fn assert_kt128_long_output_tail() {
+    let mut output = [0u8; 10032];
+    Kt128::<0>::default().finish_xof().read(&mut output);
+
+    assert_eq!(to_hex(&output[10000..]), "e8dc563642f7228c84684c898405d3a834799158c079b12880277a1d28e2ff6d");
+}
+
+#[test]
+fn assert_result_is_independent_of_write_boundaries() {
+    let data = ptn(17usize.pow(4));
+
+    let mut whole = Kt128::<32>::new(b"container image");
+    whole.write(&data);
+
+    for chunk_size in [1000, CHUNK_SIZE - 1, CHUNK_SIZE, CHUNK_SIZE + 1] {
+        let mut chunked = Kt128::<32>::new(b"container image");
+        data.chunks(chunk_size).for_each(|chunk| chunked.write(chunk));
+
+        assert_eq!(HasherContext::finish(&mut chunked), HasherContext::finish(&mut whole.clone()));
+    }
+}
+
+#[test]
+fn assert_u64_result_is_the_big_endian_output_prefix() {
+    let mut kt256 = Kt256::<64>::default();
+    kt256.write(&ptn(3 * CHUNK_SIZE));
+
+    let u64result = kt256.finish();
+    let bytes_result = HasherContext::finish(&mut kt256);
+    assert_eq!(u64result.to_be_bytes(), bytes_result[..8]);
+}
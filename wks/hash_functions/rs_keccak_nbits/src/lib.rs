@@ -21,7 +21,7 @@
 //! let mut keccakhasher = NBitKeccakState::<u32, 10, 24>::default().build_hasher();
 //! keccakhasher.write(b"hello world");
 //! let result = keccakhasher.finish();
-//! assert_eq!(result, 0xE4B4F07EA10803E6);
+//! assert_eq!(result, 0xFC2C439FE8D13841);
 //! ```
 //!
 //! Or, as a `HashSet`:
@@ -46,8 +46,8 @@
 //! let u64result2 = keccakhasher2.finish();
 //! let u64result3 = keccakhasher3.finish();
 //!
-//! assert_eq!(u64result1, 0x5A6B41FB00CB25F1);
-//! assert_eq!(u64result2, 0xA77CCB55197AE1DD);
+//! assert_eq!(u64result1, 0x8A8543F9B7644003);
+//! assert_eq!(u64result2, 0x8270C8DD7C8C98C0);
 //! assert_eq!(u64result2, u64result3);
 //! assert_ne!(u64result1, u64result2);
 //! ```
@@ -67,6 +67,7 @@
 #![no_std]
 
 pub use crate::{n_bit_keccak_hasher::NBitKeccakHasher, n_bit_keccak_state::NBitKeccakState};
+pub use rs_hasher_ctx::{Midstate, MidstateError, MIDSTATE_VERSION};
 
 mod n_bit_keccak_hasher;
 mod n_bit_keccak_state;
@@ -1,7 +1,7 @@
 use crate::NBitKeccakState;
 use core::hash::Hasher;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
-use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext};
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Midstate, MidstateError};
 use rs_internal_hasher::HashAlgorithm;
 use rs_internal_state::ExtendedOutputFunction;
 use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
@@ -143,3 +143,29 @@ where
         HasherContext::finish(&mut self.0).squeeze().into()
     }
 }
+
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize> Midstate for NBitKeccakHasher<T, RATE, OUTPUT_SIZE>
+where
+    T: BitAnd
+        + BitAndAssign
+        + BitOr<NBitWord<T>, Output = NBitWord<T>>
+        + BitXor<Output = T>
+        + BitXorAssign
+        + Copy
+        + Default
+        + Not<Output = T>,
+    NBitWord<T>: From<u64> + LittleEndianBytes + Not<Output = NBitWord<T>> + Rotate + TSize<T>,
+    u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
+{
+    fn midstate_len(&self) -> usize {
+        self.0.midstate_len()
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.0.export_midstate(bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        GenericHasher::import_midstate(bytes).map(Self)
+    }
+}
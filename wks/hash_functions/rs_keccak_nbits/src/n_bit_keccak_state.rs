@@ -2,8 +2,8 @@ use crate::NBitKeccakHasher;
 use core::hash::BuildHasher;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
 use rs_hasher_ctx::ByteArrayWrapper;
-use rs_internal_hasher::{GenericPad, HashAlgorithm, KeccakU128Size};
-use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge};
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, KeccakU128Size};
+use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge, Zeroize};
 use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
 
 /// `NBitKeccakState` represents the state of a Keccak-nBits hashing process.
@@ -150,3 +150,39 @@ where
         self.squeeze_u64()
     }
 }
+
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize> HashMidstate for NBitKeccakState<T, RATE, OUTPUT_SIZE>
+where
+    T: BitAnd
+        + BitAndAssign
+        + BitOr<NBitWord<T>, Output = NBitWord<T>>
+        + BitXor<Output = T>
+        + BitXorAssign
+        + Copy
+        + Default
+        + Not<Output = T>,
+    NBitWord<T>: From<u64> + LittleEndianBytes + Not<Output = NBitWord<T>> + Rotate + TSize<T>,
+    u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
+{
+    const ALGORITHM: &'static str = "Keccak";
+    const MIDSTATE_LEN: usize = KeccakSponge::<T, RATE, OUTPUT_SIZE>::LANES_BYTES_LEN;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        self.sponge.export_lanes(bytes)
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        Self {
+            sponge: KeccakSponge::import_lanes(bytes),
+        }
+    }
+}
+
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize> Zeroize for NBitKeccakState<T, RATE, OUTPUT_SIZE>
+where
+    T: Copy + Default,
+{
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+    }
+}
@@ -18,14 +18,14 @@ fn u8_keccak_18rate_24bytes() {
 
     let u64result = hasher.finish();
     let bytes_result = HasherContext::finish(&mut hasher);
-    assert_eq!(u64result, 0x32EFB96DFBF80718);
-    assert_eq!(format!("{bytes_result:02x}"), "32efb96dfbf807182d277c3d908062e41b2b01a93c029eed");
-    assert_eq!(format!("{bytes_result:02X}"), "32EFB96DFBF807182D277C3D908062E41B2B01A93C029EED");
+    assert_eq!(u64result, 0x2855A8F71E8965C7);
+    assert_eq!(format!("{bytes_result:02x}"), "2855a8f71e8965c7b3c8de32e5ff0fba34433ca4a4520f14");
+    assert_eq!(format!("{bytes_result:02X}"), "2855A8F71E8965C7B3C8DE32E5FF0FBA34433CA4A4520F14");
     assert_eq!(
         bytes_result,
         [
-            0x32, 0xEF, 0xB9, 0x6D, 0xFB, 0xF8, 0x07, 0x18, 0x2D, 0x27, 0x7C, 0x3D, 0x90, 0x80, 0x62, 0xE4, 0x1B, 0x2B,
-            0x01, 0xA9, 0x3C, 0x02, 0x9E, 0xED
+            0x28, 0x55, 0xA8, 0xF7, 0x1E, 0x89, 0x65, 0xC7, 0xB3, 0xC8, 0xDE, 0x32, 0xE5, 0xFF, 0x0F, 0xBA, 0x34, 0x43,
+            0x3C, 0xA4, 0xA4, 0x52, 0x0F, 0x14
         ]
     );
 }
@@ -43,14 +43,14 @@ fn u16_keccak_18rate_24bytes() {
 
     let u64result = hasher.finish();
     let bytes_result = HasherContext::finish(&mut hasher);
-    assert_eq!(u64result, 0xF967941A80194CD2);
-    assert_eq!(format!("{bytes_result:02x}"), "f967941a80194cd217521fdac2607106897e5d8fa3bf19a6");
-    assert_eq!(format!("{bytes_result:02X}"), "F967941A80194CD217521FDAC2607106897E5D8FA3BF19A6");
+    assert_eq!(u64result, 0x3552DDE6F874789C);
+    assert_eq!(format!("{bytes_result:02x}"), "3552dde6f874789c7276bcf6217fee35b0a218da2676d32b");
+    assert_eq!(format!("{bytes_result:02X}"), "3552DDE6F874789C7276BCF6217FEE35B0A218DA2676D32B");
     assert_eq!(
         bytes_result,
         [
-            0xF9, 0x67, 0x94, 0x1A, 0x80, 0x19, 0x4C, 0xD2, 0x17, 0x52, 0x1F, 0xDA, 0xC2, 0x60, 0x71, 0x06, 0x89, 0x7E,
-            0x5D, 0x8F, 0xA3, 0xBF, 0x19, 0xA6
+            0x35, 0x52, 0xDD, 0xE6, 0xF8, 0x74, 0x78, 0x9C, 0x72, 0x76, 0xBC, 0xF6, 0x21, 0x7F, 0xEE, 0x35, 0xB0, 0xA2,
+            0x18, 0xDA, 0x26, 0x76, 0xD3, 0x2B
         ]
     );
 }
@@ -0,0 +1,223 @@
+//! # KMAC `rs_kmac` - Keccak Message Authentication Code
+//!
+//! KMAC is a keyed message authentication code built on top of cSHAKE, specified by the National Institute of Standards
+//! and Technology (NIST) in SP 800-185. Unlike HMAC, it does not need to hash the message twice, as the Keccak sponge is
+//! not subject to length extension.
+//!
+//! Both KMAC128 and KMAC256 are provided, as well as their extendable-output variants, KMACXOF128 and KMACXOF256, whose
+//! output length is not bound to the requested length.
+//!
+//! ## Usage
+//!
+//! The crate provides a straightforward API. Users can create a new KMAC instance with a key and a customization
+//! string, update it with input data, and finalize to get the resultant MAC.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use KMAC128 in Rust:
+//!
+//! ```rust
+//! # use std::hash::Hasher;
+//! # use rs_kmac::{HasherContext, Kmac128};
+//! let key: Vec<u8> = (0x40..0x60).collect();
+//! let mut kmac = Kmac128::<32>::new(&key, b"My Tagged Application");
+//! kmac.write(&[0x00, 0x01, 0x02, 0x03]);
+//!
+//! let result = HasherContext::finish(&mut kmac);
+//! assert_eq!(format!("{result:02x}"), "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5");
+//! ```
+//!
+//! ## Use Cases
+//!
+//! KMAC is recommended for a variety of tasks, including:
+//!
+//! - Ensuring data integrity and authenticity in data communications.
+//! - Key derivation, through the arbitrary output length of KMACXOF.
+//! - Pseudorandom function instantiation, where a keyed Keccak based construction is desired.
+//!
+
+#![no_std]
+
+use core::fmt::{Debug, Formatter};
+use core::hash::Hasher;
+use rs_cshake::{bytepad, encode_string, right_encode, CShakeHasher, RATE_128, RATE_256};
+use rs_hasher_ctx::ByteArrayWrapper;
+pub use rs_hasher_ctx::{HasherContext, Zeroize};
+use rs_internal_state::KeccakXofReader;
+pub use rs_internal_state::XofReader;
+
+const FUNCTION_NAME: &[u8; 4] = b"KMAC";
+
+/// KMAC128 message authentication code, with a 128 bits security strength
+pub type Kmac128<const OUTPUT_SIZE: usize> = Kmac<RATE_128, OUTPUT_SIZE>;
+/// KMAC256 message authentication code, with a 256 bits security strength
+pub type Kmac256<const OUTPUT_SIZE: usize> = Kmac<RATE_256, OUTPUT_SIZE>;
+
+/// `Kmac<const RATE: usize, const OUTPUT_SIZE: usize>` is a generic struct that provides the KMAC (Keccak Message
+/// Authentication Code) in Rust, for the rate given by `RATE` in bytes.
+///
+/// The key is encoded, padded to the rate and absorbed right after the `"KMAC"` function name and the customization
+/// string. As the requested output length is appended to the message before squeezing, outputs of different lengths
+/// are unrelated to each other. `Kmac::finish_xof` provides the KMACXOF variant, in which the output length is encoded
+/// as zero and the output can be squeezed indefinitely.
+///
+/// `Kmac128` and `Kmac256` are the instances with the rates defined by NIST SP 800-185.
+///
+/// ## Examples
+///
+/// The following example reproduces the first KMACXOF128 sample published by NIST:
+///
+///```rust
+/// # use std::hash::Hasher;
+/// # use rs_kmac::{Kmac128, XofReader};
+/// let key: Vec<u8> = (0x40..0x60).collect();
+/// let mut kmac = Kmac128::<0>::new(&key, b"");
+/// kmac.write(&[0x00, 0x01, 0x02, 0x03]);
+///
+/// let mut reader = kmac.finish_xof();
+/// let mut result = [0u8; 32];
+/// reader.read(&mut result);
+///
+/// assert_eq!(
+///     result,
+///     [
+///         0xCD, 0x83, 0x74, 0x0B, 0xBD, 0x92, 0xCC, 0xC8, 0xCF, 0x03, 0x2B, 0x14, 0x81, 0xA0, 0xF4, 0x46, 0x0E, 0x7C,
+///         0xA9, 0xDD, 0x12, 0xB0, 0x8A, 0x0C, 0x40, 0x31, 0x17, 0x8B, 0xAC, 0xD6, 0xEC, 0x35
+///     ]
+/// );
+///```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Kmac<const RATE: usize, const OUTPUT_SIZE: usize> {
+    cshake: CShakeHasher<RATE, OUTPUT_SIZE>,
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Kmac<RATE, OUTPUT_SIZE> {
+    /// Creates a new KMAC context with the given key and customization string.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A byte slice that holds the key. Any length is accepted, although it should match the targeted
+    ///   security strength.
+    /// * `customization` - A byte slice that holds the customization string, which may be empty.
+    ///
+    /// # Returns
+    ///
+    /// A new KMAC context.
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        let mut cshake = CShakeHasher::new(FUNCTION_NAME, customization);
+
+        let mut block = [0u8; RATE];
+        for (i, byte) in bytepad(encode_string(key), RATE).enumerate() {
+            block[i % RATE] = byte;
+
+            if i % RATE == RATE - 1 {
+                cshake.write(&block);
+            }
+        }
+        block.zeroize();
+
+        Self {
+            cshake,
+        }
+    }
+
+    /// Computes the KMAC of a message with a key and a customization string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rs_kmac::Kmac256;
+    ///
+    /// let key: Vec<u8> = (0x40..0x60).collect();
+    /// let result = Kmac256::<64>::digest(&key, &[0x00, 0x01, 0x02, 0x03], b"My Tagged Application");
+    ///
+    /// assert_eq!(
+    ///     format!("{result:02x}"),
+    ///     "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+    ///     f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+    /// );
+    /// ```
+    pub fn digest(key: &[u8], msg: &[u8], customization: &[u8]) -> ByteArrayWrapper<OUTPUT_SIZE> {
+        let mut kmac = Self::new(key, customization);
+        kmac.write(msg);
+        HasherContext::finish(&mut kmac)
+    }
+
+    /// Finishes the KMACXOF computation and returns a reader over its unbounded output stream.
+    pub fn finish_xof(&mut self) -> KeccakXofReader<u64, RATE> {
+        self.cshake.write(right_encode(0).as_ref());
+        self.cshake.finish_xof()
+    }
+
+    /// Finishes the KMAC computation with the requested output length `L` set to the length of `output`, rather than
+    /// to `OUTPUT_SIZE`, and fills `output` with it.
+    ///
+    /// This is KMAC proper, whose output depends on its length, unlike the prefixes of a KMACXOF output. It suits key
+    /// derivation as in SP 800-108, where the length is only known at run time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::hash::Hasher;
+    /// use rs_kmac::{HasherContext, Kmac128};
+    ///
+    /// let key: Vec<u8> = (0x40..0x60).collect();
+    /// let mut kmac = Kmac128::<32>::new(&key, b"My Tagged Application");
+    /// kmac.write(&[0x00, 0x01, 0x02, 0x03]);
+    ///
+    /// let mut output = [0u8; 32];
+    /// kmac.clone().finish_into(&mut output);
+    /// assert_eq!(HasherContext::finish(&mut kmac), output);
+    ///
+    /// let mut longer_output = [0u8; 48];
+    /// kmac.finish_into(&mut longer_output);
+    /// assert_ne!(longer_output[..32], output);
+    /// ```
+    pub fn finish_into(&mut self, output: &mut [u8]) {
+        self.cshake.write(right_encode(output.len() as u64 * 8).as_ref());
+        self.cshake.finish_xof().read(output);
+    }
+
+    fn write_output_len(cshake: &mut CShakeHasher<RATE, OUTPUT_SIZE>) {
+        cshake.write(right_encode(OUTPUT_SIZE as u64 * 8).as_ref());
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Debug for Kmac<RATE, OUTPUT_SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Kmac").finish_non_exhaustive()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Drop for Kmac<RATE, OUTPUT_SIZE> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Hasher for Kmac<RATE, OUTPUT_SIZE> {
+    fn finish(&self) -> u64 {
+        let mut cshake = self.cshake.clone();
+        Self::write_output_len(&mut cshake);
+        cshake.finish()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.cshake.write(bytes)
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for Kmac<RATE, OUTPUT_SIZE> {
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn finish(&mut self) -> Self::Output {
+        Self::write_output_len(&mut self.cshake);
+        HasherContext::finish(&mut self.cshake)
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Zeroize for Kmac<RATE, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.cshake.zeroize();
+    }
+}
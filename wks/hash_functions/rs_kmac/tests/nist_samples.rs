@@ -0,0 +1,198 @@
+use rs_kmac::{HasherContext, Kmac128, Kmac256, XofReader};
+use std::hash::Hasher;
+
+const TAGGED_APPLICATION: &[u8; 21] = b"My Tagged Application";
+
+fn sample_key() -> Vec<u8> {
+    (0x40..0x60).collect()
+}
+
+fn sample_data(len: u8) -> Vec<u8> {
+    (0..len).collect()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[test]
+fn assert_kmac128_nist_sample_1() {
+    let mut kmac = Kmac128::<32>::new(&sample_key(), b"");
+    kmac.write(&sample_data(4));
+
+    let result = HasherContext::finish(&mut kmac);
+    assert_eq!(format!("{result:02x}"), "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e");
+}
+
+#[test]
+fn assert_kmac128_nist_sample_2() {
+    let mut kmac = Kmac128::<32>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(4));
+
+    let result = HasherContext::finish(&mut kmac);
+    assert_eq!(format!("{result:02x}"), "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5");
+}
+
+#[test]
+fn assert_kmac128_nist_sample_3() {
+    let mut kmac = Kmac128::<32>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(200));
+
+    let result = HasherContext::finish(&mut kmac);
+    assert_eq!(format!("{result:02x}"), "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230");
+}
+
+#[test]
+fn assert_kmac256_nist_sample_4() {
+    let mut kmac = Kmac256::<64>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(4));
+
+    let result = HasherContext::finish(&mut kmac);
+    assert_eq!(
+        format!("{result:02x}"),
+        "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+    );
+}
+
+#[test]
+fn assert_kmac256_nist_sample_5() {
+    let mut kmac = Kmac256::<64>::new(&sample_key(), b"");
+    kmac.write(&sample_data(200));
+
+    let result = HasherContext::finish(&mut kmac);
+    assert_eq!(
+        format!("{result:02x}"),
+        "75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69"
+    );
+}
+
+#[test]
+fn assert_kmac256_nist_sample_6() {
+    let mut kmac = Kmac256::<64>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(200));
+
+    let result = HasherContext::finish(&mut kmac);
+    assert_eq!(
+        format!("{result:02x}"),
+        "b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d970fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965"
+    );
+}
+
+#[test]
+fn assert_kmacxof128_nist_sample_1() {
+    let mut kmac = Kmac128::<0>::new(&sample_key(), b"");
+    kmac.write(&sample_data(4));
+
+    let mut result = [0u8; 32];
+    kmac.finish_xof().read(&mut result);
+    assert_eq!(to_hex(&result), "cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35");
+}
+
+#[test]
+fn assert_kmacxof128_nist_sample_2() {
+    let mut kmac = Kmac128::<0>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(4));
+
+    let mut result = [0u8; 32];
+    kmac.finish_xof().read(&mut result);
+    assert_eq!(to_hex(&result), "31a44527b4ed9f5c6101d11de6d26f0620aa5c341def41299657fe9df1a3b16c");
+}
+
+#[test]
+fn assert_kmacxof128_nist_sample_3() {
+    let mut kmac = Kmac128::<0>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(200));
+
+    let mut result = [0u8; 32];
+    kmac.finish_xof().read(&mut result);
+    assert_eq!(to_hex(&result), "47026c7cd793084aa0283c253ef658490c0db61438b8326fe9bddf281b83ae0f");
+}
+
+#[test]
+fn assert_kmacxof256_nist_sample_4() {
+    let mut kmac = Kmac256::<0>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(4));
+
+    let mut result = [0u8; 64];
+    kmac.finish_xof().read(&mut result);
+    assert_eq!(
+        to_hex(&result),
+        "1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa96faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b"
+    );
+}
+
+#[test]
+fn assert_kmacxof256_nist_sample_5() {
+    let mut kmac = Kmac256::<0>::new(&sample_key(), b"");
+    kmac.write(&sample_data(200));
+
+    let mut result = [0u8; 64];
+    kmac.finish_xof().read(&mut result);
+    assert_eq!(
+        to_hex(&result),
+        "ff7b171f1e8a2b24683eed37830ee797538ba8dc563f6da1e667391a75edc02ca633079f81ce12a25f45615ec89972031d18337331d24ceb8f8ca8e6a19fd98b"
+    );
+}
+
+#[test]
+fn assert_kmacxof256_nist_sample_6() {
+    let mut kmac = Kmac256::<0>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(200));
+
+    let mut result = [0u8; 64];
+    kmac.finish_xof().read(&mut result);
+    assert_eq!(
+        to_hex(&result),
+        "d5be731c954ed7732846bb59dbe3a8e30f83e77a4bff4459f2f1c2b4ecebb8ce67ba01c62e8ab8578d2d499bd1bb276768781190020a306a97de281dcc30305d"
+    );
+}
+
+#[test]
+fn assert_digest_matches_incremental_computation() {
+    let mut kmac = Kmac128::<32>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(4));
+
+    assert_eq!(
+        HasherContext::finish(&mut kmac),
+        Kmac128::<32>::digest(&sample_key(), &sample_data(4), TAGGED_APPLICATION)
+    );
+}
+
+#[test]
+fn assert_output_length_is_bound_to_the_result() {
+    let short_result = Kmac128::<16>::digest(&sample_key(), &sample_data(4), b"");
+    let long_result = Kmac128::<32>::digest(&sample_key(), &sample_data(4), b"");
+
+    assert_ne!(short_result[..], long_result[..16]);
+}
+
+#[test]
+fn assert_keys_longer_than_the_rate_are_accepted() {
+    let long_key: Vec<u8> = (0..=255).collect();
+    let mut kmac = Kmac256::<64>::new(&long_key, b"");
+    kmac.write(&sample_data(200));
+
+    let u64result = kmac.finish();
+    let bytes_result = HasherContext::finish(&mut kmac);
+
+    assert_eq!(u64result, u64::from_be_bytes(bytes_result[..8].try_into().unwrap()));
+}
+
+#[test]
+fn assert_kmac_finish_into_matches_the_fixed_output_sizes() {
+    let mut kmac = Kmac128::<32>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(200));
+    let mut output = [0u8; 32];
+    kmac.finish_into(&mut output);
+    assert_eq!(to_hex(&output), "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230");
+
+    let mut kmac = Kmac256::<64>::new(&sample_key(), TAGGED_APPLICATION);
+    kmac.write(&sample_data(4));
+    let mut output = [0u8; 64];
+    kmac.finish_into(&mut output);
+    assert_eq!(
+        to_hex(&output),
+        "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+         f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+    );
+}
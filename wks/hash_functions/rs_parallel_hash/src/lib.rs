@@ -0,0 +1,174 @@
+//! # ParallelHash `rs_parallel_hash` - Block-wise hashing of long messages
+//!
+//! ParallelHash is a cSHAKE based function, specified by the National Institute of Standards and Technology (NIST) in
+//! SP 800-185, that splits its input into blocks of a chosen size. Each block is hashed on its own, and the resulting
+//! chaining values are absorbed by an outer cSHAKE instance. Since blocks are independent of each other, their hashes
+//! may be computed in parallel; this crate computes them serially, which yields the same result.
+//!
+//! Both ParallelHash128 and ParallelHash256 are provided, as well as their extendable-output variants,
+//! ParallelHashXOF128 and ParallelHashXOF256.
+//!
+//! ## Usage
+//!
+//! Users can create a new ParallelHash instance with a block size in bytes and a customization string, write data in
+//! any number of calls, and finalize to get the resultant hash.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use ParallelHash128 in Rust:
+//!
+//! ```rust
+//! # use std::hash::Hasher;
+//! # use rs_parallel_hash::{HasherContext, ParallelHash128};
+//! let data = [
+//!     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x20, 0x21, 0x22,
+//!     0x23, 0x24, 0x25, 0x26, 0x27,
+//! ];
+//! let mut parallel_hash = ParallelHash128::<32>::new(8, b"");
+//! parallel_hash.write(&data);
+//!
+//! let result = HasherContext::finish(&mut parallel_hash);
+//! assert_eq!(format!("{result:02x}"), "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5");
+//! ```
+//!
+//! ## Use Cases
+//!
+//! ParallelHash is recommended for hashing very long messages, such as:
+//!
+//! - Large files and disk images, where the block hashes can be spread across several cores.
+//! - Streams whose blocks are produced independently of each other.
+//!
+
+#![no_std]
+
+use core::hash::Hasher;
+use rs_cshake::{left_encode, right_encode, CShakeHasher, RATE_128, RATE_256};
+use rs_hasher_ctx::ByteArrayWrapper;
+pub use rs_hasher_ctx::HasherContext;
+use rs_internal_state::KeccakXofReader;
+pub use rs_internal_state::XofReader;
+
+const FUNCTION_NAME: &[u8; 12] = b"ParallelHash";
+/// Byte length of the Keccak-f\[1600\] state, of which the capacity is what is left after the rate
+const STATE_SIZE: usize = 200;
+/// Largest chaining value length, produced by ParallelHash256
+const MAX_CHAINING_VALUE_SIZE: usize = 64;
+
+/// ParallelHash128, with a 128 bits security strength
+pub type ParallelHash128<const OUTPUT_SIZE: usize> = ParallelHash<RATE_128, OUTPUT_SIZE>;
+/// ParallelHash256, with a 256 bits security strength
+pub type ParallelHash256<const OUTPUT_SIZE: usize> = ParallelHash<RATE_256, OUTPUT_SIZE>;
+
+/// `ParallelHash<const RATE: usize, const OUTPUT_SIZE: usize>` is a generic struct that provides ParallelHash in Rust,
+/// for the rate given by `RATE` in bytes.
+///
+/// Every full block of written data is hashed with cSHAKE, without function name nor customization string, into a
+/// chaining value twice as long as the security strength. The chaining values are absorbed by the outer cSHAKE
+/// instance, customized with `"ParallelHash"` and the user given string. The last block may be shorter than the block
+/// size, and the block count is absorbed on finalization along with the output length.
+///
+/// `ParallelHash::finish_xof` provides the ParallelHashXOF variant, whose output can be squeezed indefinitely.
+///
+/// ## Examples
+///
+/// The result does not depend on how the data is split across `write` calls:
+///
+///```rust
+/// # use std::hash::Hasher;
+/// # use rs_parallel_hash::{HasherContext, ParallelHash256};
+/// let data: Vec<u8> = (0..100).collect();
+///
+/// let mut parallel_hash1 = ParallelHash256::<64>::new(16, b"My App");
+/// parallel_hash1.write(&data);
+///
+/// let mut parallel_hash2 = ParallelHash256::<64>::new(16, b"My App");
+/// data.chunks(7).for_each(|chunk| parallel_hash2.write(chunk));
+///
+/// assert_eq!(HasherContext::finish(&mut parallel_hash1), HasherContext::finish(&mut parallel_hash2));
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParallelHash<const RATE: usize, const OUTPUT_SIZE: usize> {
+    outer: CShakeHasher<RATE, OUTPUT_SIZE>,
+    block: CShakeHasher<RATE, 0>,
+    block_size: usize,
+    block_filled: usize,
+    blocks_count: u64,
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> ParallelHash<RATE, OUTPUT_SIZE> {
+    /// Creates a new ParallelHash context.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_size` - The size `B`, in bytes, of the blocks the input is split into. It must be greater than zero.
+    /// * `customization` - The customization string `S`, which may be empty.
+    pub fn new(block_size: usize, customization: &[u8]) -> Self {
+        assert!(block_size > 0, "ParallelHash block size must be greater than zero");
+
+        let mut outer = CShakeHasher::new(FUNCTION_NAME, customization);
+        outer.write(left_encode(block_size as u64).as_ref());
+
+        Self {
+            outer,
+            block: CShakeHasher::default(),
+            block_size,
+            block_filled: 0,
+            blocks_count: 0,
+        }
+    }
+
+    /// Finishes the ParallelHashXOF computation and returns a reader over its unbounded output stream.
+    pub fn finish_xof(&mut self) -> KeccakXofReader<u64, RATE> {
+        self.finish_blocks(0);
+        self.outer.finish_xof()
+    }
+
+    fn absorb_block(&mut self) {
+        let mut chaining_value = [0u8; MAX_CHAINING_VALUE_SIZE];
+        self.block.finish_xof().read(&mut chaining_value);
+
+        self.outer.write(&chaining_value[..STATE_SIZE - RATE]);
+        self.block = CShakeHasher::default();
+        self.block_filled = 0;
+        self.blocks_count += 1;
+    }
+
+    fn finish_blocks(&mut self, output_bits_len: u64) {
+        if self.block_filled > 0 {
+            self.absorb_block();
+        }
+
+        self.outer.write(right_encode(self.blocks_count).as_ref());
+        self.outer.write(right_encode(output_bits_len).as_ref());
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Hasher for ParallelHash<RATE, OUTPUT_SIZE> {
+    fn finish(&self) -> u64 {
+        let mut parallel_hash = self.clone();
+        parallel_hash.finish_blocks(OUTPUT_SIZE as u64 * 8);
+        parallel_hash.outer.finish()
+    }
+
+    fn write(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            let len = bytes.len().min(self.block_size - self.block_filled);
+            self.block.write(&bytes[..len]);
+            self.block_filled += len;
+            bytes = &bytes[len..];
+
+            if self.block_filled == self.block_size {
+                self.absorb_block();
+            }
+        }
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for ParallelHash<RATE, OUTPUT_SIZE> {
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn finish(&mut self) -> Self::Output {
+        self.finish_blocks(OUTPUT_SIZE as u64 * 8);
+        HasherContext::finish(&mut self.outer)
+    }
+}
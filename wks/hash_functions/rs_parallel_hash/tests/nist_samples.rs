@@ -0,0 +1,132 @@
+use rs_parallel_hash::{HasherContext, ParallelHash, ParallelHash128, ParallelHash256, XofReader};
+use std::hash::Hasher;
+
+const PARALLEL_DATA: &[u8; 13] = b"Parallel Data";
+
+/// Builds the NIST sample input of `blocks` blocks of `block_size` bytes, where block `i` starts at `0x10 * i`.
+fn sample_data(blocks: u8, block_size: u8) -> Vec<u8> {
+    (0..blocks).flat_map(|i| (0..block_size).map(move |j| 0x10 * i + j)).collect()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn parallel_hash<const RATE: usize, const OUTPUT_SIZE: usize>(
+    data: &[u8],
+    block_size: usize,
+    customization: &[u8],
+) -> String {
+    let mut parallel_hash = ParallelHash::<RATE, OUTPUT_SIZE>::new(block_size, customization);
+    parallel_hash.write(data);
+    format!("{:02x}", HasherContext::finish(&mut parallel_hash))
+}
+
+fn parallel_hash_xof<const RATE: usize, const OUTPUT_SIZE: usize>(
+    data: &[u8],
+    block_size: usize,
+    customization: &[u8],
+) -> String {
+    let mut parallel_hash = ParallelHash::<RATE, OUTPUT_SIZE>::new(block_size, customization);
+    parallel_hash.write(data);
+    let mut output = [0u8; OUTPUT_SIZE];
+    parallel_hash.finish_xof().read(&mut output);
+    to_hex(&output)
+}
+
+#[test]
+fn assert_parallel_hash128_nist_samples() {
+    assert_eq!(
+        parallel_hash::<168, 32>(&sample_data(3, 8), 8, b""),
+        "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5"
+    );
+    assert_eq!(
+        parallel_hash::<168, 32>(&sample_data(3, 8), 8, PARALLEL_DATA),
+        "fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206"
+    );
+    assert_eq!(
+        parallel_hash::<168, 32>(&sample_data(6, 12), 12, PARALLEL_DATA),
+        "f7fd5312896c6685c828af7e2adb97e393e7f8d54e3c2ea4b95e5aca3796e8fc"
+    );
+}
+
+#[test]
+fn assert_parallel_hash256_nist_samples() {
+    assert_eq!(
+        parallel_hash::<136, 64>(&sample_data(3, 8), 8, b""),
+        "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c451105531b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429"
+    );
+    assert_eq!(
+        parallel_hash::<136, 64>(&sample_data(3, 8), 8, PARALLEL_DATA),
+        "cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110"
+    );
+    assert_eq!(
+        parallel_hash::<136, 64>(&sample_data(6, 12), 12, PARALLEL_DATA),
+        "69d0fcb764ea055dd09334bc6021cb7e4b61348dff375da262671cdec3effa8d1b4568a6cce16b1cad946ddde27f6ce2b8dee4cd1b24851ebf00eb90d43813e9"
+    );
+}
+
+#[test]
+fn assert_parallel_hash_xof128_nist_samples() {
+    assert_eq!(
+        parallel_hash_xof::<168, 32>(&sample_data(3, 8), 8, b""),
+        "fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3"
+    );
+    assert_eq!(
+        parallel_hash_xof::<168, 32>(&sample_data(3, 8), 8, PARALLEL_DATA),
+        "ea2a793140820f7a128b8eb70a9439f93257c6e6e79b4a540d291d6dae7098d7"
+    );
+    assert_eq!(
+        parallel_hash_xof::<168, 32>(&sample_data(6, 12), 12, PARALLEL_DATA),
+        "0127ad9772ab904691987fcc4a24888f341fa0db2145e872d4efd255376602f0"
+    );
+}
+
+#[test]
+fn assert_parallel_hash_xof256_nist_samples() {
+    assert_eq!(
+        parallel_hash_xof::<136, 64>(&sample_data(3, 8), 8, b""),
+        "c10a052722614684144d28474850b410757e3cba87651ba167a5cbddff7f466675fbf84bcae7378ac444be681d729499afca667fb879348bfdda427863c82f1c"
+    );
+    assert_eq!(
+        parallel_hash_xof::<136, 64>(&sample_data(3, 8), 8, PARALLEL_DATA),
+        "538e105f1a22f44ed2f5cc1674fbd40be803d9c99bf5f8d90a2c8193f3fe6ea768e5c1a20987e2c9c65febed03887a51d35624ed12377594b5585541dc377efc"
+    );
+    assert_eq!(
+        parallel_hash_xof::<136, 64>(&sample_data(6, 12), 12, PARALLEL_DATA),
+        "6b3e790b330c889a204c2fbc728d809f19367328d852f4002dc829f73afd6bcefb7fe5b607b13a801c0be5c1170bdb794e339458fdb0e62a6af3d42558970249"
+    );
+}
+
+#[test]
+fn assert_result_is_independent_of_write_boundaries() {
+    let data = sample_data(6, 12);
+
+    let mut whole = ParallelHash128::<32>::new(12, PARALLEL_DATA);
+    whole.write(&data);
+
+    for chunk_size in [1, 5, 12, 13, 71] {
+        let mut chunked = ParallelHash128::<32>::new(12, PARALLEL_DATA);
+        data.chunks(chunk_size).for_each(|chunk| chunked.write(chunk));
+
+        assert_eq!(HasherContext::finish(&mut chunked), HasherContext::finish(&mut whole.clone()));
+    }
+}
+
+#[test]
+fn assert_block_size_is_bound_to_the_result() {
+    let data = sample_data(6, 12);
+
+    let mut parallel_hash1 = ParallelHash256::<64>::new(12, b"");
+    parallel_hash1.write(&data);
+    let mut parallel_hash2 = ParallelHash256::<64>::new(24, b"");
+    parallel_hash2.write(&data);
+
+    assert_ne!(HasherContext::finish(&mut parallel_hash1), HasherContext::finish(&mut parallel_hash2));
+}
+
+#[test]
+#[should_panic]
+fn assert_zero_block_size_panics() {
+    ParallelHash128::<32>::new(0, b"");
+}
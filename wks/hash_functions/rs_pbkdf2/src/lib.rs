@@ -0,0 +1,152 @@
+//! # PBKDF2 `rs_pbkdf2` - Password-Based Key Derivation Function 2
+//!
+//! PBKDF2 is a key derivation function, specified in PKCS #5 v2.1 (RFC 8018), that turns a password into a key of any
+//! length. It iterates a pseudorandom function many times over the password and a salt, so that each guess of an
+//! attacker costs as much as the iteration count chosen by the defender.
+//!
+//! The PBKDF2 implemented in this crate uses HMAC as its pseudorandom function, and is generic over any hash function
+//! present in the encompassing project, through `rs_hmac`. Derived keys are written into caller provided buffers, so no
+//! allocation is ever needed.
+//!
+//! ## Usage
+//!
+//! The password is given once to `Pbkdf2::new`, which keys the underlying HMAC, after which any number of keys can be
+//! derived out of it with `Pbkdf2::derive`, each with its own salt and iteration count.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use PBKDF2 with SHA-1 in Rust, with the second test case of RFC 6070:
+//!
+//! ```rust
+//! # use rs_pbkdf2::Pbkdf2;
+//! # use rs_sha1::Sha1State;
+//! let mut key = [0u8; 20];
+//! Pbkdf2::<Sha1State, 20>::new(b"password").derive(b"salt", 2, &mut key).unwrap();
+//!
+//! assert_eq!(
+//!     key,
+//!     [
+//!         0xEA, 0x6C, 0x01, 0x4D, 0xC7, 0x2D, 0x6F, 0x8C, 0xCD, 0x1E, 0xD9, 0x2A, 0xCE, 0x1D, 0x41, 0xF0, 0xD8, 0xDE,
+//!         0x89, 0x57
+//!     ]
+//! );
+//! ```
+//!
+//! ## Use Cases
+//!
+//! PBKDF2 is recommended for a variety of tasks, including:
+//!
+//! - Password storage, where only the derived key and the salt are kept.
+//! - Decrypting password protected keys, such as PKCS #5 and PKCS #8 encrypted private keys.
+//! - Deriving encryption keys out of passphrases typed by users.
+//!
+//! Memory-hard functions such as scrypt, which builds upon PBKDF2, are preferred for new password storage designs.
+//!
+
+#![no_std]
+
+use core::fmt::{Display, Formatter};
+use rs_hasher_ctx::ByteArrayWrapper;
+pub use rs_hmac::Zeroize;
+use rs_hmac::{Hmac, Mac};
+use rs_internal_hasher::HashAlgorithm;
+use rs_internal_state::BytesLen;
+
+/// `Pbkdf2Error` lists the reasons for which PBKDF2 refuses to derive a key.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Pbkdf2Error {
+    /// The iteration count is zero, while PKCS #5 requires at least one iteration
+    ZeroRounds,
+    /// The requested output is longer than `Pbkdf2::MAX_OUTPUT_LEN`
+    OutputTooLong,
+}
+
+impl Display for Pbkdf2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Pbkdf2Error::ZeroRounds => f.write_str("PBKDF2 iteration count cannot be zero"),
+            Pbkdf2Error::OutputTooLong => f.write_str("PBKDF2 output too long"),
+        }
+    }
+}
+
+/// `Pbkdf2<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize>` is a generic struct that provides PBKDF2-HMAC in Rust,
+/// over the hash function `H` whose output is `OUTPUT_SIZE` bytes long.
+///
+/// It holds the HMAC context keyed with the password, so that the password is processed only once however many keys
+/// and iterations are derived out of it.
+///
+/// ## Examples
+///
+/// Keys can be derived with any hash function supported by `Hmac`, here with SHA-256 and the first PBKDF2 sample of
+/// RFC 7914:
+///
+///```rust
+/// # use rs_pbkdf2::Pbkdf2;
+/// # use rs_sha256::Sha256State;
+/// let pbkdf2 = Pbkdf2::<Sha256State, 32>::new(b"passwd");
+///
+/// let mut key = [0u8; 64];
+/// pbkdf2.derive(b"salt", 1, &mut key).unwrap();
+/// assert_eq!(key[..8], [0x55, 0xAC, 0x04, 0x6E, 0x56, 0xE3, 0x08, 0x9F]);
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Pbkdf2<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> {
+    password_hmac: Hmac<H, OUTPUT_SIZE>,
+}
+
+impl<H, const OUTPUT_SIZE: usize> Pbkdf2<H, OUTPUT_SIZE>
+where
+    H: BytesLen + Default + HashAlgorithm<Output = ByteArrayWrapper<OUTPUT_SIZE>>,
+    ByteArrayWrapper<OUTPUT_SIZE>: From<H>,
+{
+    /// Longest output PBKDF2 can produce, of `2^32 - 1` hash outputs, as its block counter is 32 bits long
+    pub const MAX_OUTPUT_LEN: u64 = u32::MAX as u64 * OUTPUT_SIZE as u64;
+
+    /// Keys the pseudorandom function with `password`.
+    pub fn new(password: &[u8]) -> Self {
+        Self {
+            password_hmac: Hmac::new(password),
+        }
+    }
+
+    /// Fills the whole of `okm` with the key derived from the password, `salt` and `rounds` iterations.
+    ///
+    /// `okm` may be empty, and cannot be longer than `Pbkdf2::MAX_OUTPUT_LEN`.
+    pub fn derive(&self, salt: &[u8], rounds: u32, okm: &mut [u8]) -> Result<(), Pbkdf2Error> {
+        if rounds == 0 {
+            return Err(Pbkdf2Error::ZeroRounds);
+        }
+        if okm.len() as u64 > Self::MAX_OUTPUT_LEN {
+            return Err(Pbkdf2Error::OutputTooLong);
+        }
+
+        for (i, okm_block) in okm.chunks_mut(OUTPUT_SIZE).enumerate() {
+            let mut hmac = self.password_hmac.clone();
+            hmac.update(salt);
+            hmac.update(&(i as u32 + 1).to_be_bytes());
+
+            let mut u: [u8; OUTPUT_SIZE] = hmac.finalize().into();
+            let mut t = u;
+            for _ in 1..rounds {
+                let mut hmac = self.password_hmac.clone();
+                hmac.update(&u);
+                u = hmac.finalize().into();
+
+                t.iter_mut().zip(u).for_each(|(t, u)| *t ^= u);
+            }
+
+            okm_block.clone_from_slice(&t[..okm_block.len()]);
+            u.zeroize();
+            t.zeroize();
+        }
+
+        Ok(())
+    }
+}
+
+impl<H: Default + HashAlgorithm, const OUTPUT_SIZE: usize> Zeroize for Pbkdf2<H, OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.password_hmac.zeroize();
+    }
+}
@@ -0,0 +1,90 @@
+use rs_pbkdf2::{Pbkdf2, Pbkdf2Error};
+use rs_sha1::Sha1State;
+use rs_sha256::Sha256State;
+use rs_sha512::Sha512State;
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn pbkdf2_sha1(password: &[u8], salt: &[u8], rounds: u32, okm_len: usize) -> String {
+    let mut okm = vec![0u8; okm_len];
+    Pbkdf2::<Sha1State, 20>::new(password).derive(salt, rounds, &mut okm).unwrap();
+
+    bytes_to_hex(&okm)
+}
+
+#[test]
+fn assert_rfc6070_single_round() {
+    assert_eq!(pbkdf2_sha1(b"password", b"salt", 1, 20), "0c60c80f961f0e71f3a9b524af6012062fe037a6");
+}
+
+#[test]
+fn assert_rfc6070_two_rounds() {
+    assert_eq!(pbkdf2_sha1(b"password", b"salt", 2, 20), "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957");
+}
+
+#[test]
+fn assert_rfc6070_4096_rounds() {
+    assert_eq!(pbkdf2_sha1(b"password", b"salt", 4096, 20), "4b007901b765489abead49d926f721d065a429c1");
+}
+
+#[test]
+#[ignore = "runs 16777216 rounds, which takes minutes on debug builds"]
+fn assert_rfc6070_16777216_rounds() {
+    assert_eq!(pbkdf2_sha1(b"password", b"salt", 16777216, 20), "eefe3d61cd4da4e4e9945b3d6ba2158c2634e984");
+}
+
+#[test]
+fn assert_rfc6070_multiple_output_blocks() {
+    assert_eq!(
+        pbkdf2_sha1(b"passwordPASSWORDpassword", b"saltSALTsaltSALTsaltSALTsaltSALTsalt", 4096, 25),
+        "3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038"
+    );
+}
+
+#[test]
+fn assert_rfc6070_embedded_null_bytes() {
+    assert_eq!(pbkdf2_sha1(b"pass\0word", b"sa\0lt", 4096, 16), "56fa6aa75548099dcc37d7f03425e0c3");
+}
+
+#[test]
+fn assert_rfc7914_pbkdf2_sha256_samples() {
+    let mut okm = [0u8; 64];
+
+    Pbkdf2::<Sha256State, 32>::new(b"passwd").derive(b"salt", 1, &mut okm).unwrap();
+    assert_eq!(
+        bytes_to_hex(&okm),
+        "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30b\
+        d509112041d3a19783"
+    );
+
+    Pbkdf2::<Sha256State, 32>::new(b"Password").derive(b"NaCl", 80000, &mut okm).unwrap();
+    assert_eq!(
+        bytes_to_hex(&okm),
+        "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d0\
+        78478f62b397f33c8d"
+    );
+}
+
+#[test]
+fn assert_pbkdf2_sha512_correctness() {
+    let mut okm = [0u8; 64];
+    Pbkdf2::<Sha512State, 64>::new(b"password").derive(b"salt", 1000, &mut okm).unwrap();
+
+    assert_eq!(
+        bytes_to_hex(&okm),
+        "afe6c5530785b6cc6b1c6453384731bd5ee432ee549fd42fb6695779ad8a1c5bf59de69c48f774efc4007d5298f9033c0241d5ab69305e\
+        7b64eceeb8d834cfec"
+    );
+}
+
+#[test]
+fn assert_zero_rounds_and_empty_output() {
+    let pbkdf2 = Pbkdf2::<Sha1State, 20>::new(b"password");
+
+    let mut okm = [0u8; 20];
+    assert_eq!(pbkdf2.derive(b"salt", 0, &mut okm), Err(Pbkdf2Error::ZeroRounds));
+    assert_eq!(pbkdf2.derive(b"salt", 1, &mut []), Ok(()));
+    assert_eq!(Pbkdf2::<Sha1State, 20>::MAX_OUTPUT_LEN, 85899345900);
+}
@@ -0,0 +1,185 @@
+//! # scrypt `rs_scrypt` - Memory-Hard Password-Based Key Derivation Function
+//!
+//! scrypt is a password-based key derivation function, specified in RFC 7914, designed to be costly in memory as well
+//! as in time. It stretches the password with PBKDF2-HMAC-SHA-256, mixes the result through a large table of blocks
+//! computed with the Salsa20/8 core, and compresses it back with PBKDF2, so that dedicated hardware attacking it needs
+//! as much memory per guess as the defender spent.
+//!
+//! Its cost is tuned with three parameters: the CPU/memory cost `N = 2^log_n`, the block size `r` and the
+//! parallelization `p`. It needs `128 * r * (N + p + 2)` bytes of scratch memory, which `Scrypt::memory_len` reports.
+//!
+//! ## Usage
+//!
+//! The scratch memory can either be borrowed from the caller with `Scrypt::derive_with_scratch`, keeping the crate
+//! usable without any allocator, or, with the `alloc` feature enabled by default, be allocated by `Scrypt::derive`,
+//! which first checks the memory cost against a ceiling given by the caller.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use scrypt in Rust, with the second test vector of RFC 7914:
+//!
+//! ```rust
+//! # use rs_scrypt::Scrypt;
+//! let scrypt = Scrypt::new(10, 8, 16).unwrap();
+//!
+//! let mut key = [0u8; 64];
+//! scrypt.derive(b"password", b"NaCl", 16 * 1024 * 1024, &mut key).unwrap();
+//! assert_eq!(key[..8], [0xFD, 0xBA, 0xBE, 0x1C, 0x9D, 0x34, 0x72, 0x00]);
+//! ```
+//!
+//! ## Use Cases
+//!
+//! scrypt is recommended for a variety of tasks, including:
+//!
+//! - Password storage, where brute forcing with GPUs and ASICs must be made expensive.
+//! - Deriving encryption keys out of passphrases, as done by several disk and wallet encryption schemes.
+//! - Proof-of-work schemes relying on memory-hard computations.
+//!
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod ro_mix;
+#[cfg(test)]
+mod unit_tests;
+
+use crate::ro_mix::ro_mix;
+use core::fmt::{Display, Formatter};
+use rs_pbkdf2::{Pbkdf2, Zeroize};
+use rs_sha256::Sha256State;
+
+/// PBKDF2 instance scrypt is built upon
+type Pbkdf2Sha256 = Pbkdf2<Sha256State, 32>;
+
+/// Upper bound, exclusive, of the product of the block size and the parallelization, as set by RFC 7914
+const MAX_BLOCK_SIZE_TIMES_PARALLELIZATION: u64 = 1 << 30;
+
+/// `ScryptError` lists the reasons for which scrypt refuses to derive a key.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ScryptError {
+    /// The cost parameters are out of the bounds set by RFC 7914
+    InvalidParams,
+    /// The memory cost is above the ceiling given by the caller, or cannot be addressed at all
+    MemoryLimitExceeded,
+    /// The scratch buffer is shorter than `Scrypt::memory_len`
+    ScratchTooSmall,
+    /// The requested output is longer than PBKDF2-HMAC-SHA-256 can produce
+    OutputTooLong,
+}
+
+impl Display for ScryptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScryptError::InvalidParams => f.write_str("invalid scrypt parameters"),
+            ScryptError::MemoryLimitExceeded => f.write_str("scrypt memory cost exceeds the limit"),
+            ScryptError::ScratchTooSmall => f.write_str("scrypt scratch buffer too small"),
+            ScryptError::OutputTooLong => f.write_str("scrypt output too long"),
+        }
+    }
+}
+
+/// `Scrypt` is a struct that provides the scrypt key derivation function in Rust, for a set of validated cost
+/// parameters.
+///
+/// ## Examples
+///
+/// Without an allocator, the caller lends the scratch memory, here with the first test vector of RFC 7914:
+///
+///```rust
+/// # use rs_scrypt::Scrypt;
+/// let scrypt = Scrypt::new(4, 1, 1).unwrap();
+/// let mut scratch = [0u8; 128 * (16 + 1 + 2)];
+/// assert_eq!(scrypt.memory_len(), scratch.len());
+///
+/// let mut key = [0u8; 64];
+/// scrypt.derive_with_scratch(b"", b"", &mut scratch, &mut key).unwrap();
+/// assert_eq!(key[..8], [0x77, 0xD6, 0x57, 0x62, 0x38, 0x65, 0x7B, 0x20]);
+///```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Scrypt {
+    log_n: u8,
+    r: u32,
+    p: u32,
+    memory_len: usize,
+}
+
+impl Scrypt {
+    /// Validates the cost parameters of scrypt.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_n` - The base two logarithm of the CPU/memory cost `N`, which must be at least 1 and less than `16 * r`.
+    /// * `r` - The block size, of `128 * r` bytes, which must be at least 1.
+    /// * `p` - The parallelization, which must be at least 1, and such that `r * p < 2^30`.
+    pub fn new(log_n: u8, r: u32, p: u32) -> Result<Self, ScryptError> {
+        if r == 0 || p == 0 || log_n == 0 || log_n as u64 >= 16 * r as u64 {
+            return Err(ScryptError::InvalidParams);
+        }
+        if r as u64 * p as u64 >= MAX_BLOCK_SIZE_TIMES_PARALLELIZATION {
+            return Err(ScryptError::InvalidParams);
+        }
+
+        let memory_len = 1usize
+            .checked_shl(log_n as u32)
+            .and_then(|n| n.checked_add(p as usize + 2))
+            .and_then(|blocks| blocks.checked_mul(128usize.checked_mul(r as usize)?))
+            .ok_or(ScryptError::MemoryLimitExceeded)?;
+
+        Ok(Self {
+            log_n,
+            r,
+            p,
+            memory_len,
+        })
+    }
+
+    /// Returns the byte length of the scratch memory a derivation needs, of `128 * r * (N + p + 2)` bytes.
+    pub fn memory_len(&self) -> usize {
+        self.memory_len
+    }
+
+    /// Fills the whole of `okm` with the key derived from `password` and `salt`, using the start of `scratch` as
+    /// working memory.
+    ///
+    /// `scratch` must be at least `Scrypt::memory_len` bytes long, and its start is zeroized once the key is derived.
+    pub fn derive_with_scratch(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        scratch: &mut [u8],
+        okm: &mut [u8],
+    ) -> Result<(), ScryptError> {
+        if okm.len() as u64 > Pbkdf2Sha256::MAX_OUTPUT_LEN {
+            return Err(ScryptError::OutputTooLong);
+        }
+        let scratch = scratch.get_mut(..self.memory_len).ok_or(ScryptError::ScratchTooSmall)?;
+
+        let block_len = 128 * self.r as usize;
+        let (b, memory) = scratch.split_at_mut(block_len * self.p as usize);
+        let (v, xy) = memory.split_at_mut(block_len << self.log_n);
+
+        let pbkdf2 = Pbkdf2Sha256::new(password);
+        let result = pbkdf2.derive(salt, 1, b).and_then(|_| {
+            b.chunks_exact_mut(block_len).for_each(|block| ro_mix(block, v, xy));
+            pbkdf2.derive(b, 1, okm)
+        });
+        scratch.zeroize();
+
+        result.map_err(|_| ScryptError::OutputTooLong)
+    }
+
+    /// Fills the whole of `okm` with the key derived from `password` and `salt`, allocating the scratch memory.
+    ///
+    /// The derivation is refused before allocating anything if `Scrypt::memory_len` is above `max_memory` bytes.
+    #[cfg(feature = "alloc")]
+    pub fn derive(&self, password: &[u8], salt: &[u8], max_memory: usize, okm: &mut [u8]) -> Result<(), ScryptError> {
+        if self.memory_len > max_memory {
+            return Err(ScryptError::MemoryLimitExceeded);
+        }
+
+        let mut scratch = alloc::vec![0u8; self.memory_len];
+        self.derive_with_scratch(password, salt, &mut scratch, okm)
+    }
+}
@@ -0,0 +1,83 @@
+/// Byte length of the blocks the Salsa20/8 core operates on
+pub(crate) const SALSA_BLOCK_LEN: usize = 64;
+
+/// Sequential memory-hard mixing of `block`, of `128 * r` bytes, using `v` as its table of `N` blocks and `xy` as its
+/// working space of two blocks.
+pub(crate) fn ro_mix(block: &mut [u8], v: &mut [u8], xy: &mut [u8]) {
+    let block_len = block.len();
+    let n = v.len() / block_len;
+    let (x, y) = xy.split_at_mut(block_len);
+
+    x.clone_from_slice(block);
+    for v_i in v.chunks_exact_mut(block_len) {
+        v_i.clone_from_slice(x);
+        block_mix(x, y);
+    }
+
+    for _ in 0..n {
+        let j = integerify(x) as usize & (n - 1);
+        x.iter_mut().zip(&v[j * block_len..(j + 1) * block_len]).for_each(|(x, v)| *x ^= v);
+        block_mix(x, y);
+    }
+
+    block.clone_from_slice(x);
+}
+
+/// Mixes the `2 * r` sub-blocks of `b` through the Salsa20/8 core, interleaving the even and odd outputs, with `y` as
+/// working space.
+fn block_mix(b: &mut [u8], y: &mut [u8]) {
+    let r = b.len() / (2 * SALSA_BLOCK_LEN);
+    let mut x = load(&b[b.len() - SALSA_BLOCK_LEN..]);
+
+    for (i, b_i) in b.chunks_exact(SALSA_BLOCK_LEN).enumerate() {
+        x.iter_mut().zip(load(b_i)).for_each(|(x, b)| *x ^= b);
+        salsa20_8(&mut x);
+
+        let offset = (i / 2 + (i % 2) * r) * SALSA_BLOCK_LEN;
+        store(&x, &mut y[offset..offset + SALSA_BLOCK_LEN]);
+    }
+
+    b.clone_from_slice(y);
+}
+
+/// Reads the first eight bytes of the last sub-block of `b` as a little-endian integer
+fn integerify(b: &[u8]) -> u64 {
+    let last_sub_block = b.len() - SALSA_BLOCK_LEN;
+    u64::from_le_bytes(b[last_sub_block..last_sub_block + 8].try_into().unwrap())
+}
+
+/// The Salsa20 core reduced to 8 rounds, applied in place to sixteen little-endian words
+pub(crate) fn salsa20_8(b: &mut [u32; 16]) {
+    let mut x = *b;
+
+    for _ in 0..4 {
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 5, 9, 13, 1);
+        quarter_round(&mut x, 10, 14, 2, 6);
+        quarter_round(&mut x, 15, 3, 7, 11);
+
+        quarter_round(&mut x, 0, 1, 2, 3);
+        quarter_round(&mut x, 5, 6, 7, 4);
+        quarter_round(&mut x, 10, 11, 8, 9);
+        quarter_round(&mut x, 15, 12, 13, 14);
+    }
+
+    b.iter_mut().zip(x).for_each(|(b, x)| *b = b.wrapping_add(x));
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+    x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+    x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+    x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+}
+
+pub(crate) fn load(bytes: &[u8]) -> [u32; 16] {
+    let mut words = [0u32; 16];
+    words.iter_mut().zip(bytes.chunks_exact(4)).for_each(|(w, b)| *w = u32::from_le_bytes(b.try_into().unwrap()));
+    words
+}
+
+pub(crate) fn store(words: &[u32; 16], bytes: &mut [u8]) {
+    bytes.chunks_exact_mut(4).zip(words).for_each(|(b, w)| b.clone_from_slice(&w.to_le_bytes()));
+}
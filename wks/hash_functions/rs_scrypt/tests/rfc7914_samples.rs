@@ -0,0 +1,90 @@
+use rs_scrypt::{Scrypt, ScryptError};
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn scrypt_hex(password: &[u8], salt: &[u8], log_n: u8, r: u32, p: u32) -> String {
+    let mut okm = [0u8; 64];
+    Scrypt::new(log_n, r, p).unwrap().derive(password, salt, usize::MAX, &mut okm).unwrap();
+
+    bytes_to_hex(&okm)
+}
+
+#[test]
+fn assert_rfc7914_empty_password_and_salt() {
+    assert_eq!(
+        scrypt_hex(b"", b"", 4, 1, 1),
+        "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d36\
+        28cf35e20c38d18906"
+    );
+}
+
+#[test]
+fn assert_rfc7914_parallelization_of_16() {
+    assert_eq!(
+        scrypt_hex(b"password", b"NaCl", 10, 8, 16),
+        "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee\
+        6d8360cbdfa2cc0640"
+    );
+}
+
+#[test]
+fn assert_rfc7914_16384_cost() {
+    assert_eq!(
+        scrypt_hex(b"pleaseletmein", b"SodiumChloride", 14, 8, 1),
+        "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2d5432955613f0fcf62d49705242a9af9e61e85dc0d651e\
+        40dfcf017b45575887"
+    );
+}
+
+#[test]
+#[ignore = "needs a gigabyte of memory and takes minutes on debug builds"]
+fn assert_rfc7914_1048576_cost() {
+    assert_eq!(
+        scrypt_hex(b"pleaseletmein", b"SodiumChloride", 20, 8, 1),
+        "2101cb9b6a511aaeaddbbe09cf70f881ec568d574a2ffd4dabe5ee9820adaa478e56fd8f4ba5d09ffa1c6d927c40f4c337304049e8a952\
+        fbcbf45c6fa77a41a4"
+    );
+}
+
+#[test]
+fn assert_borrowed_scratch_matches_allocated_scratch() {
+    let scrypt = Scrypt::new(10, 8, 16).unwrap();
+    assert_eq!(scrypt.memory_len(), 128 * 8 * (1024 + 16 + 2));
+
+    let mut scratch = vec![0xA5u8; scrypt.memory_len() + 7];
+    let mut okm = [0u8; 64];
+    scrypt.derive_with_scratch(b"password", b"NaCl", &mut scratch, &mut okm).unwrap();
+    assert_eq!(bytes_to_hex(&okm), scrypt_hex(b"password", b"NaCl", 10, 8, 16));
+
+    let mut short_scratch = vec![0u8; scrypt.memory_len() - 1];
+    assert_eq!(
+        scrypt.derive_with_scratch(b"password", b"NaCl", &mut short_scratch, &mut okm),
+        Err(ScryptError::ScratchTooSmall)
+    );
+}
+
+#[test]
+fn assert_memory_ceiling_is_enforced() {
+    let scrypt = Scrypt::new(14, 8, 1).unwrap();
+    let mut okm = [0u8; 64];
+
+    assert_eq!(
+        scrypt.derive(b"pleaseletmein", b"SodiumChloride", scrypt.memory_len() - 1, &mut okm),
+        Err(ScryptError::MemoryLimitExceeded)
+    );
+    assert_eq!(okm, [0u8; 64]);
+    assert_eq!(scrypt.derive(b"pleaseletmein", b"SodiumChloride", scrypt.memory_len(), &mut okm), Ok(()));
+}
+
+#[test]
+fn assert_invalid_params_are_rejected() {
+    assert_eq!(Scrypt::new(0, 8, 1), Err(ScryptError::InvalidParams));
+    assert_eq!(Scrypt::new(14, 0, 1), Err(ScryptError::InvalidParams));
+    assert_eq!(Scrypt::new(14, 8, 0), Err(ScryptError::InvalidParams));
+    assert_eq!(Scrypt::new(16, 1, 1), Err(ScryptError::InvalidParams));
+    assert_eq!(Scrypt::new(14, 1 << 15, 1 << 15), Err(ScryptError::InvalidParams));
+    assert_eq!(Scrypt::new(63, 8, 1), Err(ScryptError::MemoryLimitExceeded));
+    assert!(Scrypt::new(15, 1, 1).is_ok());
+}
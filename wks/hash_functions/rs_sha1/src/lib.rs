@@ -65,7 +65,7 @@
 
 #![no_std]
 
-pub use rs_hasher_ctx::HasherContext;
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
 pub use sha1hasher::Sha1Hasher;
 pub use sha1state::Sha1State;
 
@@ -1,6 +1,6 @@
 use crate::{Sha1State, BYTES_LEN};
 use core::hash::Hasher;
-use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext};
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Midstate, MidstateError};
 use rs_internal_hasher::HashAlgorithm;
 
 /// `Sha1Hasher` is a type that provides the SHA-1 hashing algorithm in RustyShield.
@@ -79,3 +79,17 @@ impl HasherContext<BYTES_LEN> for Sha1Hasher {
         ByteArrayWrapper::from(HasherContext::finish(&mut self.0))
     }
 }
+
+impl Midstate for Sha1Hasher {
+    fn midstate_len(&self) -> usize {
+        self.0.midstate_len()
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.0.export_midstate(bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        GenericHasher::import_midstate(bytes).map(Self)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{Sha1Hasher, BYTES_LEN};
 use core::{hash::BuildHasher, ops::AddAssign};
 use rs_hasher_ctx::ByteArrayWrapper;
-use rs_internal_hasher::{GenericPad, HashAlgorithm, U64Size};
-use rs_internal_state::{BytesLen, DWords, GenericStateHasher, Sha160BitsState};
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, U64Size};
+use rs_internal_state::{BytesLen, DWords, GenericStateHasher, Sha160BitsState, ShaExtensions, Zeroize};
 use rs_n_bit_words::NBitWord;
 
 pub(crate) const H0: u32 = 0x67452301;
@@ -130,14 +130,15 @@ impl HashAlgorithm for Sha1State {
     type Output = ByteArrayWrapper<BYTES_LEN>;
 
     fn hash_block(&mut self, bytes: &[u8]) {
-        let mut state = Sha160BitsState(
-            self.0,
-            self.1,
-            self.2,
-            self.3,
-            self.4,
-            DWords::<u32>::from(<&[u8; 64]>::try_from(bytes).unwrap()),
-        );
+        let block = <&[u8; 64]>::try_from(bytes).unwrap();
+        if let Some(sha_extensions) = ShaExtensions::detect() {
+            let mut words = [self.0, self.1, self.2, self.3, self.4].map(u32::from);
+            sha_extensions.sha1_compress(&mut words, block);
+            *self = Self::from(words);
+            return;
+        }
+
+        let mut state = Sha160BitsState(self.0, self.1, self.2, self.3, self.4, DWords::<u32>::from(block));
 
         state.block_00_15();
         state.block_16_31();
@@ -152,3 +153,33 @@ impl HashAlgorithm for Sha1State {
         Into::<u64>::into(self.0) << 32 | Into::<u64>::into(self.1)
     }
 }
+
+impl HashMidstate for Sha1State {
+    const ALGORITHM: &'static str = "SHA-1";
+    const MIDSTATE_LEN: usize = 20;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip([self.0, self.1, self.2, self.3, self.4]) {
+            chunk.clone_from_slice(&u32::from(word).to_be_bytes());
+        }
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        let mut words = [0u32; 5];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+
+        Self::from(words)
+    }
+}
+
+impl Zeroize for Sha1State {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+        self.2.zeroize();
+        self.3.zeroize();
+        self.4.zeroize();
+    }
+}
@@ -186,14 +186,7 @@ fn assert_hash_values_integrity_for_each_step_16_to_19() {
 
     let mut state = hasher.state.clone();
 
-    let mut sha160bits_state = Sha160BitsState {
-        0: state.0,
-        1: state.1,
-        2: state.2,
-        3: state.3,
-        4: state.4,
-        5: words,
-    };
+    let mut sha160bits_state = Sha160BitsState(state.0, state.1, state.2, state.3, state.4, words);
     sha160bits_state.block_00_15();
     sha160bits_state.next_words();
 
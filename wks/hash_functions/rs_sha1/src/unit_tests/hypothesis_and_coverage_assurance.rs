@@ -179,6 +179,7 @@ fn convert_big_endian_bytes_to_u32() {
 }
 
 #[test]
+#[allow(clippy::identity_op, clippy::unnecessary_cast)]
 fn bit_shift_method_vs_explicit_operation() {
     let unsigned_integer: u32 = 1684234849;
 
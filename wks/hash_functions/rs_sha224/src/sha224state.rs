@@ -1,8 +1,8 @@
 use crate::{Sha224Hasher, BYTES_LEN};
 use core::{hash::BuildHasher, ops::AddAssign};
 use rs_hasher_ctx::ByteArrayWrapper;
-use rs_internal_hasher::{GenericPad, HashAlgorithm, U64Size};
-use rs_internal_state::{BytesLen, DWords, GenericStateHasher, Sha256BitsState};
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, U64Size};
+use rs_internal_state::{BytesLen, DWords, GenericStateHasher, Sha256BitsState, ShaExtensions, Zeroize};
 use rs_n_bit_words::NBitWord;
 
 const H0: u32 = 0xC1059ED8;
@@ -153,17 +153,16 @@ impl HashAlgorithm for Sha224State {
     type Output = ByteArrayWrapper<BYTES_LEN>;
 
     fn hash_block(&mut self, bytes: &[u8]) {
-        let mut state = Sha256BitsState(
-            self.0,
-            self.1,
-            self.2,
-            self.3,
-            self.4,
-            self.5,
-            self.6,
-            self.7,
-            DWords::<u32>::from(<&[u8; 64]>::try_from(bytes).unwrap()),
-        );
+        let block = <&[u8; 64]>::try_from(bytes).unwrap();
+        if let Some(sha_extensions) = ShaExtensions::detect() {
+            let mut words = [self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7].map(u32::from);
+            sha_extensions.sha256_compress(&mut words, block);
+            *self = Self::from(words);
+            return;
+        }
+
+        let mut state =
+            Sha256BitsState(self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7, DWords::<u32>::from(block));
 
         state.block_00_15();
         state.block_16_31();
@@ -177,3 +176,38 @@ impl HashAlgorithm for Sha224State {
         Into::<u64>::into(self.0) << 32 | Into::<u64>::into(self.1)
     }
 }
+
+impl HashMidstate for Sha224State {
+    const ALGORITHM: &'static str = "SHA-224";
+    const MIDSTATE_LEN: usize = 32;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        for (chunk, word) in
+            bytes.chunks_exact_mut(4).zip([self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7])
+        {
+            chunk.clone_from_slice(&u32::from(word).to_be_bytes());
+        }
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        let mut words = [0u32; 8];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+
+        Self::from(words)
+    }
+}
+
+impl Zeroize for Sha224State {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+        self.2.zeroize();
+        self.3.zeroize();
+        self.4.zeroize();
+        self.5.zeroize();
+        self.6.zeroize();
+        self.7.zeroize();
+    }
+}
@@ -49,6 +49,10 @@
 //! assert_ne!(u64result1, u64result2);
 //! ```
 //!
+//! Many independent messages, such as the nodes of a Merkle tree, are hashed faster at once with `Sha256Batch`, which
+//! interleaves their rounds across SIMD lanes. Its SSE4.1 and AVX2 backends are detected at runtime with the `std`
+//! feature.
+//!
 //! ## Use Cases
 //!
 //! SHA-256 is broadly used in various security-critical tasks, including:
@@ -63,10 +67,13 @@
 
 #![no_std]
 
-pub use rs_hasher_ctx::HasherContext;
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
+pub use rs_internal_state::BatchBackend;
+pub use sha256batch::Sha256Batch;
 pub use sha256hasher::Sha256Hasher;
 pub use sha256state::Sha256State;
 
+mod sha256batch;
 mod sha256hasher;
 mod sha256state;
 
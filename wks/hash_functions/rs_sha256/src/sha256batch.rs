@@ -0,0 +1,70 @@
+use crate::{sha256state::HX, Sha256State, BYTES_LEN};
+use rs_hasher_ctx::ByteArrayWrapper;
+use rs_internal_state::{sha256_batch, BatchBackend};
+
+/// `Sha256Batch` hashes many independent messages with SHA-256 at once, such as the nodes of a Merkle tree.
+///
+/// Instead of going through the rounds of one message after the other, it interleaves the rounds of several messages,
+/// one per SIMD lane. Messages may have different lengths, though the batch is fastest when they span the same count of
+/// blocks. The backend is picked by `Sha256Batch::default`, as the widest one the running CPU supports, and every
+/// backend yields the same digests as `Sha256Hasher`.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_sha256::{HasherContext, Sha256Batch, Sha256Hasher};
+/// # use std::hash::Hasher;
+/// let nodes = [[0x01u8; 64], [0x02u8; 64], [0x03u8; 64]];
+/// let messages: Vec<&[u8]> = nodes.iter().map(|node| &node[..]).collect();
+/// let mut digests = vec![Default::default(); 3];
+///
+/// Sha256Batch::default().digest(&messages, &mut digests);
+///
+/// let mut sha256hasher = Sha256Hasher::default();
+/// sha256hasher.write(&nodes[2]);
+/// assert_eq!(digests[2], HasherContext::finish(&mut sha256hasher));
+///```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Sha256Batch {
+    backend: BatchBackend,
+}
+
+impl Sha256Batch {
+    /// Returns a batch hashing with `backend`, or `None` if the running CPU does not support it.
+    pub fn with_backend(backend: BatchBackend) -> Option<Self> {
+        backend.is_supported().then_some(Self {
+            backend,
+        })
+    }
+
+    /// Returns the backend the batch hashes with.
+    pub fn backend(&self) -> BatchBackend {
+        self.backend
+    }
+
+    /// Hashes each of `messages`, and writes its digest to the element of `digests` at the same index.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `messages` and `digests` have different lengths.
+    pub fn digest(&self, messages: &[&[u8]], digests: &mut [ByteArrayWrapper<BYTES_LEN>]) {
+        assert_eq!(messages.len(), digests.len(), "every message needs a digest to be written to");
+
+        let mut states = [[0u32; 8]; 64];
+        for (messages, digests) in messages.chunks(states.len()).zip(digests.chunks_mut(states.len())) {
+            let states = &mut states[..messages.len()];
+            sha256_batch(self.backend, &HX, messages, states);
+            for (digest, &state) in digests.iter_mut().zip(states.iter()) {
+                *digest = Sha256State::from(state).into();
+            }
+        }
+    }
+}
+
+impl Default for Sha256Batch {
+    fn default() -> Self {
+        Self {
+            backend: BatchBackend::detect(),
+        }
+    }
+}
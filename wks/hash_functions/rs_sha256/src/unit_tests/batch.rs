@@ -0,0 +1,49 @@
+use crate::{BatchBackend, HasherContext, Sha256Batch, Sha256Hasher};
+use core::hash::Hasher;
+use rs_hasher_ctx::ByteArrayWrapper;
+
+const BACKENDS: [BatchBackend; 3] = [BatchBackend::Portable, BatchBackend::Sse41, BatchBackend::Avx2];
+
+fn sha256_digest(message: &[u8]) -> ByteArrayWrapper<32> {
+    let mut sha256hasher = Sha256Hasher::default();
+    sha256hasher.write(message);
+    HasherContext::finish(&mut sha256hasher)
+}
+
+#[test]
+fn sha256_batch_matches_hasher_on_every_padding_boundary() {
+    let data: [u8; 200] = core::array::from_fn(|i| (i * 7) as u8);
+    let messages: [&[u8]; 200] = core::array::from_fn(|len| &data[..len]);
+
+    for batch in BACKENDS.into_iter().filter_map(Sha256Batch::with_backend) {
+        let mut digests: [ByteArrayWrapper<32>; 200] = core::array::from_fn(|_| Default::default());
+        batch.digest(&messages, &mut digests);
+
+        for (message, digest) in messages.iter().zip(&digests) {
+            assert_eq!(*digest, sha256_digest(message), "{:?} on {} bytes", batch.backend(), message.len());
+        }
+    }
+}
+
+#[test]
+fn sha256_batch_handles_partial_groups_of_lanes() {
+    let nodes: [[u8; 64]; 11] = core::array::from_fn(|i| [i as u8; 64]);
+    let messages: [&[u8]; 11] = core::array::from_fn(|i| &nodes[i][..]);
+
+    for batch in BACKENDS.into_iter().filter_map(Sha256Batch::with_backend) {
+        for count in 0..=messages.len() {
+            let mut digests: [ByteArrayWrapper<32>; 11] = core::array::from_fn(|_| Default::default());
+            batch.digest(&messages[..count], &mut digests[..count]);
+
+            for (node, digest) in nodes.iter().zip(&digests[..count]) {
+                assert_eq!(*digest, sha256_digest(node));
+            }
+        }
+    }
+}
+
+#[test]
+fn sha256_batch_default_picks_a_supported_backend() {
+    assert!(Sha256Batch::default().backend().is_supported());
+    assert_eq!(Sha256Batch::with_backend(BatchBackend::Portable).unwrap().backend(), BatchBackend::Portable);
+}
@@ -0,0 +1,89 @@
+use crate::{Midstate, MidstateError, Sha256Hasher, MIDSTATE_VERSION};
+use core::hash::Hasher;
+use rs_hasher_ctx::HasherContext;
+
+const MIDSTATE_CAPACITY: usize = 160;
+
+fn exported_midstate(written: &[u8]) -> ([u8; MIDSTATE_CAPACITY], usize) {
+    let mut sha256hasher = Sha256Hasher::default();
+    sha256hasher.write(written);
+
+    let mut midstate = [0u8; MIDSTATE_CAPACITY];
+    let len = sha256hasher.export_midstate(&mut midstate).unwrap();
+    assert_eq!(len, sha256hasher.midstate_len());
+
+    (midstate, len)
+}
+
+#[test]
+fn sha256_midstate_resumes_hashing_where_it_left_off() {
+    let data: [u8; 300] = core::array::from_fn(|i| i as u8);
+
+    for split in [0, 1, 63, 64, 65, 150, 300] {
+        let (midstate, len) = exported_midstate(&data[..split]);
+
+        let mut resumed = Sha256Hasher::import_midstate(&midstate[..len]).unwrap();
+        resumed.write(&data[split..]);
+
+        let mut uninterrupted = Sha256Hasher::default();
+        uninterrupted.write(&data);
+
+        assert_eq!(HasherContext::finish(&mut resumed), HasherContext::finish(&mut uninterrupted));
+    }
+}
+
+#[test]
+fn sha256_midstate_only_buffers_the_unhashed_tail() {
+    let (_, aligned_len) = exported_midstate(&[0xAB; 128]);
+    let (_, unaligned_len) = exported_midstate(&[0xAB; 130]);
+
+    assert_eq!(unaligned_len, aligned_len + 2);
+}
+
+#[test]
+fn sha256_midstate_header_is_tagged() {
+    let (midstate, _) = exported_midstate(b"hello");
+
+    assert_eq!(midstate[..4], *b"RSMS");
+    assert_eq!(midstate[4], MIDSTATE_VERSION);
+    assert_eq!(midstate[5], 7);
+    assert_eq!(midstate[6..13], *b"SHA-256");
+}
+
+#[test]
+fn sha256_midstate_export_rejects_short_buffers() {
+    let mut sha256hasher = Sha256Hasher::default();
+    sha256hasher.write(b"hello");
+
+    let mut midstate = [0u8; 32];
+    assert_eq!(sha256hasher.export_midstate(&mut midstate), Err(MidstateError::BufferTooSmall));
+}
+
+#[test]
+fn sha256_midstate_import_rejects_corrupted_blobs() {
+    let (midstate, len) = exported_midstate(b"hello world");
+
+    for i in 5..len {
+        let mut corrupted = midstate;
+        corrupted[i] ^= 0x10;
+        assert_eq!(Sha256Hasher::import_midstate(&corrupted[..len]), Err(MidstateError::Corrupted));
+    }
+    assert_eq!(Sha256Hasher::import_midstate(&midstate[..len - 1]), Err(MidstateError::Corrupted));
+}
+
+#[test]
+fn sha256_midstate_import_rejects_unknown_layouts() {
+    let (midstate, len) = exported_midstate(b"hello world");
+
+    let mut bad_magic = midstate;
+    bad_magic[0] = b'X';
+    assert_eq!(Sha256Hasher::import_midstate(&bad_magic[..len]), Err(MidstateError::Malformed));
+    assert_eq!(Sha256Hasher::import_midstate(&midstate[..6]), Err(MidstateError::Malformed));
+
+    let mut future_version = midstate;
+    future_version[4] = MIDSTATE_VERSION + 1;
+    assert_eq!(
+        Sha256Hasher::import_midstate(&future_version[..len]),
+        Err(MidstateError::UnsupportedVersion(MIDSTATE_VERSION + 1))
+    );
+}
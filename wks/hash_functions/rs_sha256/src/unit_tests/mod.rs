@@ -1 +1,3 @@
+mod batch;
+mod midstate;
 mod use_cases;
@@ -80,7 +80,7 @@
 
 #![no_std]
 
-pub use rs_hasher_ctx::HasherContext;
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
 pub use sha384hasher::Sha384Hasher;
 pub use sha384state::Sha384State;
 
@@ -6,6 +6,7 @@ use core::hash::{BuildHasher, Hash, Hasher};
 use rs_hasher_ctx::HasherContext;
 
 #[test]
+#[allow(clippy::manual_hash_one)]
 fn sha384_empty_string_prefix_collision_resiliency() {
     let empty_str = "";
     let default_sha256state = Sha384State::default();
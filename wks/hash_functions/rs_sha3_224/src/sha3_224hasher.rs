@@ -1,6 +1,6 @@
 use crate::{Sha3_224State, OUTPUT_SIZE};
 use core::hash::{Hash, Hasher};
-use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext};
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Midstate, MidstateError};
 use rs_internal_hasher::HashAlgorithm;
 use rs_internal_state::ExtendedOutputFunction;
 
@@ -77,3 +77,17 @@ impl HasherContext<OUTPUT_SIZE> for Sha3_224Hasher {
         HasherContext::finish(&mut self.0).squeeze().into()
     }
 }
+
+impl Midstate for Sha3_224Hasher {
+    fn midstate_len(&self) -> usize {
+        self.0.midstate_len()
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.0.export_midstate(bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        GenericHasher::import_midstate(bytes).map(Self)
+    }
+}
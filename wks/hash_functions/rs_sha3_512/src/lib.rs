@@ -65,7 +65,7 @@
 
 #![no_std]
 
-pub use rs_hasher_ctx::HasherContext;
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
 pub use sha3_512hasher::Sha3_512Hasher;
 pub use sha3_512state::Sha3_512State;
 
@@ -1,8 +1,8 @@
 use crate::{Sha3_512Hasher, OUTPUT_SIZE};
 use core::hash::BuildHasher;
 use rs_hasher_ctx::ByteArrayWrapper;
-use rs_internal_hasher::{GenericPad, HashAlgorithm, KeccakU128Size};
-use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge};
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, KeccakU128Size};
+use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge, Zeroize};
 
 const RATE: usize = 72;
 
@@ -93,3 +93,24 @@ impl HashAlgorithm for Sha3_512State {
         self.squeeze_u64()
     }
 }
+
+impl HashMidstate for Sha3_512State {
+    const ALGORITHM: &'static str = "SHA3-512";
+    const MIDSTATE_LEN: usize = KeccakSponge::<u64, RATE, OUTPUT_SIZE>::LANES_BYTES_LEN;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        self.sponge.export_lanes(bytes)
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        Self {
+            sponge: KeccakSponge::import_lanes(bytes),
+        }
+    }
+}
+
+impl Zeroize for Sha3_512State {
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+    }
+}
@@ -68,6 +68,10 @@
 //! assert_ne!(u64result1, u64result2)
 //! ```
 //!
+//! Many independent messages, such as the nodes of a Merkle tree, are hashed faster at once with `Sha512Batch`, which
+//! interleaves their rounds across SIMD lanes. Its SSE4.1 and AVX2 backends are detected at runtime with the `std`
+//! feature.
+//!
 //! ## Use Cases
 //!
 //! SHA-512 is suitable for a wide range of applications, including but not limited to:
@@ -82,10 +86,13 @@
 
 #![no_std]
 
+pub use crate::sha512batch::Sha512Batch;
 pub use crate::sha512hasher::Sha512Hasher;
 pub use crate::sha512state::Sha512State;
-pub use rs_hasher_ctx::HasherContext;
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
+pub use rs_internal_state::BatchBackend;
 
+mod sha512batch;
 mod sha512hasher;
 mod sha512state;
 
@@ -0,0 +1,49 @@
+use crate::{BatchBackend, HasherContext, Sha512Batch, Sha512Hasher};
+use core::hash::Hasher;
+use rs_hasher_ctx::ByteArrayWrapper;
+
+const BACKENDS: [BatchBackend; 3] = [BatchBackend::Portable, BatchBackend::Sse41, BatchBackend::Avx2];
+
+fn sha512_digest(message: &[u8]) -> ByteArrayWrapper<64> {
+    let mut sha512hasher = Sha512Hasher::default();
+    sha512hasher.write(message);
+    HasherContext::finish(&mut sha512hasher)
+}
+
+#[test]
+fn sha512_batch_matches_hasher_on_every_padding_boundary() {
+    let data: [u8; 300] = core::array::from_fn(|i| (i * 7) as u8);
+    let messages: [&[u8]; 300] = core::array::from_fn(|len| &data[..len]);
+
+    for batch in BACKENDS.into_iter().filter_map(Sha512Batch::with_backend) {
+        let mut digests: [ByteArrayWrapper<64>; 300] = core::array::from_fn(|_| Default::default());
+        batch.digest(&messages, &mut digests);
+
+        for (message, digest) in messages.iter().zip(&digests) {
+            assert_eq!(*digest, sha512_digest(message), "{:?} on {} bytes", batch.backend(), message.len());
+        }
+    }
+}
+
+#[test]
+fn sha512_batch_handles_partial_groups_of_lanes() {
+    let nodes: [[u8; 128]; 11] = core::array::from_fn(|i| [i as u8; 128]);
+    let messages: [&[u8]; 11] = core::array::from_fn(|i| &nodes[i][..]);
+
+    for batch in BACKENDS.into_iter().filter_map(Sha512Batch::with_backend) {
+        for count in 0..=messages.len() {
+            let mut digests: [ByteArrayWrapper<64>; 11] = core::array::from_fn(|_| Default::default());
+            batch.digest(&messages[..count], &mut digests[..count]);
+
+            for (node, digest) in nodes.iter().zip(&digests[..count]) {
+                assert_eq!(*digest, sha512_digest(node));
+            }
+        }
+    }
+}
+
+#[test]
+fn sha512_batch_default_picks_a_supported_backend() {
+    assert!(Sha512Batch::default().backend().is_supported());
+    assert_eq!(Sha512Batch::with_backend(BatchBackend::Portable).unwrap().backend(), BatchBackend::Portable);
+}
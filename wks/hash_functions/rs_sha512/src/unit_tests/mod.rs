@@ -1 +1,2 @@
+mod batch;
 mod use_cases;
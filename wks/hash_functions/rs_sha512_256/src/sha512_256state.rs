@@ -1,8 +1,8 @@
 use crate::{Sha512_256Hasher, BYTES_LEN};
 use core::{hash::BuildHasher, ops::AddAssign};
 use rs_hasher_ctx::ByteArrayWrapper;
-use rs_internal_hasher::{GenericPad, HashAlgorithm, U128Size};
-use rs_internal_state::{BytesLen, DWords, GenericStateHasher, Sha512BitsState};
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, U128Size};
+use rs_internal_state::{BytesLen, DWords, GenericStateHasher, Sha512BitsState, Zeroize};
 use rs_n_bit_words::NBitWord;
 
 const H0: u64 = 0x22312194FC2BF72C;
@@ -176,3 +176,38 @@ impl HashAlgorithm for Sha512_256State {
         self.0.into()
     }
 }
+
+impl HashMidstate for Sha512_256State {
+    const ALGORITHM: &'static str = "SHA-512/256";
+    const MIDSTATE_LEN: usize = 64;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        for (chunk, word) in
+            bytes.chunks_exact_mut(8).zip([self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7])
+        {
+            chunk.clone_from_slice(&u64::from(word).to_be_bytes());
+        }
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        let mut words = [0u64; 8];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+
+        Self::from(words)
+    }
+}
+
+impl Zeroize for Sha512_256State {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+        self.2.zeroize();
+        self.3.zeroize();
+        self.4.zeroize();
+        self.5.zeroize();
+        self.6.zeroize();
+        self.7.zeroize();
+    }
+}
@@ -68,9 +68,10 @@
 
 #![no_std]
 
-pub use rs_hasher_ctx::HasherContext;
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
+pub use rs_internal_state::XofReader;
 pub use shake128hasher::Shake128Hasher;
-pub use shake128state::Shake128State;
+pub use shake128state::{Shake128Reader, Shake128State};
 
 mod shake128hasher;
 mod shake128state;
@@ -1,6 +1,6 @@
-use crate::Shake128State;
+use crate::{Shake128Reader, Shake128State};
 use core::hash::Hasher;
-use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext};
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Midstate, MidstateError};
 use rs_internal_hasher::HashAlgorithm;
 use rs_internal_state::ExtendedOutputFunction;
 
@@ -60,6 +60,34 @@ impl<const OUTPUT_SIZE: usize> From<Shake128State<OUTPUT_SIZE>> for Shake128Hash
     }
 }
 
+impl<const OUTPUT_SIZE: usize> Shake128Hasher<OUTPUT_SIZE> {
+    /// Finishes the absorbing phase and returns a reader over the unbounded SHAKE128 output stream.
+    ///
+    /// Contrary to `HasherContext::finish`, the amount of output is not bound to `OUTPUT_SIZE`, so the reader can be
+    /// squeezed as many times as needed, for instance as a mask generation or key stretching function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::hash::Hasher;
+    /// # use rs_shake128::{Shake128Hasher, XofReader};
+    /// let mut shake128hasher = Shake128Hasher::<0>::default();
+    /// shake128hasher.write(b"abc");
+    ///
+    /// let mut reader = shake128hasher.finish_xof();
+    /// let mut first_half = [0u8; 5];
+    /// let mut second_half = [0u8; 5];
+    /// reader.read(&mut first_half);
+    /// reader.read(&mut second_half);
+    ///
+    /// assert_eq!(first_half, [0x58, 0x81, 0x09, 0x2D, 0xD8]);
+    /// assert_eq!(second_half, [0x18, 0xBF, 0x5C, 0xF8, 0xA3]);
+    /// ```
+    pub fn finish_xof(&mut self) -> Shake128Reader {
+        HasherContext::finish(&mut self.0).into()
+    }
+}
+
 impl<const OUTPUT_SIZE: usize> Hasher for Shake128Hasher<OUTPUT_SIZE> {
     fn finish(&self) -> u64 {
         Hasher::finish(&self.0)
@@ -77,3 +105,17 @@ impl<const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for Shake128Hasher<OUT
         ByteArrayWrapper::from(HasherContext::finish(&mut self.0).squeeze())
     }
 }
+
+impl<const OUTPUT_SIZE: usize> Midstate for Shake128Hasher<OUTPUT_SIZE> {
+    fn midstate_len(&self) -> usize {
+        self.0.midstate_len()
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.0.export_midstate(bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        GenericHasher::import_midstate(bytes).map(Self)
+    }
+}
@@ -1,11 +1,17 @@
 use crate::Shake128Hasher;
 use core::hash::BuildHasher;
 use rs_hasher_ctx::ByteArrayWrapper;
-use rs_internal_hasher::{GenericPad, HashAlgorithm, KeccakU128Size};
-use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge};
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, KeccakU128Size};
+use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge, KeccakXofReader, Zeroize};
 
 const RATE: usize = 168;
 
+/// `Shake128Reader` streams an arbitrary amount of SHAKE128 output into caller provided buffers.
+///
+/// It is obtained through `Shake128Hasher::finish_xof`, and every call to `XofReader::read` continues the output stream
+/// from where the previous one stopped.
+pub type Shake128Reader = KeccakXofReader<u64, RATE>;
+
 /// `Shake128State` represents the state of a SHAKE128 hashing process.
 ///
 /// It holds intermediate hash calculations. However, it's important to note that starting a hashing process from an
@@ -81,6 +87,12 @@ impl<const OUTPUT_SIZE: usize> From<Shake128State<OUTPUT_SIZE>> for ByteArrayWra
     }
 }
 
+impl<const OUTPUT_SIZE: usize> From<Shake128State<OUTPUT_SIZE>> for Shake128Reader {
+    fn from(value: Shake128State<OUTPUT_SIZE>) -> Self {
+        value.sponge.into()
+    }
+}
+
 impl<const OUTPUT_SIZE: usize> HashAlgorithm for Shake128State<OUTPUT_SIZE> {
     type Padding = GenericPad<KeccakU128Size, RATE, 0x1F>;
     type Output = ByteArrayWrapper<OUTPUT_SIZE>;
@@ -93,3 +105,24 @@ impl<const OUTPUT_SIZE: usize> HashAlgorithm for Shake128State<OUTPUT_SIZE> {
         self.squeeze_u64()
     }
 }
+
+impl<const OUTPUT_SIZE: usize> HashMidstate for Shake128State<OUTPUT_SIZE> {
+    const ALGORITHM: &'static str = "SHAKE128";
+    const MIDSTATE_LEN: usize = KeccakSponge::<u64, RATE, OUTPUT_SIZE>::LANES_BYTES_LEN;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        self.sponge.export_lanes(bytes)
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        Self {
+            sponge: KeccakSponge::import_lanes(bytes),
+        }
+    }
+}
+
+impl<const OUTPUT_SIZE: usize> Zeroize for Shake128State<OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+    }
+}
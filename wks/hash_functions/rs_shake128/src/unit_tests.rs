@@ -1,5 +1,5 @@
 extern crate alloc;
-use crate::Shake128Hasher;
+use crate::{Midstate, MidstateError, Shake128Hasher, XofReader};
 use alloc::format;
 use core::hash::Hasher;
 use rs_hasher_ctx::HasherContext;
@@ -46,3 +46,64 @@ fn assert_abc_output() {
 
     assert_eq!(format!("{output:02x}"), "5881092dd818bf5cf8a3");
 }
+
+#[test]
+fn assert_xof_reader_matches_fixed_size_output_across_rate_boundaries() {
+    const OUTPUT_LEN: usize = 500;
+    let mut shake128hasher = Shake128Hasher::<OUTPUT_LEN>::default();
+    shake128hasher.write(MSG);
+
+    let expected_output = HasherContext::finish(&mut shake128hasher.clone());
+    let mut reader = shake128hasher.finish_xof();
+    let mut output = [0u8; OUTPUT_LEN];
+
+    for chunk in output.chunks_mut(7) {
+        reader.read(chunk);
+    }
+
+    assert_eq!(expected_output, output);
+}
+
+#[test]
+fn assert_xof_reader_resumes_where_previous_read_stopped() {
+    let mut shake128hasher = Shake128Hasher::<0>::default();
+    shake128hasher.write(MSG);
+
+    let mut single_read_reader = shake128hasher.clone().finish_xof();
+    let mut multiple_reads_reader = shake128hasher.finish_xof();
+    let mut single_read = [0u8; 1024];
+    let mut multiple_reads = [0u8; 1024];
+
+    single_read_reader.read(&mut single_read);
+    multiple_reads_reader.read(&mut multiple_reads[..1]);
+    multiple_reads_reader.read(&mut multiple_reads[1..300]);
+    multiple_reads_reader.read(&mut []);
+    multiple_reads_reader.read(&mut multiple_reads[300..]);
+
+    assert_eq!(single_read, multiple_reads);
+}
+
+#[test]
+fn shake128_midstate_resumes_hashing_where_it_left_off() {
+    let mut shake128hasher = Shake128Hasher::<32>::default();
+    shake128hasher.write(MSG);
+    shake128hasher.write(MSG);
+
+    let mut midstate = [0u8; 512];
+    let len = shake128hasher.export_midstate(&mut midstate).unwrap();
+    let mut resumed = Shake128Hasher::<32>::import_midstate(&midstate[..len]).unwrap();
+
+    shake128hasher.write(MSG);
+    resumed.write(MSG);
+    assert_eq!(HasherContext::finish(&mut resumed), HasherContext::finish(&mut shake128hasher));
+}
+
+#[test]
+fn shake128_midstate_is_bound_to_the_output_size() {
+    let mut shake128hasher = Shake128Hasher::<32>::default();
+    shake128hasher.write(MSG);
+
+    let mut midstate = [0u8; 512];
+    let len = shake128hasher.export_midstate(&mut midstate).unwrap();
+    assert_eq!(Shake128Hasher::<64>::import_midstate(&midstate[..len]), Err(MidstateError::AlgorithmMismatch));
+}
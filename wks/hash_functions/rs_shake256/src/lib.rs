@@ -65,9 +65,10 @@
 
 #![no_std]
 
-pub use rs_hasher_ctx::HasherContext;
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
+pub use rs_internal_state::XofReader;
 pub use shake256hasher::Shake256Hasher;
-pub use shake256state::Shake256State;
+pub use shake256state::{Shake256Reader, Shake256State};
 
 mod shake256hasher;
 mod shake256state;
@@ -1,6 +1,6 @@
-use crate::Shake256State;
+use crate::{Shake256Reader, Shake256State};
 use core::hash::Hasher;
-use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext};
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Midstate, MidstateError};
 use rs_internal_hasher::HashAlgorithm;
 use rs_internal_state::ExtendedOutputFunction;
 
@@ -60,6 +60,34 @@ impl<const OUTPUT_SIZE: usize> From<Shake256State<OUTPUT_SIZE>> for Shake256Hash
     }
 }
 
+impl<const OUTPUT_SIZE: usize> Shake256Hasher<OUTPUT_SIZE> {
+    /// Finishes the absorbing phase and returns a reader over the unbounded SHAKE256 output stream.
+    ///
+    /// Contrary to `HasherContext::finish`, the amount of output is not bound to `OUTPUT_SIZE`, so the reader can be
+    /// squeezed as many times as needed, for instance as a mask generation or key stretching function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::hash::Hasher;
+    /// # use rs_shake256::{Shake256Hasher, XofReader};
+    /// let mut shake256hasher = Shake256Hasher::<0>::default();
+    /// shake256hasher.write(b"abc");
+    ///
+    /// let mut reader = shake256hasher.finish_xof();
+    /// let mut first_half = [0u8; 5];
+    /// let mut second_half = [0u8; 5];
+    /// reader.read(&mut first_half);
+    /// reader.read(&mut second_half);
+    ///
+    /// assert_eq!(first_half, [0x48, 0x33, 0x66, 0x60, 0x13]);
+    /// assert_eq!(second_half, [0x60, 0xA8, 0x77, 0x1C, 0x68]);
+    /// ```
+    pub fn finish_xof(&mut self) -> Shake256Reader {
+        HasherContext::finish(&mut self.0).into()
+    }
+}
+
 impl<const OUTPUT_SIZE: usize> Hasher for Shake256Hasher<OUTPUT_SIZE> {
     fn finish(&self) -> u64 {
         Hasher::finish(&self.0)
@@ -77,3 +105,17 @@ impl<const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for Shake256Hasher<OUT
         HasherContext::finish(&mut self.0).squeeze().into()
     }
 }
+
+impl<const OUTPUT_SIZE: usize> Midstate for Shake256Hasher<OUTPUT_SIZE> {
+    fn midstate_len(&self) -> usize {
+        self.0.midstate_len()
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.0.export_midstate(bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        GenericHasher::import_midstate(bytes).map(Self)
+    }
+}
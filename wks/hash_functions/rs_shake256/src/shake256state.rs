@@ -1,11 +1,17 @@
 use crate::Shake256Hasher;
 use core::hash::BuildHasher;
 use rs_hasher_ctx::ByteArrayWrapper;
-use rs_internal_hasher::{GenericPad, HashAlgorithm, KeccakU128Size};
-use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge};
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, KeccakU128Size};
+use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge, KeccakXofReader, Zeroize};
 
 const RATE: usize = 136;
 
+/// `Shake256Reader` streams an arbitrary amount of SHAKE256 output into caller provided buffers.
+///
+/// It is obtained through `Shake256Hasher::finish_xof`, and every call to `XofReader::read` continues the output stream
+/// from where the previous one stopped.
+pub type Shake256Reader = KeccakXofReader<u64, RATE>;
+
 /// `Shake256State` represents the state of a SHAKE256 hashing process.
 ///
 /// It holds intermediate hash calculations. However, it's important to note that starting a hashing process from an
@@ -81,6 +87,12 @@ impl<const OUTPUT_SIZE: usize> From<Shake256State<OUTPUT_SIZE>> for ByteArrayWra
     }
 }
 
+impl<const OUTPUT_SIZE: usize> From<Shake256State<OUTPUT_SIZE>> for Shake256Reader {
+    fn from(value: Shake256State<OUTPUT_SIZE>) -> Self {
+        value.sponge.into()
+    }
+}
+
 impl<const OUTPUT_SIZE: usize> HashAlgorithm for Shake256State<OUTPUT_SIZE> {
     type Padding = GenericPad<KeccakU128Size, RATE, 0x1F>;
     type Output = ByteArrayWrapper<OUTPUT_SIZE>;
@@ -93,3 +105,24 @@ impl<const OUTPUT_SIZE: usize> HashAlgorithm for Shake256State<OUTPUT_SIZE> {
         self.squeeze_u64()
     }
 }
+
+impl<const OUTPUT_SIZE: usize> HashMidstate for Shake256State<OUTPUT_SIZE> {
+    const ALGORITHM: &'static str = "SHAKE256";
+    const MIDSTATE_LEN: usize = KeccakSponge::<u64, RATE, OUTPUT_SIZE>::LANES_BYTES_LEN;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        self.sponge.export_lanes(bytes)
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        Self {
+            sponge: KeccakSponge::import_lanes(bytes),
+        }
+    }
+}
+
+impl<const OUTPUT_SIZE: usize> Zeroize for Shake256State<OUTPUT_SIZE> {
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+    }
+}
@@ -1,10 +1,12 @@
 extern crate alloc;
 
-use crate::{Shake256Hasher, Shake256State};
+use crate::{Shake256Hasher, Shake256State, XofReader};
 use alloc::format;
 use core::hash::{BuildHasher, Hasher};
 use rs_hasher_ctx::HasherContext;
 
+const MSG: &[u8; 43] = b"The quick brown fox jumps over the lazy dog";
+
 #[test]
 fn assert_empty_string_hash_correctness() {
     let shake256state = Shake256State::<64>::default();
@@ -35,3 +37,39 @@ fn testdsa() {
         ]
     )
 }
+
+#[test]
+fn assert_xof_reader_matches_fixed_size_output_across_rate_boundaries() {
+    const OUTPUT_LEN: usize = 500;
+    let mut shake256hasher = Shake256Hasher::<OUTPUT_LEN>::default();
+    shake256hasher.write(MSG);
+
+    let expected_output = HasherContext::finish(&mut shake256hasher.clone());
+    let mut reader = shake256hasher.finish_xof();
+    let mut output = [0u8; OUTPUT_LEN];
+
+    for chunk in output.chunks_mut(7) {
+        reader.read(chunk);
+    }
+
+    assert_eq!(expected_output, output);
+}
+
+#[test]
+fn assert_xof_reader_resumes_where_previous_read_stopped() {
+    let mut shake256hasher = Shake256Hasher::<0>::default();
+    shake256hasher.write(MSG);
+
+    let mut single_read_reader = shake256hasher.clone().finish_xof();
+    let mut multiple_reads_reader = shake256hasher.finish_xof();
+    let mut single_read = [0u8; 1024];
+    let mut multiple_reads = [0u8; 1024];
+
+    single_read_reader.read(&mut single_read);
+    multiple_reads_reader.read(&mut multiple_reads[..1]);
+    multiple_reads_reader.read(&mut multiple_reads[1..300]);
+    multiple_reads_reader.read(&mut []);
+    multiple_reads_reader.read(&mut multiple_reads[300..]);
+
+    assert_eq!(single_read, multiple_reads);
+}
@@ -0,0 +1,127 @@
+//! # TupleHash `rs_tuple_hash` - Hash of a tuple of byte strings
+//!
+//! TupleHash is a cSHAKE based function, specified by the National Institute of Standards and Technology (NIST) in
+//! SP 800-185, that hashes a tuple of byte strings. Every element is encoded along with its length, so that the tuples
+//! `("abc", "d")` and `("ab", "cd")` yield unrelated results, something a plain hash over the concatenation cannot
+//! ensure.
+//!
+//! Both TupleHash128 and TupleHash256 are provided, as well as their extendable-output variants, TupleHashXOF128 and
+//! TupleHashXOF256.
+//!
+//! ## Usage
+//!
+//! Every call to `Hasher::write` appends one element to the tuple. Users can create a new TupleHash instance with a
+//! customization string, write each element, and finalize to get the resultant hash.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use TupleHash128 in Rust:
+//!
+//! ```rust
+//! # use std::hash::Hasher;
+//! # use rs_tuple_hash::{HasherContext, TupleHash128};
+//! let mut tuple_hash = TupleHash128::<32>::new(b"My Tuple App");
+//! tuple_hash.write(&[0x00, 0x01, 0x02]);
+//! tuple_hash.write(&[0x10, 0x11, 0x12, 0x13, 0x14, 0x15]);
+//!
+//! let result = HasherContext::finish(&mut tuple_hash);
+//! assert_eq!(format!("{result:02x}"), "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb");
+//! ```
+//!
+//! ## Use Cases
+//!
+//! TupleHash is recommended whenever structured data has to be hashed, such as:
+//!
+//! - Hashing several fields of a record, without having to devise an unambiguous serialization.
+//! - Deriving identifiers from multiple inputs.
+//!
+
+#![no_std]
+
+use core::hash::Hasher;
+use rs_cshake::{left_encode, right_encode, CShakeHasher, RATE_128, RATE_256};
+use rs_hasher_ctx::ByteArrayWrapper;
+pub use rs_hasher_ctx::HasherContext;
+use rs_internal_state::KeccakXofReader;
+pub use rs_internal_state::XofReader;
+
+const FUNCTION_NAME: &[u8; 9] = b"TupleHash";
+
+/// TupleHash128, with a 128 bits security strength
+pub type TupleHash128<const OUTPUT_SIZE: usize> = TupleHash<RATE_128, OUTPUT_SIZE>;
+/// TupleHash256, with a 256 bits security strength
+pub type TupleHash256<const OUTPUT_SIZE: usize> = TupleHash<RATE_256, OUTPUT_SIZE>;
+
+/// `TupleHash<const RATE: usize, const OUTPUT_SIZE: usize>` is a generic struct that provides TupleHash in Rust, for
+/// the rate given by `RATE` in bytes.
+///
+/// Contrary to the other hashers of this project, each call to `Hasher::write` is a whole tuple element, which is
+/// absorbed as `encode_string(bytes)`. Therefore, writing `b"ab"` and then `b"c"` is not the same as writing `b"abc"`. Keep in
+/// mind that the `Hash` implementations of the standard library may call `write` more than once per value, as it
+/// happens with the trailing `0xFF` written after a `str`.
+///
+/// `TupleHash::finish_xof` provides the TupleHashXOF variant, whose output can be squeezed indefinitely.
+///
+/// ## Examples
+///
+/// The following example shows that the element boundaries are part of the result:
+///
+///```rust
+/// # use std::hash::Hasher;
+/// # use rs_tuple_hash::{HasherContext, TupleHash256};
+/// let mut tuple_hash1 = TupleHash256::<64>::new(b"");
+/// tuple_hash1.write(b"abc");
+/// tuple_hash1.write(b"d");
+///
+/// let mut tuple_hash2 = TupleHash256::<64>::new(b"");
+/// tuple_hash2.write(b"ab");
+/// tuple_hash2.write(b"cd");
+///
+/// assert_ne!(HasherContext::finish(&mut tuple_hash1), HasherContext::finish(&mut tuple_hash2));
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TupleHash<const RATE: usize, const OUTPUT_SIZE: usize> {
+    cshake: CShakeHasher<RATE, OUTPUT_SIZE>,
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> TupleHash<RATE, OUTPUT_SIZE> {
+    /// Creates a new TupleHash context with the given customization string, which may be empty.
+    pub fn new(customization: &[u8]) -> Self {
+        Self {
+            cshake: CShakeHasher::new(FUNCTION_NAME, customization),
+        }
+    }
+
+    /// Finishes the TupleHashXOF computation and returns a reader over its unbounded output stream.
+    pub fn finish_xof(&mut self) -> KeccakXofReader<u64, RATE> {
+        self.cshake.write(right_encode(0).as_ref());
+        self.cshake.finish_xof()
+    }
+
+    fn write_output_len(cshake: &mut CShakeHasher<RATE, OUTPUT_SIZE>) {
+        cshake.write(right_encode(OUTPUT_SIZE as u64 * 8).as_ref());
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> Hasher for TupleHash<RATE, OUTPUT_SIZE> {
+    fn finish(&self) -> u64 {
+        let mut cshake = self.cshake.clone();
+        Self::write_output_len(&mut cshake);
+        cshake.finish()
+    }
+
+    /// Appends `bytes` to the tuple as a single element.
+    fn write(&mut self, bytes: &[u8]) {
+        self.cshake.write(left_encode(bytes.len() as u64 * 8).as_ref());
+        self.cshake.write(bytes);
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize> HasherContext<OUTPUT_SIZE> for TupleHash<RATE, OUTPUT_SIZE> {
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn finish(&mut self) -> Self::Output {
+        Self::write_output_len(&mut self.cshake);
+        HasherContext::finish(&mut self.cshake)
+    }
+}
@@ -0,0 +1,123 @@
+use rs_tuple_hash::{HasherContext, TupleHash, TupleHash128, TupleHash256, XofReader};
+use std::hash::Hasher;
+
+const TUPLE_APP: &[u8; 12] = b"My Tuple App";
+const X1: [u8; 3] = [0x00, 0x01, 0x02];
+const X2: [u8; 6] = [0x10, 0x11, 0x12, 0x13, 0x14, 0x15];
+const X3: [u8; 9] = [0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28];
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn tuple_hash<const RATE: usize, const OUTPUT_SIZE: usize>(elements: &[&[u8]], customization: &[u8]) -> String {
+    let mut tuple_hash = TupleHash::<RATE, OUTPUT_SIZE>::new(customization);
+    elements.iter().for_each(|element| tuple_hash.write(element));
+    format!("{:02x}", HasherContext::finish(&mut tuple_hash))
+}
+
+fn tuple_hash_xof<const RATE: usize, const OUTPUT_SIZE: usize>(elements: &[&[u8]], customization: &[u8]) -> String {
+    let mut tuple_hash = TupleHash::<RATE, OUTPUT_SIZE>::new(customization);
+    elements.iter().for_each(|element| tuple_hash.write(element));
+    let mut output = [0u8; OUTPUT_SIZE];
+    tuple_hash.finish_xof().read(&mut output);
+    to_hex(&output)
+}
+
+#[test]
+fn assert_tuple_hash128_nist_samples() {
+    assert_eq!(
+        tuple_hash::<168, 32>(&[&X1, &X2], b""),
+        "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1"
+    );
+    assert_eq!(
+        tuple_hash::<168, 32>(&[&X1, &X2], TUPLE_APP),
+        "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb"
+    );
+    assert_eq!(
+        tuple_hash::<168, 32>(&[&X1, &X2, &X3], TUPLE_APP),
+        "e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84"
+    );
+}
+
+#[test]
+fn assert_tuple_hash256_nist_samples() {
+    assert_eq!(
+        tuple_hash::<136, 64>(&[&X1, &X2], b""),
+        "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec607311ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194"
+    );
+    assert_eq!(
+        tuple_hash::<136, 64>(&[&X1, &X2], TUPLE_APP),
+        "147c2191d5ed7efd98dbd96d7ab5a11692576f5fe2a5065f3e33de6bba9f3aa1c4e9a068a289c61c95aab30aee1e410b0b607de3620e24a4e3bf9852a1d4367e"
+    );
+    assert_eq!(
+        tuple_hash::<136, 64>(&[&X1, &X2, &X3], TUPLE_APP),
+        "45000be63f9b6bfd89f54717670f69a9bc763591a4f05c50d68891a744bcc6e7d6d5b5e82c018da999ed35b0bb49c9678e526abd8e85c13ed254021db9e790ce"
+    );
+}
+
+#[test]
+fn assert_tuple_hash_xof128_nist_samples() {
+    assert_eq!(
+        tuple_hash_xof::<168, 32>(&[&X1, &X2], b""),
+        "2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488"
+    );
+    assert_eq!(
+        tuple_hash_xof::<168, 32>(&[&X1, &X2], TUPLE_APP),
+        "3fc8ad69453128292859a18b6c67d7ad85f01b32815e22ce839c49ec374e9b9a"
+    );
+    assert_eq!(
+        tuple_hash_xof::<168, 32>(&[&X1, &X2, &X3], TUPLE_APP),
+        "900fe16cad098d28e74d632ed852f99daab7f7df4d99e775657885b4bf76d6f8"
+    );
+}
+
+#[test]
+fn assert_tuple_hash_xof256_nist_samples() {
+    assert_eq!(
+        tuple_hash_xof::<136, 64>(&[&X1, &X2], b""),
+        "03ded4610ed6450a1e3f8bc44951d14fbc384ab0efe57b000df6b6df5aae7cd568e77377daf13f37ec75cf5fc598b6841d51dd207c991cd45d210ba60ac52eb9"
+    );
+    assert_eq!(
+        tuple_hash_xof::<136, 64>(&[&X1, &X2], TUPLE_APP),
+        "6483cb3c9952eb20e830af4785851fc597ee3bf93bb7602c0ef6a65d741aeca7e63c3b128981aa05c6d27438c79d2754bb1b7191f125d6620fca12ce658b2442"
+    );
+    assert_eq!(
+        tuple_hash_xof::<136, 64>(&[&X1, &X2, &X3], TUPLE_APP),
+        "0c59b11464f2336c34663ed51b2b950bec743610856f36c28d1d088d8a2446284dd09830a6a178dc752376199fae935d86cfdee5913d4922dfd369b66a53c897"
+    );
+}
+
+#[test]
+fn assert_element_boundaries_change_the_result() {
+    let mut split = TupleHash128::<32>::new(b"");
+    split.write(b"ab");
+    split.write(b"c");
+
+    let mut whole = TupleHash128::<32>::new(b"");
+    whole.write(b"abc");
+
+    assert_ne!(HasherContext::finish(&mut split), HasherContext::finish(&mut whole));
+}
+
+#[test]
+fn assert_empty_elements_are_part_of_the_tuple() {
+    let mut one_element = TupleHash256::<64>::new(b"");
+    one_element.write(b"");
+
+    let mut no_elements = TupleHash256::<64>::new(b"");
+
+    assert_ne!(HasherContext::finish(&mut one_element), HasherContext::finish(&mut no_elements));
+}
+
+#[test]
+fn assert_output_length_is_bound_to_the_result() {
+    let mut short = TupleHash128::<16>::new(TUPLE_APP);
+    short.write(&X1);
+    let mut long = TupleHash128::<32>::new(TUPLE_APP);
+    long.write(&X1);
+
+    let short_result = HasherContext::finish(&mut short);
+    let long_result = HasherContext::finish(&mut long);
+    assert_ne!(short_result.as_ref(), &long_result.as_ref()[..16]);
+}
@@ -0,0 +1,75 @@
+//! # TurboSHAKE `rs_turbo_shake` - Round-reduced SHAKE
+//!
+//! TurboSHAKE is a family of extendable-output functions, specified in RFC 9861, that follows the SHAKE construction
+//! on top of Keccak-p\[1600, 12\], the Keccak permutation reduced to its last 12 rounds. Halving the rounds roughly
+//! doubles the throughput, while keeping a comfortable security margin against the best known attacks.
+//!
+//! Both TurboSHAKE128 and TurboSHAKE256 are provided. On top of the message, TurboSHAKE takes a domain separation byte
+//! in the range `0x01..=0x7F`, with `0x1F` being the one for plain message hashing, which allows higher level
+//! constructions, such as KangarooTwelve, to separate their different uses of the function.
+//!
+//! ## Usage
+//!
+//! The crate offers the same API as the SHAKE crates. Users can create a new TurboSHAKE hasher instance, update it
+//! with input data, and finalize to get the resultant hash of arbitrary length.
+//!
+//! ### Example
+//!
+//! Here is an example of how to use TurboSHAKE256 in Rust:
+//!
+//! ```rust
+//! # use std::hash::Hasher;
+//! # use rs_turbo_shake::{TurboShake256Hasher, XofReader};
+//! let mut turbo_shake256hasher = TurboShake256Hasher::<0>::default();
+//! turbo_shake256hasher.write(&[0x00]);
+//!
+//! let mut reader = turbo_shake256hasher.finish_xof();
+//! let mut output = [0u8; 8];
+//! reader.read(&mut output);
+//! assert_eq!(output, [0x3E, 0x17, 0x12, 0xF9, 0x28, 0xF8, 0xEA, 0xF1]);
+//! ```
+//!
+//! ## Use Cases
+//!
+//! TurboSHAKE is recommended wherever SHAKE would be used and speed matters, such as:
+//!
+//! - Hashing of large amounts of data, as in file and container image integrity checks.
+//! - Building blocks of faster hash modes, such as the KangarooTwelve tree hash.
+//!
+
+#![no_std]
+
+pub use rs_hasher_ctx::{HasherContext, Midstate, MidstateError, MIDSTATE_VERSION};
+pub use rs_internal_state::XofReader;
+pub use turbo_shake_hasher::TurboShakeHasher;
+pub use turbo_shake_state::{TurboShakeReader, TurboShakeState, TURBO_SHAKE_ROUNDS};
+
+mod turbo_shake_hasher;
+mod turbo_shake_state;
+
+#[cfg(test)]
+mod unit_tests;
+
+/// Rate, in bytes, of TurboSHAKE128
+pub const RATE_128: usize = 168;
+/// Rate, in bytes, of TurboSHAKE256
+pub const RATE_256: usize = 136;
+/// Domain separation byte for plain message hashing
+pub const DEFAULT_DOMAIN: u8 = 0x1F;
+
+/// TurboSHAKE128, with a 128 bits security strength
+pub type TurboShake128Hasher<const OUTPUT_SIZE: usize, const DOMAIN: u8 = DEFAULT_DOMAIN> =
+    TurboShakeHasher<RATE_128, OUTPUT_SIZE, DOMAIN>;
+/// TurboSHAKE256, with a 256 bits security strength
+pub type TurboShake256Hasher<const OUTPUT_SIZE: usize, const DOMAIN: u8 = DEFAULT_DOMAIN> =
+    TurboShakeHasher<RATE_256, OUTPUT_SIZE, DOMAIN>;
+/// State of TurboSHAKE128
+pub type TurboShake128State<const OUTPUT_SIZE: usize, const DOMAIN: u8 = DEFAULT_DOMAIN> =
+    TurboShakeState<RATE_128, OUTPUT_SIZE, DOMAIN>;
+/// State of TurboSHAKE256
+pub type TurboShake256State<const OUTPUT_SIZE: usize, const DOMAIN: u8 = DEFAULT_DOMAIN> =
+    TurboShakeState<RATE_256, OUTPUT_SIZE, DOMAIN>;
+/// Reader over the output stream of TurboSHAKE128
+pub type TurboShake128Reader = TurboShakeReader<RATE_128>;
+/// Reader over the output stream of TurboSHAKE256
+pub type TurboShake256Reader = TurboShakeReader<RATE_256>;
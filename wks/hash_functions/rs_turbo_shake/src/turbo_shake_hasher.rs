@@ -0,0 +1,111 @@
+use crate::{TurboShakeReader, TurboShakeState};
+use core::hash::Hasher;
+use rs_hasher_ctx::{ByteArrayWrapper, GenericHasher, HasherContext, Midstate, MidstateError};
+use rs_internal_state::ExtendedOutputFunction;
+
+/// `TurboShakeHasher` is a type that provides the TurboSHAKE extendable-output function, as specified in RFC 9861, for
+/// the rate given by `RATE` in bytes.
+///
+/// `DOMAIN` is the domain separation byte, which must lie within `0x01..=0x7F`. Applications hashing plain messages
+/// should stick to `DEFAULT_DOMAIN`, which the `TurboShake128Hasher` and `TurboShake256Hasher` aliases default to, and
+/// pick other values only to separate unrelated uses of the function. Out of range values are rejected at compile
+/// time.
+///
+/// The `TurboShakeHasher` struct adheres to Rust's `Hasher` trait, enabling you to use it interchangeably with other
+/// hashers in Rust.
+///
+/// ## Examples
+///
+/// Different domain separation bytes yield unrelated outputs for the same message:
+///
+///```rust
+/// # use std::hash::Hasher;
+/// # use rs_turbo_shake::{HasherContext, TurboShake128Hasher};
+/// let mut turbo_shake128hasher = TurboShake128Hasher::<32>::default();
+/// turbo_shake128hasher.write(&[0xFF]);
+///
+/// let mut domain_separated_hasher = TurboShake128Hasher::<32, 0x06>::default();
+/// domain_separated_hasher.write(&[0xFF]);
+///
+/// let result = HasherContext::finish(&mut domain_separated_hasher);
+/// assert_ne!(HasherContext::finish(&mut turbo_shake128hasher), result);
+/// assert_eq!(format!("{result:02x}"), "8ec9c66465ed0d4a6c35d13506718d687a25cb05c74cca1e42501abd83874a67");
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TurboShakeHasher<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8>(
+    GenericHasher<TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>, OUTPUT_SIZE>,
+);
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN> {
+    /// Finishes the absorbing phase and returns a reader over the unbounded TurboSHAKE output stream.
+    pub fn finish_xof(&mut self) -> TurboShakeReader<RATE> {
+        HasherContext::finish(&mut self.0).into()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> Default
+    for TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn default() -> Self {
+        const { assert!(DOMAIN >= 0x01 && DOMAIN <= 0x7F, "TurboSHAKE domain separation byte must be in 0x01..=0x7F") };
+
+        Self(GenericHasher::default())
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> From<TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>>
+    for TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn from(value: TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>) -> Self {
+        value.0.state
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> From<TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>>
+    for TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn from(value: TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>) -> Self {
+        let mut turbo_shake_hasher = Self::default();
+        turbo_shake_hasher.0.state = value;
+
+        turbo_shake_hasher
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> Hasher
+    for TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn finish(&self) -> u64 {
+        Hasher::finish(&self.0)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes)
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> HasherContext<OUTPUT_SIZE>
+    for TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn finish(&mut self) -> Self::Output {
+        ByteArrayWrapper::from(HasherContext::finish(&mut self.0).squeeze())
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> Midstate
+    for TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn midstate_len(&self) -> usize {
+        self.0.midstate_len()
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.0.export_midstate(bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        GenericHasher::import_midstate(bytes).map(Self)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::TurboShakeHasher;
+use core::hash::BuildHasher;
+use rs_hasher_ctx::ByteArrayWrapper;
+use rs_internal_hasher::{GenericPad, HashAlgorithm, HashMidstate, KeccakU128Size};
+use rs_internal_state::{BytesLen, ExtendedOutputFunction, KeccakSponge, KeccakXofReader, Zeroize};
+
+/// Number of rounds of the Keccak-p\[1600\] permutation used by TurboSHAKE
+pub const TURBO_SHAKE_ROUNDS: usize = 12;
+
+/// `TurboShakeReader` streams an arbitrary amount of TurboSHAKE output into caller provided buffers.
+///
+/// It is obtained through `TurboShakeHasher::finish_xof`, and every call to `XofReader::read` continues the output
+/// stream from where the previous one stopped.
+pub type TurboShakeReader<const RATE: usize> = KeccakXofReader<u64, RATE, TURBO_SHAKE_ROUNDS>;
+
+/// `TurboShakeState` represents the state of a TurboSHAKE hashing process, for the rate given by `RATE` in bytes and
+/// the domain separation byte given by `DOMAIN`.
+///
+/// `TurboShake128State` and `TurboShake256State` are the instances with the rates defined by RFC 9861, and the ones
+/// that should be used in practice.
+///
+/// It holds intermediate hash calculations. However, it's important to note that starting a hashing process from an
+/// arbitrary `TurboShakeState` is not equivalent to resuming the original process that produced that state, as the
+/// pad contents are not part of it.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::hash::{BuildHasher, Hasher};
+/// # use rs_turbo_shake::{HasherContext, TurboShake128State};
+/// let mut turbo_shake128hasher = TurboShake128State::<32>::default().build_hasher();
+/// turbo_shake128hasher.write(b"");
+///
+/// let result = HasherContext::finish(&mut turbo_shake128hasher);
+/// assert_eq!(format!("{result:02x}"), "1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TurboShakeState<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> {
+    sponge: KeccakSponge<u64, RATE, OUTPUT_SIZE, TURBO_SHAKE_ROUNDS>,
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> BuildHasher
+    for TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    type Hasher = TurboShakeHasher<RATE, OUTPUT_SIZE, DOMAIN>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        TurboShakeHasher::default()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> BytesLen
+    for TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn len() -> usize {
+        RATE
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> ExtendedOutputFunction<OUTPUT_SIZE>
+    for TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn squeeze_u64(&self) -> u64 {
+        self.sponge.squeeze_u64()
+    }
+
+    fn squeeze(&mut self) -> [u8; OUTPUT_SIZE] {
+        self.sponge.squeeze()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> From<TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>>
+    for ByteArrayWrapper<OUTPUT_SIZE>
+{
+    fn from(mut value: TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>) -> Self {
+        value.squeeze().into()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> From<TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>>
+    for TurboShakeReader<RATE>
+{
+    fn from(value: TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>) -> Self {
+        value.sponge.into()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> HashAlgorithm
+    for TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    type Padding = GenericPad<KeccakU128Size, RATE, DOMAIN>;
+    type Output = ByteArrayWrapper<OUTPUT_SIZE>;
+
+    fn hash_block(&mut self, bytes: &[u8]) {
+        self.sponge.absorb(bytes);
+    }
+
+    fn state_to_u64(&self) -> u64 {
+        self.squeeze_u64()
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> HashMidstate
+    for TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    const ALGORITHM: &'static str = "TurboSHAKE";
+    const MIDSTATE_LEN: usize = KeccakSponge::<u64, RATE, OUTPUT_SIZE, TURBO_SHAKE_ROUNDS>::LANES_BYTES_LEN;
+
+    fn export_state(&self, bytes: &mut [u8]) {
+        self.sponge.export_lanes(bytes)
+    }
+
+    fn import_state(bytes: &[u8]) -> Self {
+        Self {
+            sponge: KeccakSponge::import_lanes(bytes),
+        }
+    }
+}
+
+impl<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8> Zeroize
+    for TurboShakeState<RATE, OUTPUT_SIZE, DOMAIN>
+{
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+    }
+}
@@ -0,0 +1,115 @@
+extern crate alloc;
+use crate::{TurboShake128Hasher, TurboShake256Hasher, TurboShakeHasher, XofReader};
+use alloc::{format, string::String, vec::Vec};
+use core::hash::Hasher;
+use rs_hasher_ctx::HasherContext;
+
+/// The `ptn(n)` test pattern of RFC 9861: the byte sequence `00 01 .. F9 FA 00 01 ..` of length `n`
+fn ptn(n: usize) -> Vec<u8> {
+    (0..n).map(|i| (i % 251) as u8).collect()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn turbo_shake<const RATE: usize, const OUTPUT_SIZE: usize, const DOMAIN: u8>(msg: &[u8]) -> String {
+    let mut turbo_shake_hasher = TurboShakeHasher::<RATE, OUTPUT_SIZE, DOMAIN>::default();
+    turbo_shake_hasher.write(msg);
+    format!("{:02x}", HasherContext::finish(&mut turbo_shake_hasher))
+}
+
+#[test]
+fn assert_turbo_shake128_rfc9861_ptn_samples() {
+    let expected = [
+        "1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c",
+        "55cedd6f60af7bb29a4042ae832ef3f58db7299f893ebb9247247d856958daa9",
+        "9c97d036a3bac819db70ede0ca554ec6e4c2a1a4ffbfd9ec269ca6a111161233",
+        "96c77c279e0126f7fc07c9b07f5cdae1e0be60bdbe10620040e75d7223a624d2",
+        "d4976eb56bcf118520582b709f73e1d6853e001fdaf80e1b13e0d0599d5fb372",
+    ];
+
+    assert_eq!(turbo_shake::<168, 32, 0x1F>(b""), expected[0]);
+    for (i, expected) in expected.iter().skip(1).enumerate() {
+        assert_eq!(turbo_shake::<168, 32, 0x1F>(&ptn(17usize.pow(i as u32))), *expected);
+    }
+}
+
+#[test]
+fn assert_turbo_shake256_rfc9861_ptn_samples() {
+    let expected = [
+        "367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0",
+        "3e1712f928f8eaf1054632b2aa0a246ed8b0c378728f60bc970410155c28820e90cc90d8a3006aa2372c5c5ea176b0682bf22bae7467ac94f74d43d39b0482e2",
+        "b3bab0300e6a191fbe6137939835923578794ea54843f5011090fa2f3780a9e5cb22c59d78b40a0fbff9e672c0fbe0970bd2c845091c6044d687054da5d8e9c7",
+        "66b810db8e90780424c0847372fdc95710882fde31c6df75beb9d4cd9305cfcae35e7b83e8b7e6eb4b78605880116316fe2c078a09b94ad7b8213c0a738b65c0",
+        "c74ebc919a5b3b0dd1228185ba02d29ef442d69d3d4276a93efe0bf9a16a7dc0cd4eabadab8cd7a5edd96695f5d360abe09e2c6511a3ec397da3b76b9e1674fb",
+    ];
+
+    assert_eq!(turbo_shake::<136, 64, 0x1F>(b""), expected[0]);
+    for (i, expected) in expected.iter().skip(1).enumerate() {
+        assert_eq!(turbo_shake::<136, 64, 0x1F>(&ptn(17usize.pow(i as u32))), *expected);
+    }
+}
+
+#[test]
+fn assert_turbo_shake128_rfc9861_domain_separation_samples() {
+    assert_eq!(
+        turbo_shake::<168, 32, 0x01>(&[0xFF; 3]),
+        "bf323f940494e88ee1c540fe660be8a0c93f43d15ec006998462fa994eed5dab"
+    );
+    assert_eq!(
+        turbo_shake::<168, 32, 0x06>(&[0xFF]),
+        "8ec9c66465ed0d4a6c35d13506718d687a25cb05c74cca1e42501abd83874a67"
+    );
+    assert_eq!(
+        turbo_shake::<168, 32, 0x07>(&[0xFF; 3]),
+        "b658576001cad9b1e5f399a9f77723bba05458042d68206f7252682dba3663ed"
+    );
+    assert_eq!(
+        turbo_shake::<168, 32, 0x0B>(&[0xFF; 7]),
+        "8deeaa1aec47ccee569f659c21dfa8e112db3cee37b18178b2acd805b799cc37"
+    );
+    assert_eq!(
+        turbo_shake::<168, 32, 0x30>(&[0xFF]),
+        "553122e2135e363c3292bed2c6421fa232bab03daa07c7d6636603286506325b"
+    );
+    assert_eq!(
+        turbo_shake::<168, 32, 0x7F>(&[0xFF; 3]),
+        "16274cc656d44cefd422395d0f9053bda6d28e122aba15c765e5ad0e6eaf26f9"
+    );
+}
+
+#[test]
+fn assert_turbo_shake_long_output_tail() {
+    let mut output = [0u8; 10032];
+
+    TurboShake128Hasher::<0>::default().finish_xof().read(&mut output);
+    assert_eq!(to_hex(&output[10000..]), "a3b9b0385900ce761f22aed548e754da10a5242d62e8c658e3f3a923a7555607");
+
+    TurboShake256Hasher::<0>::default().finish_xof().read(&mut output);
+    assert_eq!(to_hex(&output[10000..]), "abefa11630c661269249742685ec082f207265dccf2f43534e9c61ba0c9d1d75");
+}
+
+#[test]
+fn assert_xof_reader_matches_fixed_size_output() {
+    const OUTPUT_LEN: usize = 400;
+    let mut turbo_shake128hasher = TurboShake128Hasher::<OUTPUT_LEN>::default();
+    turbo_shake128hasher.write(&ptn(289));
+
+    let expected_output = HasherContext::finish(&mut turbo_shake128hasher.clone());
+    let mut reader = turbo_shake128hasher.finish_xof();
+    let mut output = [0u8; OUTPUT_LEN];
+    output.chunks_mut(11).for_each(|chunk| reader.read(chunk));
+
+    assert_eq!(expected_output, output);
+}
+
+#[test]
+fn assert_u64_result_is_the_big_endian_output_prefix() {
+    let mut turbo_shake128hasher = TurboShake128Hasher::<32>::default();
+    turbo_shake128hasher.write(&ptn(17));
+
+    let u64result = turbo_shake128hasher.finish();
+    let bytes_result = HasherContext::finish(&mut turbo_shake128hasher);
+    assert_eq!(u64result.to_be_bytes(), bytes_result[..8]);
+}
@@ -0,0 +1,92 @@
+use core::hint::black_box;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+/// `Choice` is the outcome of a constant-time comparison, kept as a byte that is either `0` or `1`.
+///
+/// It implements the bitwise operators instead of `PartialEq`, so that outcomes are combined without branching. The
+/// byte is passed through an optimization barrier when the `Choice` is built, which keeps the compiler from turning the
+/// masks derived from it back into branches.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_constant_time::Choice;
+/// let yes = Choice::from(1);
+/// let no = Choice::from(0);
+///
+/// assert!(bool::from(yes & !no));
+/// assert_eq!((yes ^ yes).unwrap_u8(), 0);
+///```
+#[derive(Clone, Copy, Debug)]
+pub struct Choice(u8);
+
+impl Choice {
+    /// Returns the byte behind the `Choice`, either `0` or `1`.
+    pub fn unwrap_u8(&self) -> u8 {
+        self.0
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for Choice {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Choice {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitXor for Choice {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl BitXorAssign for Choice {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> Self {
+        choice.0 != 0
+    }
+}
+
+impl From<u8> for Choice {
+    /// Builds a `Choice` out of `value`, which must be `0` or `1`.
+    fn from(value: u8) -> Self {
+        debug_assert!(value <= 1, "Choice must be built out of 0 or 1");
+        Self(black_box(value))
+    }
+}
+
+impl Not for Choice {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(1 & !self.0)
+    }
+}
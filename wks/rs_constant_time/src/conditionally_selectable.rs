@@ -0,0 +1,95 @@
+use crate::Choice;
+use core::ops::Neg;
+
+/// `ConditionallySelectable` picks one of two values through bit masks derived from a `Choice`, instead of a branch.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_constant_time::{Choice, ConditionallySelectable};
+/// let mut a = [0x01u8, 0x02];
+/// let mut b = [0xF1u8, 0xF2];
+///
+/// <[u8; 2]>::conditional_swap(&mut a, &mut b, Choice::from(1));
+/// assert_eq!((a, b), ([0xF1, 0xF2], [0x01, 0x02]));
+///
+/// a.conditional_assign(&[0x00, 0x00], Choice::from(0));
+/// assert_eq!(a, [0xF1, 0xF2]);
+///```
+pub trait ConditionallySelectable: Sized {
+    /// Returns `a` if `choice` is `0`, and `b` if it is `1`.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    /// Overwrites `self` with `other` if `choice` is `1`.
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        *self = Self::conditional_select(self, other, choice);
+    }
+
+    /// Swaps `a` and `b` if `choice` is `1`.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let selected_a = Self::conditional_select(a, b, choice);
+        *b = Self::conditional_select(b, a, choice);
+        *a = selected_a;
+    }
+}
+
+/// `ConditionallyNegatable` negates a value through bit masks derived from a `Choice`, instead of a branch.
+///
+/// It is implemented for every `ConditionallySelectable` type whose references can be negated, such as the signed
+/// integers, and `NBitWord`, whose negation wraps around.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_constant_time::{Choice, ConditionallyNegatable};
+/// let mut x = 42i64;
+///
+/// x.conditional_negate(Choice::from(1));
+/// assert_eq!(x, -42);
+///
+/// x.conditional_negate(Choice::from(0));
+/// assert_eq!(x, -42);
+///```
+pub trait ConditionallyNegatable {
+    /// Negates `self` if `choice` is `1`.
+    fn conditional_negate(&mut self, choice: Choice);
+}
+
+impl<T> ConditionallyNegatable for T
+where
+    T: ConditionallySelectable,
+    for<'a> &'a T: Neg<Output = T>,
+{
+    fn conditional_negate(&mut self, choice: Choice) {
+        let negated = -&*self;
+        self.conditional_assign(&negated, choice);
+    }
+}
+
+macro_rules! impl_conditionally_selectable_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl ConditionallySelectable for $t {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    // All ones if `choice` is `1`, and all zeros otherwise
+                    let mask = (choice.unwrap_u8() as $t).wrapping_neg();
+                    *a ^ (mask & (*a ^ *b))
+                }
+            }
+        )*
+    };
+}
+
+impl_conditionally_selectable_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl ConditionallySelectable for Choice {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Choice::from(u8::conditional_select(&a.unwrap_u8(), &b.unwrap_u8(), choice))
+    }
+}
+
+impl<T: ConditionallySelectable, const N: usize> ConditionallySelectable for [T; N] {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        core::array::from_fn(|i| T::conditional_select(&a[i], &b[i], choice))
+    }
+}
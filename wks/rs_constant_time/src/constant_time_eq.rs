@@ -0,0 +1,68 @@
+use crate::Choice;
+
+/// `ConstantTimeEq` compares two values in a time that does not depend on their content.
+///
+/// Slices of different lengths are unequal right away, as their lengths are not deemed secret. Otherwise, every
+/// element is compared, even once a difference is found.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_constant_time::ConstantTimeEq;
+/// let tag = [0x11u8, 0x22, 0x33, 0x44];
+///
+/// assert!(bool::from(tag.ct_eq(&[0x11, 0x22, 0x33, 0x44])));
+/// assert!(bool::from(tag.ct_ne(&[0x10, 0x22, 0x33, 0x44])));
+/// assert!(bool::from(tag[..].ct_ne(&tag[..3])));
+///```
+pub trait ConstantTimeEq {
+    /// Returns a `Choice` of `1` if `self` and `other` are equal, and `0` otherwise.
+    fn ct_eq(&self, other: &Self) -> Choice;
+
+    /// Returns a `Choice` of `1` if `self` and `other` differ, and `0` otherwise.
+    fn ct_ne(&self, other: &Self) -> Choice {
+        !self.ct_eq(other)
+    }
+}
+
+macro_rules! impl_constant_time_eq_for_integers {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl ConstantTimeEq for $t {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    // The top bit of `x | -x` is set if and only if `x` is not zero
+                    let x = (*self ^ *other) as $u;
+                    let is_not_zero = (x | x.wrapping_neg()) >> (<$u>::BITS - 1);
+                    Choice::from(is_not_zero as u8 ^ 1)
+                }
+            }
+        )*
+    };
+}
+
+impl_constant_time_eq_for_integers!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+impl ConstantTimeEq for Choice {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        !(*self ^ *other)
+    }
+}
+
+impl<T: ConstantTimeEq> ConstantTimeEq for [T] {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        if self.len() != other.len() {
+            return Choice::from(0);
+        }
+
+        self.iter().zip(other).fold(Choice::from(1), |is_equal, (a, b)| is_equal & a.ct_eq(b))
+    }
+}
+
+impl<T: ConstantTimeEq, const N: usize> ConstantTimeEq for [T; N] {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self[..].ct_eq(&other[..])
+    }
+}
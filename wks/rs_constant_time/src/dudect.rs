@@ -0,0 +1,215 @@
+use core::hint::black_box;
+use std::time::Instant;
+use std::vec::Vec;
+
+/// Absolute t statistic above which dudect deems the timings of the two classes distinguishable
+pub const LEAKAGE_THRESHOLD: f64 = 4.5;
+
+/// Count of cropped t-tests run on top of the uncropped one
+const CROPPED_TESTS_COUNT: usize = 100;
+/// Fewest measurements per class for a t-test to be taken into account
+const MIN_CLASS_MEASUREMENTS: f64 = 10.0;
+
+/// `Class` is one of the two classes of inputs a `Dudect` run compares.
+///
+/// The usual split is a fixed input for `Class::Left` against random inputs for `Class::Right`, or two fixed inputs
+/// exercising opposite paths of the code under test.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Class {
+    Left,
+    Right,
+}
+
+/// `Dudect` is a statistical timing harness in the spirit of dudect, which checks that an operation takes as long on
+/// two classes of inputs.
+///
+/// Each measurement picks a class at random, prepares an input of that class, then times a batch of calls to the
+/// operation on it. The timings of both classes are then compared with Welch's t-test, once as they are and once per
+/// cropping percentile, which discards the slowest measurements, mostly disturbed by interrupts and context switches.
+///
+/// The harness only catches differences it can measure: run it on an optimized build, with enough measurements, and
+/// with batches long enough for the clock resolution.
+///
+/// ## Examples
+///
+/// A comparison stopping at the first differing byte is told apart from a full one. The outcome hangs on the load of
+/// the machine, hence the example is only compiled:
+///
+///```rust,no_run
+/// # use rs_constant_time::{Class, Dudect, LEAKAGE_THRESHOLD};
+/// let secret = [0x2Au8; 4096];
+/// let mut forgery = secret;
+/// forgery[0] ^= 0x01;
+///
+/// let report = Dudect::new(20_000).with_batch_size(4).run(
+///     |class| match class {
+///         Class::Left => &secret,
+///         Class::Right => &forgery,
+///     },
+///     |guess| secret == **guess,
+/// );
+/// assert!(report.max_t() > LEAKAGE_THRESHOLD);
+///```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Dudect {
+    measurements: usize,
+    batch_size: usize,
+    seed: u64,
+}
+
+impl Dudect {
+    /// Creates a harness taking `measurements` timings, split at random between both classes.
+    pub fn new(measurements: usize) -> Self {
+        Self {
+            measurements,
+            batch_size: 1,
+            seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// Sets the count of calls to the operation timed together, at least 1.
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            ..self
+        }
+    }
+
+    /// Sets the seed of the generator picking the class of each measurement.
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            seed,
+            ..self
+        }
+    }
+
+    /// Times `operation` over inputs built by `prepare` for each class, and returns the outcome of the t-tests.
+    ///
+    /// Inputs are all prepared before the first timing, so that only `operation` is measured.
+    pub fn run<I, R, P, O>(&self, mut prepare: P, mut operation: O) -> DudectReport
+    where
+        P: FnMut(Class) -> I,
+        O: FnMut(&I) -> R,
+    {
+        let mut state = self.seed | 1;
+        let classes: Vec<Class> = (0..self.measurements)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                if state & 1 == 0 {
+                    Class::Left
+                } else {
+                    Class::Right
+                }
+            })
+            .collect();
+        let inputs: Vec<I> = classes.iter().map(|&class| prepare(class)).collect();
+
+        let timings: Vec<f64> = inputs
+            .iter()
+            .map(|input| {
+                let start = Instant::now();
+                for _ in 0..self.batch_size {
+                    black_box(operation(black_box(input)));
+                }
+                start.elapsed().as_nanos() as f64
+            })
+            .collect();
+
+        DudectReport::new(&classes, &timings)
+    }
+}
+
+/// `DudectReport` is the outcome of a `Dudect` run: the largest absolute t statistic among its t-tests.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DudectReport {
+    max_t: f64,
+    measurements: usize,
+}
+
+impl DudectReport {
+    /// Runs the uncropped t-test and the cropped ones over `timings`, each taken on an input of the class at the same
+    /// index of `classes`.
+    pub fn new(classes: &[Class], timings: &[f64]) -> Self {
+        let mut sorted_timings = timings.to_vec();
+        sorted_timings.sort_by(f64::total_cmp);
+
+        // Percentiles get denser towards the top, where the timings disturbed by the system start
+        let thresholds = (0..CROPPED_TESTS_COUNT).map(|i| {
+            let percentile = 1.0 - 0.5f64.powf(10.0 * (i + 1) as f64 / CROPPED_TESTS_COUNT as f64);
+            sorted_timings[((sorted_timings.len() as f64 * percentile) as usize).min(sorted_timings.len() - 1)]
+        });
+
+        let max_t = core::iter::once(f64::INFINITY)
+            .chain(thresholds)
+            .filter_map(|threshold| {
+                let mut t_test = WelchTTest::default();
+                classes
+                    .iter()
+                    .zip(timings)
+                    .filter(|(_, &timing)| timing < threshold)
+                    .for_each(|(&class, &timing)| t_test.push(class, timing));
+                t_test.t()
+            })
+            .map(f64::abs)
+            .fold(0.0, f64::max);
+
+        Self {
+            max_t,
+            measurements: timings.len(),
+        }
+    }
+
+    /// Returns the largest absolute t statistic among the t-tests.
+    pub fn max_t(&self) -> f64 {
+        self.max_t
+    }
+
+    /// Returns the count of timings the t-tests were run on.
+    pub fn measurements(&self) -> usize {
+        self.measurements
+    }
+
+    /// Returns whether no t-test could tell both classes apart, according to `LEAKAGE_THRESHOLD`.
+    pub fn is_constant_time(&self) -> bool {
+        self.max_t <= LEAKAGE_THRESHOLD
+    }
+}
+
+/// Welch's t-test between the two classes, updated online with Welford's algorithm
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct WelchTTest {
+    count: [f64; 2],
+    mean: [f64; 2],
+    squared_deviations: [f64; 2],
+}
+
+impl WelchTTest {
+    pub(crate) fn push(&mut self, class: Class, timing: f64) {
+        let i = class as usize;
+        self.count[i] += 1.0;
+        let delta = timing - self.mean[i];
+        self.mean[i] += delta / self.count[i];
+        self.squared_deviations[i] += delta * (timing - self.mean[i]);
+    }
+
+    /// Returns the t statistic, or `None` if a class has too few measurements
+    pub(crate) fn t(&self) -> Option<f64> {
+        if self.count.iter().any(|&count| count < MIN_CLASS_MEASUREMENTS) {
+            return None;
+        }
+
+        let variances = [0, 1].map(|i| self.squared_deviations[i] / (self.count[i] - 1.0));
+        let standard_error = (variances[0] / self.count[0] + variances[1] / self.count[1]).sqrt();
+        if standard_error == 0.0 {
+            return Some(if self.mean[0] == self.mean[1] {
+                0.0
+            } else {
+                f64::INFINITY
+            });
+        }
+
+        Some((self.mean[0] - self.mean[1]) / standard_error)
+    }
+}
@@ -0,0 +1,64 @@
+//! # Constant Time `rs_constant_time` - Branch-free comparisons and selections
+//!
+//! Comparing a secret value with the built-in `==` operator, or picking one of two values with an `if`, takes a time
+//! that depends on the values themselves: slices are compared until their first differing byte, and branches are
+//! predicted. Measured over many runs, that time tells an attacker where a forged authentication tag starts to differ
+//! from the genuine one, or which bits a secret is made of.
+//!
+//! This crate provides the `Choice` type, a boolean kept as a `0` or `1` byte that is never branched on, and the traits
+//! built upon it:
+//!
+//! - `ConstantTimeEq` compares two values, reading all of their content.
+//! - `ConditionallySelectable` picks, assigns or swaps values through bit masks.
+//! - `ConditionallyNegatable` negates a value through bit masks.
+//!
+//! They are implemented for the unsigned and signed integers, slices and arrays. `NBitWord` and `ByteArrayWrapper`
+//! implement them in their own crates.
+//!
+//! ## Usage
+//!
+//! A `Choice` is only turned into a `bool` once the outcome is meant to be public, such as whether a tag is accepted.
+//!
+//! ### Example
+//!
+//! ```rust
+//! # use rs_constant_time::{Choice, ConditionallySelectable, ConstantTimeEq};
+//! let secret = [0x2Au8; 16];
+//! let guess = [0x2Au8; 16];
+//!
+//! let is_equal: Choice = secret.ct_eq(&guess);
+//! let outcome = u32::conditional_select(&0xBAD, &0x600D, is_equal);
+//! assert_eq!(outcome, 0x600D);
+//! ```
+//!
+//! ## Timing Harness
+//!
+//! With the `std` feature, `Dudect` runs a statistical timing test in the spirit of dudect, which measures an operation
+//! over two classes of inputs and checks with Welch's t-test that their timings cannot be told apart. It is meant to be
+//! run locally, on an optimized build, to catch timing regressions.
+//!
+//! ## Use Cases
+//!
+//! - Verifying authentication tags and password hashes.
+//! - Selecting between secret values in the arithmetic of public-key algorithms.
+//!
+
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use crate::choice::Choice;
+pub use crate::conditionally_selectable::{ConditionallyNegatable, ConditionallySelectable};
+pub use crate::constant_time_eq::ConstantTimeEq;
+#[cfg(feature = "std")]
+pub use crate::dudect::{Class, Dudect, DudectReport, LEAKAGE_THRESHOLD};
+
+mod choice;
+mod conditionally_selectable;
+mod constant_time_eq;
+#[cfg(feature = "std")]
+mod dudect;
+
+#[cfg(test)]
+mod unit_tests;
@@ -0,0 +1,131 @@
+use crate::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+#[test]
+fn assert_choice_operators_match_bool_ones() {
+    for a in [0u8, 1] {
+        for b in [0u8, 1] {
+            let (choice_a, choice_b) = (Choice::from(a), Choice::from(b));
+            let (bool_a, bool_b) = (a == 1, b == 1);
+
+            assert_eq!(bool::from(choice_a & choice_b), bool_a & bool_b);
+            assert_eq!(bool::from(choice_a | choice_b), bool_a | bool_b);
+            assert_eq!(bool::from(choice_a ^ choice_b), bool_a ^ bool_b);
+            assert_eq!(bool::from(choice_a.ct_eq(&choice_b)), bool_a == bool_b);
+        }
+        assert_eq!((!Choice::from(a)).unwrap_u8(), a ^ 1);
+    }
+}
+
+#[test]
+fn assert_integers_ct_eq_on_edge_values() {
+    let values = [0u64, 1, 2, 0x7FFF_FFFF_FFFF_FFFF, 0x8000_0000_0000_0000, u64::MAX - 1, u64::MAX];
+    for a in values {
+        for b in values {
+            assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            assert_eq!(bool::from(a.ct_ne(&b)), a != b);
+            assert_eq!(bool::from((a as i64).ct_eq(&(b as i64))), a == b);
+            assert_eq!(bool::from((a as u8).ct_eq(&(b as u8))), a as u8 == b as u8);
+        }
+    }
+}
+
+#[test]
+fn assert_slices_ct_eq_compare_lengths_and_every_element() {
+    let tag = [0x5Au8; 32];
+    for i in 0..tag.len() {
+        let mut tampered_tag = tag;
+        tampered_tag[i] ^= 0x80;
+        assert!(bool::from(tag.ct_ne(&tampered_tag)));
+    }
+
+    assert!(bool::from(tag.ct_eq(&tag)));
+    assert!(bool::from(tag[..].ct_ne(&tag[..31])));
+    assert!(bool::from([0u8; 0][..].ct_eq(&[])));
+}
+
+#[test]
+fn assert_conditional_select_assign_and_swap() {
+    assert_eq!(u32::conditional_select(&0xAAAA_AAAA, &0x5555_5555, Choice::from(0)), 0xAAAA_AAAA);
+    assert_eq!(u32::conditional_select(&0xAAAA_AAAA, &0x5555_5555, Choice::from(1)), 0x5555_5555);
+    assert_eq!(i8::conditional_select(&-1, &127, Choice::from(1)), 127);
+
+    let mut a = [1u128, 2, 3];
+    a.conditional_assign(&[4, 5, 6], Choice::from(0));
+    assert_eq!(a, [1, 2, 3]);
+    a.conditional_assign(&[4, 5, 6], Choice::from(1));
+    assert_eq!(a, [4, 5, 6]);
+
+    let (mut x, mut y) = (7usize, 9usize);
+    usize::conditional_swap(&mut x, &mut y, Choice::from(0));
+    assert_eq!((x, y), (7, 9));
+    usize::conditional_swap(&mut x, &mut y, Choice::from(1));
+    assert_eq!((x, y), (9, 7));
+}
+
+#[test]
+fn assert_conditional_negate_of_signed_integers() {
+    for value in [0i32, 1, -1, i32::MAX, i32::MIN + 1] {
+        let mut negated = value;
+        negated.conditional_negate(Choice::from(1));
+        assert_eq!(negated, -value);
+
+        let mut kept = value;
+        kept.conditional_negate(Choice::from(0));
+        assert_eq!(kept, value);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn assert_welch_t_test_separates_distinct_distributions() {
+    use crate::dudect::WelchTTest;
+    use crate::{Class, DudectReport};
+
+    let mut t_test = WelchTTest::default();
+    for i in 0..100 {
+        t_test.push(Class::Left, 100.0 + (i % 7) as f64);
+        t_test.push(Class::Right, 100.0 + (i % 7) as f64);
+    }
+    assert_eq!(t_test.t(), Some(0.0));
+
+    for i in 0..100 {
+        t_test.push(Class::Right, 150.0 + (i % 7) as f64);
+    }
+    assert!(t_test.t().unwrap() < -10.0);
+
+    let classes: std::vec::Vec<Class> = (0..1000)
+        .map(|i| {
+            if i % 2 == 0 {
+                Class::Left
+            } else {
+                Class::Right
+            }
+        })
+        .collect();
+    let timings: std::vec::Vec<f64> = (0..1000).map(|i| 100.0 + (i / 2 % 13) as f64).collect();
+    assert!(DudectReport::new(&classes, &timings).is_constant_time());
+
+    let timings: std::vec::Vec<f64> = (0..1000).map(|i| 100.0 + (i % 2 * 20) as f64 + (i / 2 % 13) as f64).collect();
+    assert!(!DudectReport::new(&classes, &timings).is_constant_time());
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[ignore = "timing measurement depending on the load of the machine, run with `cargo test --release --features std -- --ignored`"]
+fn assert_dudect_tells_early_exit_comparison_apart() {
+    use crate::{Class, ConstantTimeEq, Dudect, LEAKAGE_THRESHOLD};
+
+    let secret = [0x2Au8; 4096];
+    let mut forgery = secret;
+    forgery[0] ^= 0x01;
+    let input = |class| match class {
+        Class::Left => &secret,
+        Class::Right => &forgery,
+    };
+
+    let report = Dudect::new(20_000).with_batch_size(4).run(input, |guess| secret == **guess);
+    assert!(report.max_t() > LEAKAGE_THRESHOLD);
+
+    let report = Dudect::new(20_000).with_batch_size(4).run(input, |guess| bool::from(secret[..].ct_eq(&guess[..])));
+    assert!(report.is_constant_time());
+}
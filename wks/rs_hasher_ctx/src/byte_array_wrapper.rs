@@ -1,16 +1,22 @@
 use core::{
     array::IntoIter,
     fmt::{Formatter, LowerHex, UpperHex},
+    hash::{Hash, Hasher},
     ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Index, Not, Sub},
     ops::{Range, RangeFrom, RangeFull, RangeTo},
 };
-use rs_internal_state::{ExtendedOutputFunction, KeccakSponge};
+use rs_constant_time::{Choice, ConditionallySelectable, ConstantTimeEq};
+use rs_internal_state::{ExtendedOutputFunction, KeccakSponge, Zeroize};
 use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
 
 pub const LOWER_HEX_ERR: &str = "Error trying to format lower hex string";
 pub const UPPER_HEX_ERR: &str = "Error trying to format upper hex string";
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// `ByteArrayWrapper<const LEN: usize>` holds the `LEN` bytes of a digest or a tag.
+///
+/// Its equality is checked in constant time, through `ConstantTimeEq`, so that comparing a computed tag with a received
+/// one does not tell where they differ.
+#[derive(Clone, Debug, Eq)]
 pub struct ByteArrayWrapper<const LEN: usize>([u8; LEN]);
 
 impl<const LEN: usize> ByteArrayWrapper<LEN> {
@@ -26,6 +32,18 @@ impl<const LEN: usize> AsRef<[u8]> for ByteArrayWrapper<LEN> {
     }
 }
 
+impl<const LEN: usize> ConditionallySelectable for ByteArrayWrapper<LEN> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(<[u8; LEN]>::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<const LEN: usize> ConstantTimeEq for ByteArrayWrapper<LEN> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 impl<const LEN: usize> Default for ByteArrayWrapper<LEN> {
     fn default() -> Self {
         [0u8; LEN].into()
@@ -38,8 +56,8 @@ impl<const LEN: usize> From<[u8; LEN]> for ByteArrayWrapper<LEN> {
     }
 }
 
-impl<T, const RATE: usize, const OUTPUT_SIZE: usize> From<KeccakSponge<T, RATE, OUTPUT_SIZE>>
-    for ByteArrayWrapper<OUTPUT_SIZE>
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize, const ROUNDS: usize>
+    From<KeccakSponge<T, RATE, OUTPUT_SIZE, ROUNDS>> for ByteArrayWrapper<OUTPUT_SIZE>
 where
     T: BitAnd
         + BitAndAssign
@@ -52,7 +70,7 @@ where
     NBitWord<T>: From<u64> + LittleEndianBytes + Rotate + TSize<T>,
     u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
 {
-    fn from(mut value: KeccakSponge<T, RATE, OUTPUT_SIZE>) -> Self {
+    fn from(mut value: KeccakSponge<T, RATE, OUTPUT_SIZE, ROUNDS>) -> Self {
         value.squeeze().into()
     }
 }
@@ -63,6 +81,12 @@ impl<const LEN: usize> From<ByteArrayWrapper<LEN>> for [u8; LEN] {
     }
 }
 
+impl<const LEN: usize> Hash for ByteArrayWrapper<LEN> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
 impl<const LEN: usize> Index<usize> for ByteArrayWrapper<LEN> {
     type Output = u8;
 
@@ -122,9 +146,15 @@ impl<const LEN: usize> LowerHex for ByteArrayWrapper<LEN> {
     }
 }
 
+impl<const LEN: usize> PartialEq for ByteArrayWrapper<LEN> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 impl<const LEN: usize> PartialEq<[u8; LEN]> for ByteArrayWrapper<LEN> {
     fn eq(&self, other: &[u8; LEN]) -> bool {
-        self.0 == *other
+        self.0.ct_eq(other).into()
     }
 }
 
@@ -137,3 +167,9 @@ impl<const LEN: usize> UpperHex for ByteArrayWrapper<LEN> {
         UpperHex::fmt(&self.0[LEN - 1], f_n_minus_one)
     }
 }
+
+impl<const LEN: usize> Zeroize for ByteArrayWrapper<LEN> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
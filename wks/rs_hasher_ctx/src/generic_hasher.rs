@@ -1,6 +1,7 @@
 use crate::{ByteArrayWrapper, HasherContext};
 use core::hash::Hasher;
 use rs_internal_hasher::{DigestThroughPad, HashAlgorithm};
+use rs_internal_state::Zeroize;
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct GenericHasher<H: Default + HashAlgorithm, const OUTPUT_LEN: usize> {
@@ -33,3 +34,10 @@ where
         self.state.clone()
     }
 }
+
+impl<H: Default + HashAlgorithm, const OUTPUT_LEN: usize> Zeroize for GenericHasher<H, OUTPUT_LEN> {
+    fn zeroize(&mut self) {
+        self.padding.zeroize();
+        self.state.zeroize();
+    }
+}
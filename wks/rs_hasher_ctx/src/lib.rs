@@ -2,9 +2,15 @@
 
 pub use byte_array_wrapper::ByteArrayWrapper;
 pub use generic_hasher::GenericHasher;
+pub use mac::{Mac, MacError};
+pub use midstate::{Midstate, MidstateError, MIDSTATE_VERSION};
+pub use rs_constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+pub use rs_internal_state::Zeroize;
 
 mod byte_array_wrapper;
 mod generic_hasher;
+mod mac;
+mod midstate;
 
 /// Overloads the finish Hasher method for a version that mutates itself
 pub trait HasherContext<const OUTPUT_LEN: usize> {
@@ -0,0 +1,69 @@
+use crate::ByteArrayWrapper;
+use core::fmt::{Display, Formatter};
+use rs_constant_time::ConstantTimeEq;
+
+/// `MacError` lists the reasons for which `Mac::verify` rejects a tag.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MacError {
+    /// The tag is shorter than `Mac::MIN_TAG_LEN` or longer than the full tag
+    TagLength,
+    /// The tag does not authenticate the message
+    Mismatch,
+}
+
+impl Display for MacError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MacError::TagLength => f.write_str("invalid authentication tag length"),
+            MacError::Mismatch => f.write_str("authentication tag mismatch"),
+        }
+    }
+}
+
+/// `Mac` is the interface shared by message authentication codes, whose full tags are `OUTPUT_LEN` bytes long.
+///
+/// Messages are fed through `Mac::update`, and the tag is obtained through `Mac::finalize`, which consumes the context,
+/// or `Mac::finalize_reset`, which brings it back to its freshly keyed state. `Mac::verify` checks a received tag in
+/// constant time, and accepts tags truncated down to `Mac::MIN_TAG_LEN` bytes, of which only the leftmost bytes of the
+/// full tag are compared.
+pub trait Mac<const OUTPUT_LEN: usize>: Sized {
+    /// Shortest truncated tag accepted by `Mac::verify`. It defaults to the policy of RFC 2104, which asks for at least
+    /// half the full tag length and no less than 80 bits.
+    const MIN_TAG_LEN: usize = if OUTPUT_LEN < 10 {
+        OUTPUT_LEN
+    } else if OUTPUT_LEN / 2 < 10 {
+        10
+    } else {
+        OUTPUT_LEN / 2
+    };
+
+    /// Feeds `bytes` to the message being authenticated.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the full tag of the message.
+    fn finalize(self) -> ByteArrayWrapper<OUTPUT_LEN>;
+
+    /// Returns the full tag of the message and resets the context, which can then authenticate a new message with the
+    /// same key.
+    fn finalize_reset(&mut self) -> ByteArrayWrapper<OUTPUT_LEN>;
+
+    /// Discards the message fed so far, keeping the key.
+    fn reset(&mut self);
+
+    /// Checks `tag` against the tag of the message, in constant time with respect to their content.
+    ///
+    /// `tag` may be truncated, as long as it is at least `Mac::MIN_TAG_LEN` bytes long, in which case it is compared
+    /// with the leftmost bytes of the full tag.
+    fn verify(self, tag: &[u8]) -> Result<(), MacError> {
+        if tag.len() < Self::MIN_TAG_LEN || tag.len() > OUTPUT_LEN {
+            return Err(MacError::TagLength);
+        }
+
+        let expected_tag = self.finalize();
+        if !bool::from(expected_tag[..tag.len()].ct_eq(tag)) {
+            return Err(MacError::Mismatch);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use crate::GenericHasher;
+use core::fmt::{Display, Formatter};
+use rs_internal_hasher::{HashMidstate, PadMidstate};
+
+/// Leading bytes of every exported midstate
+const MAGIC: [u8; 4] = *b"RSMS";
+/// Version of the midstate layout written by `Midstate::export_midstate`
+pub const MIDSTATE_VERSION: u8 = 1;
+/// Byte length of the bytes count field
+const BYTES_COUNT_LEN: usize = 16;
+/// Byte length of the trailing CRC-32 checksum
+const CHECKSUM_LEN: usize = 4;
+/// Longest algorithm tag, whose length is stored in a single byte
+const MAX_TAG_LEN: usize = u8::MAX as usize;
+/// Longest header, made of the magic bytes, the version, the tag and the algorithm parameters
+const MAX_HEADER_LEN: usize = 4 + 2 + MAX_TAG_LEN + 2 + 2 + 1 + 2;
+
+/// `MidstateError` lists the reasons for which a midstate cannot be exported or imported.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MidstateError {
+    /// The output buffer is shorter than `Midstate::midstate_len`
+    BufferTooSmall,
+    /// The blob does not follow the midstate layout, e.g. it is truncated or does not start with the magic bytes
+    Malformed,
+    /// The blob was written with a layout version this library does not know about
+    UnsupportedVersion(u8),
+    /// The blob checksum does not match its content
+    Corrupted,
+    /// The blob holds the state of another algorithm, or of the same one with other parameters
+    AlgorithmMismatch,
+}
+
+impl Display for MidstateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MidstateError::BufferTooSmall => f.write_str("midstate buffer too small"),
+            MidstateError::Malformed => f.write_str("malformed midstate"),
+            MidstateError::UnsupportedVersion(version) => write!(f, "unsupported midstate version {version}"),
+            MidstateError::Corrupted => f.write_str("midstate checksum mismatch"),
+            MidstateError::AlgorithmMismatch => f.write_str("midstate belongs to another algorithm"),
+        }
+    }
+}
+
+/// `Midstate` serializes the full in-progress state of a hasher, so that a long computation can be saved and resumed,
+/// possibly across processes and restarts.
+///
+/// The exported blob is laid out as follows, with integers in big-endian order:
+///
+/// | Field        | Size                           | Content                                          |
+/// |--------------|--------------------------------|--------------------------------------------------|
+/// | Magic        | 4                              | `RSMS`                                           |
+/// | Version      | 1                              | `MIDSTATE_VERSION`                               |
+/// | Tag length   | 1                              | Length of the algorithm name                     |
+/// | Tag          | Tag length                     | Algorithm name, e.g. `SHA-256`                   |
+/// | Output size  | 2                              | Digest length, in bytes                          |
+/// | Block size   | 2                              | Block length, in bytes                           |
+/// | Delimiter    | 1                              | Delimiter byte of the padding rule               |
+/// | State length | 2                              | Byte length of the chaining values               |
+/// | State        | State length                   | Chaining values                                  |
+/// | Bytes count  | 16                             | Count of bytes written so far                    |
+/// | Buffered     | Bytes count modulo block size  | Bytes written since the last hashed block        |
+/// | Checksum     | 4                              | CRC-32 of all the previous fields                |
+///
+/// Importing checks the version, the checksum and that every parameter of the tag matches the receiving hasher, so a
+/// blob is never silently resumed by the wrong algorithm.
+pub trait Midstate: Sized {
+    /// Returns the byte length of the blob `Midstate::export_midstate` would write.
+    fn midstate_len(&self) -> usize;
+    /// Writes the midstate blob at the start of `bytes`, returning its length.
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError>;
+    /// Rebuilds a hasher from a midstate blob, which must span the whole of `bytes`.
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError>;
+}
+
+impl<H, const OUTPUT_LEN: usize> GenericHasher<H, OUTPUT_LEN>
+where
+    H: Default + HashMidstate,
+    H::Padding: PadMidstate,
+{
+    /// Writes the midstate blob at the start of `bytes`, tagged with `tag` instead of `HashMidstate::ALGORITHM`.
+    ///
+    /// Hashers whose finalization depends on more than their `GenericHasher`, such as cSHAKE falling back to SHAKE when
+    /// not customized, use distinct tags to keep their variants apart.
+    pub fn export_tagged_midstate(&self, tag: &str, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        let len = self.tagged_midstate_len(tag);
+        let bytes = bytes.get_mut(..len).ok_or(MidstateError::BufferTooSmall)?;
+
+        let header_len = Self::header_len(tag);
+        Self::write_header(tag, &mut bytes[..header_len]);
+
+        let mut body = Writer(&mut bytes[header_len..]);
+        body.skip(H::MIDSTATE_LEN);
+        body.put(&self.padding.bytes_count().to_be_bytes());
+        body.put(self.padding.buffered());
+        self.state.export_state(&mut bytes[header_len..header_len + H::MIDSTATE_LEN]);
+
+        let checksum = crc32(&bytes[..len - CHECKSUM_LEN]);
+        bytes[len - CHECKSUM_LEN..].clone_from_slice(&checksum.to_be_bytes());
+
+        Ok(len)
+    }
+
+    /// Rebuilds a hasher from a midstate blob written by `GenericHasher::export_tagged_midstate` with the same `tag`.
+    pub fn import_tagged_midstate(tag: &str, bytes: &[u8]) -> Result<Self, MidstateError> {
+        if bytes.len() < MAGIC.len() + 1 + CHECKSUM_LEN || bytes[..MAGIC.len()] != MAGIC {
+            return Err(MidstateError::Malformed);
+        }
+        if bytes[MAGIC.len()] != MIDSTATE_VERSION {
+            return Err(MidstateError::UnsupportedVersion(bytes[MAGIC.len()]));
+        }
+
+        let (content, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+        if crc32(content).to_be_bytes() != checksum {
+            return Err(MidstateError::Corrupted);
+        }
+
+        let header_len = Self::header_len(tag);
+        let mut expected_header = [0u8; MAX_HEADER_LEN];
+        Self::write_header(tag, &mut expected_header[..header_len]);
+        if content.get(..header_len) != Some(&expected_header[..header_len]) {
+            return Err(MidstateError::AlgorithmMismatch);
+        }
+
+        let state_end = header_len + H::MIDSTATE_LEN;
+        let count_end = state_end + BYTES_COUNT_LEN;
+        let count_bytes = content.get(state_end..count_end).ok_or(MidstateError::Malformed)?;
+        let bytes_count = u128::from_be_bytes(count_bytes.try_into().map_err(|_| MidstateError::Malformed)?);
+
+        let block_len = <H::Padding as PadMidstate>::BLOCK_LEN as u128;
+        let buffered = &content[count_end..];
+        if buffered.len() as u128 != bytes_count % block_len {
+            return Err(MidstateError::Malformed);
+        }
+
+        let padding = <H::Padding as PadMidstate>::restore(bytes_count, buffered);
+        if padding.bytes_count() != bytes_count {
+            return Err(MidstateError::Malformed);
+        }
+
+        Ok(Self {
+            padding,
+            state: H::import_state(&content[header_len..state_end]),
+        })
+    }
+
+    /// Returns the byte length of the blob `GenericHasher::export_tagged_midstate` would write for `tag`.
+    pub fn tagged_midstate_len(&self, tag: &str) -> usize {
+        Self::header_len(tag) + H::MIDSTATE_LEN + BYTES_COUNT_LEN + self.padding.buffered().len() + CHECKSUM_LEN
+    }
+
+    fn header_len(tag: &str) -> usize {
+        assert!(tag.len() <= MAX_TAG_LEN, "Midstate tag cannot be longer than {MAX_TAG_LEN} bytes");
+        MAX_HEADER_LEN - MAX_TAG_LEN + tag.len()
+    }
+
+    fn write_header(tag: &str, bytes: &mut [u8]) {
+        let mut header = Writer(bytes);
+        header.put(&MAGIC);
+        header.put(&[MIDSTATE_VERSION, tag.len() as u8]);
+        header.put(tag.as_bytes());
+        header.put(&(OUTPUT_LEN as u16).to_be_bytes());
+        header.put(&(<H::Padding as PadMidstate>::BLOCK_LEN as u16).to_be_bytes());
+        header.put(&[<H::Padding as PadMidstate>::DELIMITER]);
+        header.put(&(H::MIDSTATE_LEN as u16).to_be_bytes());
+    }
+}
+
+impl<H, const OUTPUT_LEN: usize> Midstate for GenericHasher<H, OUTPUT_LEN>
+where
+    H: Default + HashMidstate,
+    H::Padding: PadMidstate,
+{
+    fn midstate_len(&self) -> usize {
+        self.tagged_midstate_len(H::ALGORITHM)
+    }
+
+    fn export_midstate(&self, bytes: &mut [u8]) -> Result<usize, MidstateError> {
+        self.export_tagged_midstate(H::ALGORITHM, bytes)
+    }
+
+    fn import_midstate(bytes: &[u8]) -> Result<Self, MidstateError> {
+        Self::import_tagged_midstate(H::ALGORITHM, bytes)
+    }
+}
+
+/// Sequential writer over a byte slice known to be large enough
+struct Writer<'a>(&'a mut [u8]);
+
+impl Writer<'_> {
+    fn put(&mut self, bytes: &[u8]) {
+        let (head, tail) = core::mem::take(&mut self.0).split_at_mut(bytes.len());
+        head.clone_from_slice(bytes);
+        self.0 = tail;
+    }
+
+    fn skip(&mut self, len: usize) {
+        self.0 = &mut core::mem::take(&mut self.0)[len..];
+    }
+}
+
+/// CRC-32 with the IEEE 802.3 polynomial, as used by zlib and PNG
+fn crc32(bytes: &[u8]) -> u32 {
+    const POLYNOMIAL: u32 = 0xEDB88320;
+
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| (crc >> 1) ^ (POLYNOMIAL & (crc & 1).wrapping_neg()))
+    })
+}
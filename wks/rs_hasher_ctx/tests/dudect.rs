@@ -0,0 +1,48 @@
+//! Statistical timing tests of the constant-time comparisons. They are noisy on shared machines, so they are ignored by
+//! default, and meant to be run locally on an optimized build:
+//!
+//! `cargo test --release -p rs_hasher_ctx --test dudect -- --ignored`
+
+use rs_constant_time::{Class, Dudect};
+use rs_hasher_ctx::ByteArrayWrapper;
+use std::hint::black_box;
+
+const MEASUREMENTS: usize = 200_000;
+const BATCH_SIZE: usize = 16;
+
+#[test]
+#[ignore]
+fn assert_byte_array_wrapper_eq_is_constant_time() {
+    let secret = ByteArrayWrapper::from([0x2Au8; 64]);
+    let mut forgery = [0x2Au8; 64];
+    forgery[0] ^= 0x01;
+    let forgery = ByteArrayWrapper::from(forgery);
+
+    let report = Dudect::new(MEASUREMENTS).with_batch_size(BATCH_SIZE).run(
+        |class| match class {
+            Class::Left => &secret,
+            Class::Right => &forgery,
+        },
+        |guess| black_box(&secret) == *guess,
+    );
+
+    assert!(report.is_constant_time(), "max |t| = {}", report.max_t());
+}
+
+#[test]
+#[ignore]
+fn assert_dudect_detects_short_circuit_comparison() {
+    let secret = [0x2Au8; 4096];
+    let mut forgery = secret;
+    forgery[0] ^= 0x01;
+
+    let report = Dudect::new(MEASUREMENTS).with_batch_size(BATCH_SIZE).run(
+        |class| match class {
+            Class::Left => &secret,
+            Class::Right => &forgery,
+        },
+        |guess| black_box(&secret) == *guess,
+    );
+
+    assert!(!report.is_constant_time(), "max |t| = {}", report.max_t());
+}
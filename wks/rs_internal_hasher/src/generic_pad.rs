@@ -1,5 +1,6 @@
-use crate::{BigEndianBytes, BytePad, DigestThroughPad, HashAlgorithm, HasherPadOps, LenPad};
+use crate::{BigEndianBytes, BytePad, DigestThroughPad, HashAlgorithm, HasherPadOps, LenPad, PadMidstate};
 use core::ops::{Add, AddAssign, BitAnd, Index, IndexMut, Mul, Range, RangeFrom, RangeTo, Rem};
+use rs_internal_state::Zeroize;
 
 /// * `DELIMITER`: The delimiter byte used in the padding rule, which is unique to each specific application
 ///   of the sponge construction or finishing pad, when processing the SHA-1 family of hash algorithms.
@@ -106,7 +107,7 @@ where
 
             self.pad[lw..lw + left].clone_from_slice(&bytes[..left]);
 
-            if (lw + left) % self.pad.len() != 0 {
+            if !(lw + left).is_multiple_of(self.pad.len()) {
                 return;
             }
 
@@ -215,6 +216,35 @@ where
     }
 }
 
+impl<S, const LEN: usize, const DELIMITER: u8> PadMidstate for GenericPad<S, LEN, DELIMITER>
+where
+    S: AddAssign<usize> + Copy + BitAnd<Output = usize> + From<u128> + From<usize> + Rem<Output = usize>,
+    u128: From<S>,
+{
+    const BLOCK_LEN: usize = LEN;
+    const DELIMITER: u8 = DELIMITER;
+
+    fn bytes_count(&self) -> u128 {
+        u128::from(self.size)
+    }
+
+    fn buffered(&self) -> &[u8] {
+        &self.pad[..(self.bytes_count() % LEN as u128) as usize]
+    }
+
+    fn restore(bytes_count: u128, buffered: &[u8]) -> Self {
+        assert_eq!(buffered.len() as u128, bytes_count % LEN as u128, "Buffered bytes do not match the bytes count");
+
+        let mut pad = [0u8; LEN];
+        pad[..buffered.len()].clone_from_slice(buffered);
+
+        Self {
+            size: S::from(bytes_count),
+            pad,
+        }
+    }
+}
+
 impl<S, const LEN: usize, const DELIMITER: u8> PartialEq<[u8; LEN]> for GenericPad<S, LEN, DELIMITER>
 where
     S: AddAssign<usize> + Copy + BitAnd<Output = usize> + From<usize> + Rem<Output = usize>,
@@ -223,3 +253,14 @@ where
         self.pad == *other
     }
 }
+
+/// Wipes the buffered message bytes, and resets the bytes count, which is not secret.
+impl<S, const LEN: usize, const DELIMITER: u8> Zeroize for GenericPad<S, LEN, DELIMITER>
+where
+    S: AddAssign<usize> + Copy + BitAnd<Output = usize> + From<usize> + Rem<Output = usize>,
+{
+    fn zeroize(&mut self) {
+        self.pad.zeroize();
+        self.size = S::from(0);
+    }
+}
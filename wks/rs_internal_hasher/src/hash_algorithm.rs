@@ -2,9 +2,9 @@ use crate::{BytePad, DigestThroughPad, LenPad};
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::ops::{Index, IndexMut, RangeTo};
-use rs_internal_state::BytesLen;
+use rs_internal_state::{BytesLen, Zeroize};
 
-pub trait HashAlgorithm: BytesLen + Clone {
+pub trait HashAlgorithm: BytesLen + Clone + Zeroize {
     type Padding: AsRef<[u8]>
         + AsMut<[u8]>
         + BytePad
@@ -17,7 +17,8 @@ pub trait HashAlgorithm: BytesLen + Clone {
         + LenPad
         + Index<usize, Output = u8>
         + IndexMut<usize>
-        + Index<RangeTo<usize>, Output = [u8]>;
+        + Index<RangeTo<usize>, Output = [u8]>
+        + Zeroize;
     type Output: AsRef<[u8]> + Index<usize, Output = u8>;
 
     fn hash_block(&mut self, bytes: &[u8]);
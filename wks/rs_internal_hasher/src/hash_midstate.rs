@@ -0,0 +1,15 @@
+use crate::HashAlgorithm;
+
+/// `HashMidstate` gives access to the raw chaining values of a hash algorithm, so that an in-progress computation can
+/// be saved and resumed later on, possibly by another process.
+pub trait HashMidstate: HashAlgorithm {
+    /// Name tagging the algorithm, e.g. `"SHA-256"`
+    const ALGORITHM: &'static str;
+    /// Byte length of the serialized state
+    const MIDSTATE_LEN: usize;
+
+    /// Serializes the state into `bytes`, which is exactly `MIDSTATE_LEN` bytes long.
+    fn export_state(&self, bytes: &mut [u8]);
+    /// Deserializes a state written by `export_state` from `bytes`, which is exactly `MIDSTATE_LEN` bytes long.
+    fn import_state(bytes: &[u8]) -> Self;
+}
@@ -52,6 +52,12 @@ impl From<usize> for KeccakU128Size {
     }
 }
 
+impl From<KeccakU128Size> for u128 {
+    fn from(value: KeccakU128Size) -> Self {
+        value.0
+    }
+}
+
 impl Mul<u32> for KeccakU128Size {
     type Output = Self;
 
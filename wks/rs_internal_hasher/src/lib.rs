@@ -2,8 +2,9 @@
 
 pub use crate::{
     be_bytes::BigEndianBytes, byte_pad::BytePad, digest_through_pad::DigestThroughPad, generic_pad::GenericPad,
-    hash_algorithm::HashAlgorithm, hasher_pad_ops::HasherPadOps, keccak_u128_size::KeccakU128Size, len_pad::LenPad,
-    u128_size::U128Size, u64_size::U64Size,
+    hash_algorithm::HashAlgorithm, hash_midstate::HashMidstate, hasher_pad_ops::HasherPadOps,
+    keccak_u128_size::KeccakU128Size, len_pad::LenPad, pad_midstate::PadMidstate, u128_size::U128Size,
+    u64_size::U64Size,
 };
 
 mod be_bytes;
@@ -11,9 +12,11 @@ mod byte_pad;
 mod digest_through_pad;
 mod generic_pad;
 mod hash_algorithm;
+mod hash_midstate;
 mod hasher_pad_ops;
 mod keccak_u128_size;
 mod len_pad;
+mod pad_midstate;
 mod u128_size;
 mod u64_size;
 
@@ -0,0 +1,19 @@
+/// `PadMidstate` exposes what a padding buffer holds between two blocks: the count of bytes written so far, of which
+/// the trailing ones not yet hashed are still buffered.
+pub trait PadMidstate: Sized {
+    /// Block length, in bytes
+    const BLOCK_LEN: usize;
+    /// Delimiter byte of the padding rule
+    const DELIMITER: u8;
+
+    /// Returns the count of bytes written so far.
+    fn bytes_count(&self) -> u128;
+    /// Returns the bytes written since the last hashed block.
+    fn buffered(&self) -> &[u8];
+    /// Rebuilds a padding buffer from a bytes count and the bytes written since the last hashed block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffered` is not `bytes_count % BLOCK_LEN` bytes long.
+    fn restore(bytes_count: u128, buffered: &[u8]) -> Self;
+}
@@ -52,6 +52,12 @@ impl From<usize> for U128Size {
     }
 }
 
+impl From<U128Size> for u128 {
+    fn from(value: U128Size) -> Self {
+        value.0
+    }
+}
+
 impl Mul<u32> for U128Size {
     type Output = Self;
 
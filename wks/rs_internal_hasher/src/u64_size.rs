@@ -60,6 +60,12 @@ impl From<usize> for U64Size {
     }
 }
 
+impl From<U64Size> for u128 {
+    fn from(value: U64Size) -> Self {
+        value.0 as u128
+    }
+}
+
 impl Mul<u32> for U64Size {
     type Output = Self;
 
@@ -0,0 +1,99 @@
+use crate::keccak::state::{KeccakState, KeccakStateIter, KeccakStateIterMut};
+use crate::keccak::{HEIGHT, KECCAK_F_ROUNDS, WIDTH};
+use crate::Zeroize;
+use core::mem::size_of;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
+use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
+
+const LAST_BIT_PAD: u8 = 0x80;
+
+/// `KeccakDuplex` implements the duplex construction over the Keccak permutation, as described in "Duplexing the
+/// sponge" by Bertoni, Daemen, Peeters and Van Assche.
+///
+/// Contrary to a sponge, which absorbs the whole input before squeezing any output, a duplex object interleaves both:
+/// every duplexing call pads its input, absorbs it, applies the permutation and returns up to `RATE` bytes of output,
+/// which depend on all the inputs given so far. This is the building block of authenticated encryption modes such as
+/// SpongeWrap, as well as of the Keyak and Ketje families.
+///
+/// It works over any of the Keccak-f widths: `u8` for Keccak-f\[200\], `u16` for Keccak-f\[400\], `u32` for
+/// Keccak-f\[800\] and `u64` for Keccak-f\[1600\]. `RATE` must be a multiple of the lane size and smaller than the
+/// state. `ROUNDS` sets how many rounds of the permutation are applied per call. It defaults to `KECCAK_F_ROUNDS`,
+/// which stands for the full Keccak-f of the lane width, that is 18, 20, 22 or 24 rounds. Lower values select the
+/// round-reduced Keccak-p, as used by Ketje.
+///
+/// # Example
+///
+/// On a fresh duplex object, a call with the SHA-3 domain separation suffix computes a SHA3-256 digest:
+///
+/// ```rust
+/// # use rs_internal_state::KeccakDuplex;
+/// let mut duplex = KeccakDuplex::<u64, 136>::default();
+/// let mut output = [0u8; 32];
+/// duplex.duplexing(b"abc", 0x06, &mut output);
+///
+/// assert_eq!(output[..4], [0x3A, 0x98, 0x5D, 0xA7]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KeccakDuplex<T: Default + Copy, const RATE: usize, const ROUNDS: usize = KECCAK_F_ROUNDS> {
+    state: KeccakState<T>,
+}
+
+impl<T, const RATE: usize, const ROUNDS: usize> KeccakDuplex<T, RATE, ROUNDS>
+where
+    T: BitAnd
+        + BitAndAssign
+        + BitOr<NBitWord<T>, Output = NBitWord<T>>
+        + BitXor<Output = T>
+        + BitXorAssign
+        + Copy
+        + Default
+        + Not<Output = T>,
+    NBitWord<T>: From<u64> + LittleEndianBytes + Rotate + TSize<T>,
+    u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
+{
+    /// Absorbs `input` followed by the bits of `delimited_suffix`, applies the permutation and fills `output` with the
+    /// beginning of the rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The bytes to absorb, at most `RATE - 1` of them so that there is room for the padding.
+    /// * `delimited_suffix` - The trailing bits appended to the input, least significant bit first, followed by a
+    ///   single `1` bit delimiter. `0x01` appends no bits, while `0x02` and `0x03` append a single `0` or `1` bit, which
+    ///   is how frame bits are given. It must lie within `0x01..=0x7F`.
+    /// * `output` - The buffer receiving the output, at most `RATE` bytes long. It may be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the length or range requirements above does not hold, or if `ROUNDS` is neither the default nor
+    /// at most the number of rounds of Keccak-f for the lane width.
+    pub fn duplexing(&mut self, input: &[u8], delimited_suffix: u8, output: &mut [u8]) {
+        const {
+            assert!(RATE > 1 && RATE.is_multiple_of(size_of::<T>()), "Duplex rate must be a multiple of the lane size");
+            assert!(RATE < WIDTH * HEIGHT * size_of::<T>(), "Duplex rate must leave room for a capacity");
+        };
+        assert!(input.len() < RATE, "Duplexing input must leave room for the padding");
+        assert!(output.len() <= RATE, "Duplexing output cannot be larger than the rate");
+        assert!(delimited_suffix != 0 && delimited_suffix < LAST_BIT_PAD, "Delimited suffix must be in 0x01..=0x7F");
+
+        let t_size = size_of::<T>();
+        let mut block = [0u8; RATE];
+        block[..input.len()].clone_from_slice(input);
+        block[input.len()] ^= delimited_suffix;
+        block[RATE - 1] ^= LAST_BIT_PAD;
+
+        for (lane, bytes) in KeccakStateIterMut::new(&mut self.state).zip(block.chunks_exact(t_size)) {
+            *lane ^= NBitWord::<T>::from_le_bytes(bytes);
+        }
+        self.state.apply_rounds(ROUNDS);
+
+        for (chunk, lane) in output.chunks_mut(t_size).zip(KeccakStateIter::new(&self.state)) {
+            chunk.clone_from_slice(&lane.to_le_bytes().as_ref()[..chunk.len()]);
+        }
+    }
+}
+
+impl<T: Copy + Default, const RATE: usize, const ROUNDS: usize> Zeroize for KeccakDuplex<T, RATE, ROUNDS> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
@@ -1,3 +0,0 @@
-pub(crate) trait FromBytes<T> {
-    fn from_bytes(bytes: &[u8]) -> Self;
-}
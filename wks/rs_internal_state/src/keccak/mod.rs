@@ -1,12 +1,14 @@
 use crate::keccak::state::{KeccakState, KeccakStateIter, KeccakStateIterMut};
 use crate::keccak::xof::ExtendedOutputFunction;
+use crate::keccak::xof_reader::KeccakXofReader;
+use crate::Zeroize;
 use alloc::borrow::ToOwned;
 use core::mem::size_of;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
 use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
 
 pub(crate) mod chi;
-mod from_bytes;
+pub(crate) mod duplex;
 pub(crate) mod iota;
 pub(crate) mod pi;
 pub(crate) mod plane;
@@ -14,9 +16,13 @@ pub(crate) mod rho;
 pub(crate) mod state;
 pub(crate) mod theta;
 pub(crate) mod xof;
+pub(crate) mod xof_reader;
 
 pub(crate) const WIDTH: usize = 5;
 pub(crate) const HEIGHT: usize = 5;
+/// Number of rounds of the Keccak-f\[1600\] permutation, which the Keccak constructions also take as their default round
+/// count to mean the full Keccak-f permutation of narrower widths
+pub const KECCAK_F_ROUNDS: usize = 24;
 
 const RC: [u64; 24] = [
     0x0000000000000001,
@@ -56,8 +62,12 @@ const RC: [u64; 24] = [
 ///
 /// The Keccak-f permutation is a family of permutations parameterized by the width of the state.
 /// The most commonly used instance is Keccak-f[1600], with a state width of 1600 bits.
+///
+/// `ROUNDS` sets how many rounds of the permutation are applied, defaulting to `KECCAK_F_ROUNDS`, which stands for all
+/// the rounds of Keccak-f whatever the width. Lower values turn it into the round-reduced Keccak-p permutation, on which
+/// TurboSHAKE and KangarooTwelve are built.
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
-pub struct KeccakSponge<T, const RATE: usize, const OUTPUT_SIZE: usize>
+pub struct KeccakSponge<T, const RATE: usize, const OUTPUT_SIZE: usize, const ROUNDS: usize = KECCAK_F_ROUNDS>
 where
     T: Default + Copy,
 {
@@ -65,7 +75,7 @@ where
     state: KeccakState<T>,
 }
 
-impl<T, const RATE: usize, const OUTPUT_SIZE: usize> KeccakSponge<T, RATE, OUTPUT_SIZE>
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize, const ROUNDS: usize> KeccakSponge<T, RATE, OUTPUT_SIZE, ROUNDS>
 where
     T: BitAnd
         + BitAndAssign
@@ -82,7 +92,7 @@ where
     /// The absorb method takes an input byte slice and processes it through the sponge construction.
     /// It first pads the input using the padding rule, then divides the padded input into blocks of
     /// size `N`. Each block is XORed with the rate portion of the state, followed by the application of
-    /// the Keccak-f permutation
+    /// the Keccak-p permutation with `ROUNDS` rounds
     pub fn absorb(&mut self, input: &[u8]) {
         let lanes_to_fulfill = RATE / (size_of::<T>());
         for (lane, byte) in
@@ -91,11 +101,36 @@ where
             *lane ^= NBitWord::<T>::from_le_bytes(byte);
         }
 
-        self.state.apply_f();
+        self.state.apply_rounds(ROUNDS);
+    }
+
+    /// Byte length of the state, as serialized by `KeccakSponge::export_lanes`
+    pub const LANES_BYTES_LEN: usize = WIDTH * HEIGHT * size_of::<T>();
+
+    /// Serializes every lane of the state, in little-endian order, into `bytes`, which must be `LANES_BYTES_LEN` bytes
+    /// long.
+    pub fn export_lanes(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), Self::LANES_BYTES_LEN, "Keccak lanes buffer has a wrong length");
+
+        for (chunk, lane) in bytes.chunks_exact_mut(size_of::<T>()).zip(KeccakStateIter::new(&self.state)) {
+            chunk.clone_from_slice(lane.to_le_bytes().as_ref());
+        }
+    }
+
+    /// Rebuilds a sponge from lanes serialized by `KeccakSponge::export_lanes`.
+    pub fn import_lanes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::LANES_BYTES_LEN, "Keccak lanes buffer has a wrong length");
+
+        let mut sponge = Self::default();
+        for (lane, chunk) in KeccakStateIterMut::new(&mut sponge.state).zip(bytes.chunks_exact(size_of::<T>())) {
+            *lane = NBitWord::<T>::from_le_bytes(chunk);
+        }
+
+        sponge
     }
 
     fn words_to_take(t_size: usize) -> usize {
-        if RATE % t_size != 0 {
+        if !RATE.is_multiple_of(t_size) {
             1 + RATE / t_size
         } else {
             RATE / t_size
@@ -103,8 +138,8 @@ where
     }
 }
 
-impl<T, const RATE: usize, const OUTPUT_SIZE: usize> ExtendedOutputFunction<OUTPUT_SIZE>
-    for KeccakSponge<T, RATE, OUTPUT_SIZE>
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize, const ROUNDS: usize> ExtendedOutputFunction<OUTPUT_SIZE>
+    for KeccakSponge<T, RATE, OUTPUT_SIZE, ROUNDS>
 where
     T: BitAnd
         + BitAndAssign
@@ -138,7 +173,7 @@ where
             completed_bytes += bytes_in_rate;
 
             if BYTE_COUNT_IN_U64 > completed_bytes {
-                state.apply_f();
+                state.apply_rounds(ROUNDS);
             }
         }
 
@@ -147,7 +182,7 @@ where
 
     fn squeeze(&mut self) -> [u8; OUTPUT_SIZE] {
         let t_size = size_of::<T>();
-        let words_to_tale = KeccakSponge::<T, RATE, OUTPUT_SIZE>::words_to_take(t_size);
+        let words_to_tale = Self::words_to_take(t_size);
         let mut output = [0u8; OUTPUT_SIZE];
         let mut completed_bytes = 0;
 
@@ -163,10 +198,30 @@ where
             completed_bytes += RATE;
 
             if OUTPUT_SIZE > completed_bytes {
-                self.state.apply_f();
+                self.state.apply_rounds(ROUNDS);
             }
         }
 
         output
     }
 }
+
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize, const ROUNDS: usize>
+    From<KeccakSponge<T, RATE, OUTPUT_SIZE, ROUNDS>> for KeccakXofReader<T, RATE, ROUNDS>
+where
+    T: Default + Copy,
+{
+    fn from(value: KeccakSponge<T, RATE, OUTPUT_SIZE, ROUNDS>) -> Self {
+        KeccakXofReader::new(value.state)
+    }
+}
+
+impl<T, const RATE: usize, const OUTPUT_SIZE: usize, const ROUNDS: usize> Zeroize
+    for KeccakSponge<T, RATE, OUTPUT_SIZE, ROUNDS>
+where
+    T: Copy + Default,
+{
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
@@ -1,4 +1,5 @@
 use crate::keccak::WIDTH;
+use crate::Zeroize;
 use core::ops::{Index, IndexMut};
 use core::slice::{Iter, IterMut};
 use rs_n_bit_words::NBitWord;
@@ -39,3 +40,9 @@ impl<'a, T> IntoIterator for &'a mut Plane<T> {
         self.lanes.iter_mut()
     }
 }
+
+impl<T: Copy + Default> Zeroize for Plane<T> {
+    fn zeroize(&mut self) {
+        self.lanes.zeroize();
+    }
+}
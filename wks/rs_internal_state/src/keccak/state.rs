@@ -5,9 +5,11 @@ use crate::{
     keccak::plane::Plane,
     keccak::rho::Rho,
     keccak::theta::Theta,
-    keccak::{HEIGHT, RC, WIDTH},
+    keccak::{HEIGHT, KECCAK_F_ROUNDS, RC, WIDTH},
+    Zeroize,
 };
 use core::iter::Flatten;
+use core::mem::size_of;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
 use core::slice::{Iter, IterMut};
 use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
@@ -108,8 +110,28 @@ where
     NBitWord<T>: From<u64> + LittleEndianBytes + Not<Output = NBitWord<T>> + Rotate + TSize<T>,
     u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
 {
+    /// Number of rounds of the Keccak-f permutation over lanes of `T`, that is `12 + 2l` for lanes of `2^l` bits: 18
+    /// for `u8`, 20 for `u16`, 22 for `u32` and 24 for `u64`.
+    pub const F_ROUNDS: usize = 12 + 2 * (8 * size_of::<T>()).trailing_zeros() as usize;
+
+    /// Applies the Keccak-f permutation, that is, all of its `F_ROUNDS` rounds.
     pub fn apply_f(&mut self) {
-        (0..24).fold(self, |state, i| {
+        self.apply_p(Self::F_ROUNDS);
+    }
+
+    /// Applies the Keccak-p permutation reduced to the given number of rounds.
+    ///
+    /// As defined in FIPS 202, section 3.3, the rounds kept are the last ones of Keccak-f, indexed from
+    /// `F_ROUNDS - rounds` to `F_ROUNDS - 1`. So `apply_p(F_ROUNDS)` is the same as `apply_f`, and over `u64` lanes
+    /// `apply_p(12)` runs the rounds indexed from 12 to 23, as used by TurboSHAKE and KangarooTwelve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rounds` is greater than the `F_ROUNDS` rounds of Keccak-f.
+    pub fn apply_p(&mut self, rounds: usize) {
+        assert!(rounds <= Self::F_ROUNDS, "Keccak-p cannot have more rounds than Keccak-f");
+
+        (Self::F_ROUNDS - rounds..Self::F_ROUNDS).fold(self, |state, i| {
             state.theta();
             state.rho();
             state.pi();
@@ -119,6 +141,16 @@ where
             state
         });
     }
+
+    /// Applies the `ROUNDS` rounds a sponge construction is parameterized with, where the default of
+    /// `KECCAK_F_ROUNDS` stands for the full Keccak-f permutation, whatever the width of the lanes.
+    pub(crate) fn apply_rounds(&mut self, rounds: usize) {
+        if rounds == KECCAK_F_ROUNDS {
+            self.apply_f();
+        } else {
+            self.apply_p(rounds);
+        }
+    }
 }
 
 impl<T> Chi for KeccakState<T>
@@ -272,3 +304,9 @@ where
         });
     }
 }
+
+impl<T: Copy + Default> Zeroize for KeccakState<T> {
+    fn zeroize(&mut self) {
+        self.planes.zeroize();
+    }
+}
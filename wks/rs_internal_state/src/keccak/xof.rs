@@ -3,3 +3,9 @@ pub trait ExtendedOutputFunction<const OUTPUT_SIZE: usize> {
     /// Squeezes the output data from the sponge
     fn squeeze(&mut self) -> [u8; OUTPUT_SIZE];
 }
+
+/// Streams the output of an extendable-output function into caller provided buffers of any size
+pub trait XofReader {
+    /// Fills `output` with the next `output.len()` bytes of the stream, resuming from where the previous call stopped
+    fn read(&mut self, output: &mut [u8]);
+}
@@ -0,0 +1,85 @@
+use crate::keccak::state::{KeccakState, KeccakStateIter};
+use crate::keccak::xof::XofReader;
+use crate::keccak::KECCAK_F_ROUNDS;
+use crate::Zeroize;
+use core::mem::size_of;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
+use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
+
+/// `KeccakXofReader` holds a finalized Keccak state and squeezes it on demand.
+///
+/// Unlike `ExtendedOutputFunction::squeeze`, whose output length is fixed at compile time, the reader can be called
+/// repeatedly with buffers of any size. It keeps track of the position within the rate, so that the concatenation of
+/// all reads is the same byte stream regardless of how it was split between calls. The permutation, with the same
+/// `ROUNDS` as the sponge it comes from, is only applied once the whole rate portion of the state has been consumed.
+///
+/// # Example
+///
+/// ```rust
+/// # use rs_internal_state::{KeccakSponge, KeccakXofReader, XofReader};
+/// let sponge = KeccakSponge::<u64, 168, 0>::default();
+/// let mut reader = KeccakXofReader::from(sponge.clone());
+/// let mut split_reader = KeccakXofReader::from(sponge);
+///
+/// let mut output = [0u8; 200];
+/// let mut split_output = [0u8; 200];
+/// reader.read(&mut output);
+/// split_reader.read(&mut split_output[..7]);
+/// split_reader.read(&mut split_output[7..]);
+///
+/// assert_eq!(output, split_output);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct KeccakXofReader<T: Default + Copy, const RATE: usize, const ROUNDS: usize = KECCAK_F_ROUNDS> {
+    state: KeccakState<T>,
+    offset: usize,
+}
+
+impl<T: Default + Copy, const RATE: usize, const ROUNDS: usize> KeccakXofReader<T, RATE, ROUNDS> {
+    pub(crate) fn new(state: KeccakState<T>) -> Self {
+        Self {
+            state,
+            offset: 0,
+        }
+    }
+}
+
+impl<T, const RATE: usize, const ROUNDS: usize> XofReader for KeccakXofReader<T, RATE, ROUNDS>
+where
+    T: BitAnd
+        + BitAndAssign
+        + BitOr<NBitWord<T>, Output = NBitWord<T>>
+        + BitXor<Output = T>
+        + BitXorAssign
+        + Copy
+        + Default
+        + Not<Output = T>,
+    NBitWord<T>: From<u64> + LittleEndianBytes + Rotate + TSize<T>,
+    u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
+{
+    fn read(&mut self, mut output: &mut [u8]) {
+        let t_size = size_of::<T>();
+
+        while !output.is_empty() {
+            if self.offset == RATE {
+                self.state.apply_rounds(ROUNDS);
+                self.offset = 0;
+            }
+
+            let lane_offset = self.offset % t_size;
+            let lane = KeccakStateIter::new(&self.state).nth(self.offset / t_size).unwrap().to_le_bytes();
+            let available = (t_size - lane_offset).min(RATE - self.offset).min(output.len());
+
+            output[..available].clone_from_slice(&lane.as_ref()[lane_offset..lane_offset + available]);
+            output = &mut output[available..];
+            self.offset += available;
+        }
+    }
+}
+
+impl<T: Copy + Default, const RATE: usize, const ROUNDS: usize> Zeroize for KeccakXofReader<T, RATE, ROUNDS> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.offset.zeroize();
+    }
+}
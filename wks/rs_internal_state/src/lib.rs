@@ -1,21 +1,35 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub use bytes_len::BytesLen;
 pub use d_words::DWords;
-pub use keccak::{state::KeccakState, xof::ExtendedOutputFunction, KeccakSponge};
+pub use keccak::{
+    duplex::KeccakDuplex,
+    state::KeccakState,
+    xof::{ExtendedOutputFunction, XofReader},
+    xof_reader::KeccakXofReader,
+    KeccakSponge, KECCAK_F_ROUNDS,
+};
+pub use multi_buffer::{sha256_batch, sha512_batch, BatchBackend};
 pub use n_bit_states::{
     sha160bits_state::Sha160BitsState, sha256bits_state::Sha256BitsState, sha512bits_state::Sha512BitsState,
     GenericStateHasher,
 };
 pub use rotors::sha160rotor::Sha160Rotor;
+pub use sha_extensions::ShaExtensions;
+pub use zeroize::Zeroize;
 
 mod bytes_len;
 mod d_words;
 mod keccak;
+mod multi_buffer;
 mod n_bit_states;
 mod rotors;
+mod sha_extensions;
+mod zeroize;
 
 #[cfg(test)]
 mod unit_tests;
@@ -0,0 +1,397 @@
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+use crate::{Sha256BitsState, Sha512BitsState};
+use core::ops::{BitAnd, BitOr, BitXor, Shr};
+
+/// Most lanes any backend interleaves
+const MAX_LANES: usize = 8;
+
+/// `BatchBackend` is the instruction set a batch of independent SHA-2 messages is hashed with.
+///
+/// Every backend interleaves the rounds of several messages, one per lane, and yields the same digests as hashing
+/// each message on its own. They differ in the instructions the lanes are processed with, and in how many lanes are
+/// interleaved:
+///
+/// | Backend    | SHA-224/256 lanes            | SHA-384/512 lanes            |
+/// |------------|------------------------------|------------------------------|
+/// | `Portable` | 8, as plain words            | 4, as plain words            |
+/// | `Sse41`    | 4, in one 128-bit register   | 2, in one 128-bit register   |
+/// | `Avx2`     | 8, in one 256-bit register   | 4, in one 256-bit register   |
+///
+/// `Portable` leaves any vectorization to the compiler, while the x86_64 backends run every operation of the rounds on
+/// all of their lanes with a single instruction. As neither SSE4.1 nor AVX2 rotate their lanes, each rotation takes
+/// two shifts and an or.
+///
+/// `Portable` is always available. The x86_64 backends are detected at runtime with the `std` feature, and otherwise
+/// picked up only if the target was compiled with them, e.g. with `-C target-cpu=native`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BatchBackend {
+    Portable,
+    Sse41,
+    Avx2,
+}
+
+impl BatchBackend {
+    /// Returns the widest backend the running CPU supports.
+    pub fn detect() -> Self {
+        [Self::Avx2, Self::Sse41].into_iter().find(|backend| backend.is_supported()).unwrap_or(Self::Portable)
+    }
+
+    /// Returns whether the running CPU supports the backend.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::Portable => true,
+            #[cfg(all(target_arch = "x86_64", feature = "std"))]
+            Self::Sse41 => std::is_x86_feature_detected!("sse4.1"),
+            #[cfg(all(target_arch = "x86_64", feature = "std"))]
+            Self::Avx2 => std::is_x86_feature_detected!("avx2"),
+            #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+            Self::Sse41 => cfg!(target_feature = "sse4.1"),
+            #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+            Self::Avx2 => cfg!(target_feature = "avx2"),
+            #[cfg(not(target_arch = "x86_64"))]
+            Self::Sse41 | Self::Avx2 => false,
+        }
+    }
+}
+
+/// Hashes each of `messages` with SHA-224 or SHA-256 from the `initial` state, and writes its final state to the
+/// element of `states` at the same index.
+///
+/// ## Panics
+///
+/// Panics if `messages` and `states` have different lengths, or if `backend` is not supported by the running CPU.
+pub fn sha256_batch(backend: BatchBackend, initial: &[u32; 8], messages: &[&[u8]], states: &mut [[u32; 8]]) {
+    batch(backend, initial, messages, states)
+}
+
+/// Hashes each of `messages` with SHA-384 or SHA-512 from the `initial` state, and writes its final state to the
+/// element of `states` at the same index.
+///
+/// ## Panics
+///
+/// Panics if `messages` and `states` have different lengths, or if `backend` is not supported by the running CPU.
+pub fn sha512_batch(backend: BatchBackend, initial: &[u64; 8], messages: &[&[u8]], states: &mut [[u64; 8]]) {
+    batch(backend, initial, messages, states)
+}
+
+fn batch<W: Sha2Word>(backend: BatchBackend, initial: &[W; 8], messages: &[&[u8]], states: &mut [[W; 8]]) {
+    assert_eq!(messages.len(), states.len(), "every message needs a state to be written to");
+    assert!(backend.is_supported(), "{backend:?} is not supported by this CPU");
+
+    match backend {
+        BatchBackend::Portable => W::portable(initial, messages, states),
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: the CPU was just checked to support SSE4.1
+        BatchBackend::Sse41 => unsafe { W::sse41(initial, messages, states) },
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: the CPU was just checked to support AVX2
+        BatchBackend::Avx2 => unsafe { W::avx2(initial, messages, states) },
+        #[cfg(not(target_arch = "x86_64"))]
+        BatchBackend::Sse41 | BatchBackend::Avx2 => unreachable!(),
+    }
+}
+
+/// A SHA-2 word, along with the constants of the hash functions built upon it
+trait Sha2Word:
+    'static
+    + Copy
+    + Default
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const BLOCK_LEN: usize;
+    const K: &'static [Self];
+    const ONES: Self;
+    /// Rotations of Σ0 and Σ1, as `TSize::sigma0` and `TSize::sigma1` run them
+    const BIG_SIGMA: [[u32; 3]; 2];
+    /// Rotations and shift of σ0 and σ1, as `TSize::gamma0` and `TSize::gamma1` run them
+    const SMALL_SIGMA: [[u32; 3]; 2];
+
+    fn from_be_slice(bytes: &[u8]) -> Self;
+    fn rotate_right(self, n: u32) -> Self;
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    fn portable(initial: &[Self; 8], messages: &[&[u8]], states: &mut [[Self; 8]]);
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn sse41(initial: &[Self; 8], messages: &[&[u8]], states: &mut [[Self; 8]]);
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn avx2(initial: &[Self; 8], messages: &[&[u8]], states: &mut [[Self; 8]]);
+}
+
+macro_rules! impl_sha2_word {
+    ($word:ty, $k:expr, $big_sigma:expr, $small_sigma:expr, $portable:ty, $sse41:ty, $avx2:ty) => {
+        impl Sha2Word for $word {
+            const BLOCK_LEN: usize = 16 * core::mem::size_of::<Self>();
+            const K: &'static [Self] = &$k;
+            const ONES: Self = Self::MAX;
+            const BIG_SIGMA: [[u32; 3]; 2] = $big_sigma;
+            const SMALL_SIGMA: [[u32; 3]; 2] = $small_sigma;
+
+            fn from_be_slice(bytes: &[u8]) -> Self {
+                Self::from_be_bytes(bytes.try_into().unwrap())
+            }
+
+            #[inline(always)]
+            fn rotate_right(self, n: u32) -> Self {
+                <$word>::rotate_right(self, n)
+            }
+
+            #[inline(always)]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$word>::wrapping_add(self, rhs)
+            }
+
+            fn portable(initial: &[Self; 8], messages: &[&[u8]], states: &mut [[Self; 8]]) {
+                lanes::<$portable>(initial, messages, states)
+            }
+
+            #[cfg(target_arch = "x86_64")]
+            unsafe fn sse41(initial: &[Self; 8], messages: &[&[u8]], states: &mut [[Self; 8]]) {
+                x86_64::sse41::<$sse41>(initial, messages, states)
+            }
+
+            #[cfg(target_arch = "x86_64")]
+            unsafe fn avx2(initial: &[Self; 8], messages: &[&[u8]], states: &mut [[Self; 8]]) {
+                x86_64::avx2::<$avx2>(initial, messages, states)
+            }
+        }
+    };
+}
+
+impl_sha2_word!(
+    u32,
+    Sha256BitsState::K,
+    [[2, 13, 22], [6, 11, 25]],
+    [[7, 18, 3], [17, 19, 10]],
+    Portable<u32, 8>,
+    x86_64::Sse41U32,
+    x86_64::Avx2U32
+);
+impl_sha2_word!(
+    u64,
+    Sha512BitsState::K,
+    [[28, 34, 39], [14, 18, 41]],
+    [[1, 8, 7], [19, 61, 6]],
+    Portable<u64, 4>,
+    x86_64::Sse41U64,
+    x86_64::Avx2U64
+);
+
+/// `Lanes` holds one SHA-2 word of each message hashed side by side, and runs the same operation on all of them at
+/// once.
+///
+/// The functions of the rounds are those `TSize` gives `NBitWord`, applied to every lane.
+trait Lanes: Copy + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self> {
+    type Word: Sha2Word;
+    const COUNT: usize;
+
+    fn splat(word: Self::Word) -> Self;
+    /// Gathers the first `COUNT` words, one per lane.
+    fn load(words: &[Self::Word; MAX_LANES]) -> Self;
+    /// Scatters the lanes to the first `COUNT` words.
+    fn store(self, words: &mut [Self::Word; MAX_LANES]);
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn rotate_right(self, n: u32) -> Self;
+    fn shr(self, n: u32) -> Self;
+
+    #[inline(always)]
+    fn gamma0(self) -> Self {
+        let [a, b, c] = Self::Word::SMALL_SIGMA[0];
+        self.rotate_right(a) ^ self.rotate_right(b) ^ self.shr(c)
+    }
+
+    #[inline(always)]
+    fn gamma1(self) -> Self {
+        let [a, b, c] = Self::Word::SMALL_SIGMA[1];
+        self.rotate_right(a) ^ self.rotate_right(b) ^ self.shr(c)
+    }
+
+    #[inline(always)]
+    fn sigma0(self) -> Self {
+        let [a, b, c] = Self::Word::BIG_SIGMA[0];
+        self.rotate_right(a) ^ self.rotate_right(b) ^ self.rotate_right(c)
+    }
+
+    #[inline(always)]
+    fn sigma1(self) -> Self {
+        let [a, b, c] = Self::Word::BIG_SIGMA[1];
+        self.rotate_right(a) ^ self.rotate_right(b) ^ self.rotate_right(c)
+    }
+
+    #[inline(always)]
+    fn ch(x: Self, y: Self, z: Self) -> Self {
+        ((y ^ z) & x) ^ z
+    }
+
+    #[inline(always)]
+    fn maj(x: Self, y: Self, z: Self) -> Self {
+        (x & y) | ((x | y) & z)
+    }
+}
+
+/// `N` lanes of plain words
+#[derive(Clone, Copy)]
+struct Portable<W, const N: usize>([W; N]);
+
+impl<W: Sha2Word, const N: usize> Lanes for Portable<W, N> {
+    type Word = W;
+    const COUNT: usize = N;
+
+    #[inline(always)]
+    fn splat(word: W) -> Self {
+        Self([word; N])
+    }
+
+    #[inline(always)]
+    fn load(words: &[W; MAX_LANES]) -> Self {
+        Self(core::array::from_fn(|lane| words[lane]))
+    }
+
+    #[inline(always)]
+    fn store(self, words: &mut [W; MAX_LANES]) {
+        words[..N].copy_from_slice(&self.0);
+    }
+
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|lane| self.0[lane].wrapping_add(rhs.0[lane])))
+    }
+
+    #[inline(always)]
+    fn rotate_right(self, n: u32) -> Self {
+        Self(self.0.map(|word| word.rotate_right(n)))
+    }
+
+    #[inline(always)]
+    fn shr(self, n: u32) -> Self {
+        Self(self.0.map(|word| word >> n))
+    }
+}
+
+macro_rules! impl_portable_bit_op {
+    ($op:ident, $fn:ident) => {
+        impl<W: Sha2Word, const N: usize> $op for Portable<W, N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self {
+                Self(core::array::from_fn(|lane| self.0[lane].$fn(rhs.0[lane])))
+            }
+        }
+    };
+}
+
+impl_portable_bit_op!(BitAnd, bitand);
+impl_portable_bit_op!(BitOr, bitor);
+impl_portable_bit_op!(BitXor, bitxor);
+
+/// Hashes `messages` by groups of `L::COUNT`, interleaving the rounds of every message of a group.
+///
+/// Messages of a group may span different counts of blocks: once a lane has gone through all of its blocks, it is
+/// still fed with empty ones to keep the lanes in step, but their outcome is masked out of its state.
+#[inline(always)]
+fn lanes<L: Lanes>(initial: &[L::Word; 8], messages: &[&[u8]], states: &mut [[L::Word; 8]]) {
+    for (messages, states) in messages.chunks(L::COUNT).zip(states.chunks_mut(L::COUNT)) {
+        let mut lanes_state = initial.map(L::splat);
+        let mut blocks_count = [0; MAX_LANES];
+        for (count, message) in blocks_count.iter_mut().zip(messages.iter()) {
+            *count = padded_len::<L::Word>(message);
+        }
+        let mut block = [0u8; 128];
+        let block = &mut block[..L::Word::BLOCK_LEN];
+
+        for block_index in 0..blocks_count.iter().copied().max().unwrap_or(0) {
+            let mut words = [[L::Word::default(); MAX_LANES]; 16];
+            for (lane, message) in messages.iter().enumerate() {
+                // Blocks lying within the message are read in place, only the trailing ones are padded
+                let start = block_index * L::Word::BLOCK_LEN;
+                let bytes = match message.get(start..start + L::Word::BLOCK_LEN) {
+                    Some(bytes) => bytes,
+                    None => {
+                        padded_block::<L::Word>(message, block_index, block);
+                        &*block
+                    }
+                };
+                for (i, word) in bytes.chunks_exact(L::Word::BLOCK_LEN / 16).enumerate() {
+                    words[i][lane] = L::Word::from_be_slice(word);
+                }
+            }
+            let active = blocks_count.map(|count| {
+                if block_index < count {
+                    L::Word::ONES
+                } else {
+                    L::Word::default()
+                }
+            });
+            compress(&mut lanes_state, &mut words.map(|words| L::load(&words)), L::load(&active));
+        }
+
+        let lanes_state = lanes_state.map(|lanes| {
+            let mut words = [L::Word::default(); MAX_LANES];
+            lanes.store(&mut words);
+            words
+        });
+        for (lane, state) in states.iter_mut().enumerate() {
+            *state = lanes_state.map(|words| words[lane]);
+        }
+    }
+}
+
+/// Returns the count of blocks `message` spans once padded.
+fn padded_len<W: Sha2Word>(message: &[u8]) -> usize {
+    // The message is followed by a 0x80 byte and by its bit length, on two words
+    (message.len() + 1 + 2 * core::mem::size_of::<W>()).div_ceil(W::BLOCK_LEN)
+}
+
+/// Copies the block at `block_index` of the padded `message` into `block`.
+fn padded_block<W: Sha2Word>(message: &[u8], block_index: usize, block: &mut [u8]) {
+    block.fill(0);
+    let start = (block_index * W::BLOCK_LEN).min(message.len());
+    let end = ((block_index + 1) * W::BLOCK_LEN).min(message.len());
+    block[..end - start].copy_from_slice(&message[start..end]);
+
+    if block_index == message.len() / W::BLOCK_LEN {
+        block[message.len() % W::BLOCK_LEN] = 0x80;
+    }
+    if block_index + 1 == padded_len::<W>(message) {
+        let bits_len = (message.len() as u128) << 3;
+        let len_bytes = 2 * core::mem::size_of::<W>();
+        block[W::BLOCK_LEN - len_bytes..].copy_from_slice(&bits_len.to_be_bytes()[16 - len_bytes..]);
+    }
+}
+
+/// Runs every round over all lanes at once, then adds the outcome of the active lanes to their state.
+///
+/// The rounds are those `Sha256Rotor` and `Sha512Rotor` run, and the words are expanded as `next_words` of
+/// `Sha256BitsState` and `Sha512BitsState` does.
+#[inline(always)]
+fn compress<L: Lanes>(state: &mut [L; 8], w: &mut [L; 16], active: L) {
+    let mut v = *state;
+
+    // Rounds go by 16, so that the message schedule is indexed with constants once unrolled
+    for (chunk, k) in L::Word::K.chunks_exact(16).enumerate() {
+        if chunk > 0 {
+            for i in 0..16 {
+                w[i] = w[i]
+                    .wrapping_add(w[(i + 1) % 16].gamma0())
+                    .wrapping_add(w[(i + 9) % 16])
+                    .wrapping_add(w[(i + 14) % 16].gamma1());
+            }
+        }
+
+        for i in 0..16 {
+            let [a, b, c, d, e, f, g, h] = v;
+            let t0 =
+                e.sigma1().wrapping_add(L::ch(e, f, g)).wrapping_add(h).wrapping_add(w[i]).wrapping_add(L::splat(k[i]));
+            v = [t0.wrapping_add(a.sigma0()).wrapping_add(L::maj(a, b, c)), a, b, c, d.wrapping_add(t0), e, f, g];
+        }
+    }
+
+    for (state, v) in state.iter_mut().zip(v) {
+        *state = state.wrapping_add(v & active);
+    }
+}
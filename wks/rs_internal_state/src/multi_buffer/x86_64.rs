@@ -0,0 +1,137 @@
+use super::{lanes, Lanes, MAX_LANES};
+use core::arch::x86_64::*;
+use core::ops::{BitAnd, BitOr, BitXor};
+
+/// Hashes `messages` by groups of `L::COUNT`, with the rounds compiled for SSE4.1.
+///
+/// ## Safety
+///
+/// The CPU must have SSE4.1.
+#[target_feature(enable = "sse4.1")]
+pub(super) unsafe fn sse41<L: Lanes>(initial: &[L::Word; 8], messages: &[&[u8]], states: &mut [[L::Word; 8]]) {
+    lanes::<L>(initial, messages, states)
+}
+
+/// Hashes `messages` by groups of `L::COUNT`, with the rounds compiled for AVX2.
+///
+/// ## Safety
+///
+/// The CPU must have AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn avx2<L: Lanes>(initial: &[L::Word; 8], messages: &[&[u8]], states: &mut [[L::Word; 8]]) {
+    lanes::<L>(initial, messages, states)
+}
+
+// Lanes are only ever made within `sse41` and `avx2`, once the CPU is known to have the extension their intrinsics
+// belong to, and are inlined into them.
+macro_rules! impl_lanes {
+    (
+        $(#[$meta:meta])*
+        $lanes:ident($register:ty) of $count:literal $word:ty as $signed:ty,
+        $set1:ident, $load:ident, $store:ident, $add:ident, $srl:ident, $sll:ident, $and:ident, $or:ident, $xor:ident
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy)]
+        pub(super) struct $lanes($register);
+
+        impl Lanes for $lanes {
+            type Word = $word;
+            const COUNT: usize = $count;
+
+            #[inline(always)]
+            fn splat(word: $word) -> Self {
+                // SAFETY: see above
+                Self(unsafe { $set1(word as $signed) })
+            }
+
+            #[inline(always)]
+            fn load(words: &[$word; MAX_LANES]) -> Self {
+                // SAFETY: see above, and `words` spans at least one register
+                Self(unsafe { $load(words.as_ptr().cast()) })
+            }
+
+            #[inline(always)]
+            fn store(self, words: &mut [$word; MAX_LANES]) {
+                // SAFETY: see above, and `words` spans at least one register
+                unsafe { $store(words.as_mut_ptr().cast(), self.0) }
+            }
+
+            #[inline(always)]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                // SAFETY: see above
+                Self(unsafe { $add(self.0, rhs.0) })
+            }
+
+            #[inline(always)]
+            fn rotate_right(self, n: u32) -> Self {
+                // SAFETY: see above
+                unsafe {
+                    let right = _mm_cvtsi32_si128(n as i32);
+                    let left = _mm_cvtsi32_si128((<$word>::BITS - n) as i32);
+                    Self($or($srl(self.0, right), $sll(self.0, left)))
+                }
+            }
+
+            #[inline(always)]
+            fn shr(self, n: u32) -> Self {
+                // SAFETY: see above
+                Self(unsafe { $srl(self.0, _mm_cvtsi32_si128(n as i32)) })
+            }
+        }
+
+        impl BitAnd for $lanes {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitand(self, rhs: Self) -> Self {
+                // SAFETY: see above
+                Self(unsafe { $and(self.0, rhs.0) })
+            }
+        }
+
+        impl BitOr for $lanes {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitor(self, rhs: Self) -> Self {
+                // SAFETY: see above
+                Self(unsafe { $or(self.0, rhs.0) })
+            }
+        }
+
+        impl BitXor for $lanes {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitxor(self, rhs: Self) -> Self {
+                // SAFETY: see above
+                Self(unsafe { $xor(self.0, rhs.0) })
+            }
+        }
+    };
+}
+
+impl_lanes!(
+    /// Four 32-bit lanes of an SSE register
+    Sse41U32(__m128i) of 4 u32 as i32,
+    _mm_set1_epi32, _mm_loadu_si128, _mm_storeu_si128, _mm_add_epi32, _mm_srl_epi32, _mm_sll_epi32,
+    _mm_and_si128, _mm_or_si128, _mm_xor_si128
+);
+impl_lanes!(
+    /// Two 64-bit lanes of an SSE register
+    Sse41U64(__m128i) of 2 u64 as i64,
+    _mm_set1_epi64x, _mm_loadu_si128, _mm_storeu_si128, _mm_add_epi64, _mm_srl_epi64, _mm_sll_epi64,
+    _mm_and_si128, _mm_or_si128, _mm_xor_si128
+);
+impl_lanes!(
+    /// Eight 32-bit lanes of an AVX2 register
+    Avx2U32(__m256i) of 8 u32 as i32,
+    _mm256_set1_epi32, _mm256_loadu_si256, _mm256_storeu_si256, _mm256_add_epi32, _mm256_srl_epi32,
+    _mm256_sll_epi32, _mm256_and_si256, _mm256_or_si256, _mm256_xor_si256
+);
+impl_lanes!(
+    /// Four 64-bit lanes of an AVX2 register
+    Avx2U64(__m256i) of 4 u64 as i64,
+    _mm256_set1_epi64x, _mm256_loadu_si256, _mm256_storeu_si256, _mm256_add_epi64, _mm256_srl_epi64,
+    _mm256_sll_epi64, _mm256_and_si256, _mm256_or_si256, _mm256_xor_si256
+);
@@ -221,4 +221,88 @@ impl Sha512BitsState {
     pub const K77: u64 = 0x597F299CFC657E2A;
     pub const K78: u64 = 0x5FCB6FAB3AD6FAEC;
     pub const K79: u64 = 0x6C44198C4A475817;
+
+    /// Round constants, in the order the rounds use them
+    pub(crate) const K: [u64; 80] = [
+        Self::K00,
+        Self::K01,
+        Self::K02,
+        Self::K03,
+        Self::K04,
+        Self::K05,
+        Self::K06,
+        Self::K07,
+        Self::K08,
+        Self::K09,
+        Self::K10,
+        Self::K11,
+        Self::K12,
+        Self::K13,
+        Self::K14,
+        Self::K15,
+        Self::K16,
+        Self::K17,
+        Self::K18,
+        Self::K19,
+        Self::K20,
+        Self::K21,
+        Self::K22,
+        Self::K23,
+        Self::K24,
+        Self::K25,
+        Self::K26,
+        Self::K27,
+        Self::K28,
+        Self::K29,
+        Self::K30,
+        Self::K31,
+        Self::K32,
+        Self::K33,
+        Self::K34,
+        Self::K35,
+        Self::K36,
+        Self::K37,
+        Self::K38,
+        Self::K39,
+        Self::K40,
+        Self::K41,
+        Self::K42,
+        Self::K43,
+        Self::K44,
+        Self::K45,
+        Self::K46,
+        Self::K47,
+        Self::K48,
+        Self::K49,
+        Self::K50,
+        Self::K51,
+        Self::K52,
+        Self::K53,
+        Self::K54,
+        Self::K55,
+        Self::K56,
+        Self::K57,
+        Self::K58,
+        Self::K59,
+        Self::K60,
+        Self::K61,
+        Self::K62,
+        Self::K63,
+        Self::K64,
+        Self::K65,
+        Self::K66,
+        Self::K67,
+        Self::K68,
+        Self::K69,
+        Self::K70,
+        Self::K71,
+        Self::K72,
+        Self::K73,
+        Self::K74,
+        Self::K75,
+        Self::K76,
+        Self::K77,
+        Self::K78,
+        Self::K79,
+    ];
 }
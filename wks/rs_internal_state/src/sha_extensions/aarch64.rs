@@ -0,0 +1,70 @@
+use crate::{Sha160Rotor, Sha256BitsState};
+use core::arch::aarch64::*;
+
+/// Loads the 16 big-endian words of `block`, four to a register.
+#[target_feature(enable = "neon")]
+unsafe fn load_words(block: &[u8; 64]) -> [uint32x4_t; 4] {
+    core::array::from_fn(|i| vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[16 * i..].as_ptr()))))
+}
+
+/// Compresses `block` into `state` with SHA1C, SHA1P, SHA1M, SHA1H, SHA1SU0 and SHA1SU1.
+///
+/// ## Safety
+///
+/// The CPU must have the cryptographic extension.
+#[target_feature(enable = "sha2")]
+pub(super) unsafe fn sha1_compress(state: &mut [u32; 5], block: &[u8; 64]) {
+    let ks = [Sha160Rotor::T_00_19, Sha160Rotor::T_20_39, Sha160Rotor::T_40_59, Sha160Rotor::T_60_79];
+    let abcd_save = vld1q_u32(state.as_ptr());
+    let mut w = load_words(block);
+
+    let mut abcd = abcd_save;
+    let mut e = state[4];
+    for i in 0..20 {
+        let wk = vaddq_u32(w[i % 4], vdupq_n_u32(ks[i / 5]));
+        // The E of the next group of four rounds is the A of this one, rotated
+        let next_e = vsha1h_u32(vgetq_lane_u32::<0>(abcd));
+        abcd = match i / 5 {
+            0 => vsha1cq_u32(abcd, e, wk),
+            2 => vsha1mq_u32(abcd, e, wk),
+            _ => vsha1pq_u32(abcd, e, wk),
+        };
+        e = next_e;
+
+        if i < 16 {
+            let w_16 = vsha1su0q_u32(w[i % 4], w[(i + 1) % 4], w[(i + 2) % 4]);
+            w[i % 4] = vsha1su1q_u32(w_16, w[(i + 3) % 4]);
+        }
+    }
+
+    vst1q_u32(state.as_mut_ptr(), vaddq_u32(abcd, abcd_save));
+    state[4] = state[4].wrapping_add(e);
+}
+
+/// Compresses `block` into `state` with SHA256H, SHA256H2, SHA256SU0 and SHA256SU1.
+///
+/// ## Safety
+///
+/// The CPU must have the cryptographic extension.
+#[target_feature(enable = "sha2")]
+pub(super) unsafe fn sha256_compress(state: &mut [u32; 8], block: &[u8; 64]) {
+    let abcd_save = vld1q_u32(state.as_ptr());
+    let efgh_save = vld1q_u32(state[4..].as_ptr());
+    let mut w = load_words(block);
+
+    let (mut abcd, mut efgh) = (abcd_save, efgh_save);
+    for (i, k) in Sha256BitsState::K.chunks_exact(4).enumerate() {
+        let wk = vaddq_u32(w[i % 4], vld1q_u32(k.as_ptr()));
+        let previous_abcd = abcd;
+        abcd = vsha256hq_u32(abcd, efgh, wk);
+        efgh = vsha256h2q_u32(efgh, previous_abcd, wk);
+
+        if i < 12 {
+            let w_16 = vsha256su0q_u32(w[i % 4], w[(i + 1) % 4]);
+            w[i % 4] = vsha256su1q_u32(w_16, w[(i + 2) % 4], w[(i + 3) % 4]);
+        }
+    }
+
+    vst1q_u32(state.as_mut_ptr(), vaddq_u32(abcd, abcd_save));
+    vst1q_u32(state[4..].as_mut_ptr(), vaddq_u32(efgh, efgh_save));
+}
@@ -0,0 +1,103 @@
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+const UNKNOWN: u8 = 0;
+const ABSENT: u8 = 1;
+const PRESENT: u8 = 2;
+
+/// Outcome of the CPU feature detection, run on first use only
+static DETECTED: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+/// `ShaExtensions` runs the SHA-1 and SHA-256 compression functions on the dedicated instructions of the CPU: the SHA
+/// extensions on x86_64, and the cryptographic extension on AArch64.
+///
+/// An instance is only handed out by `ShaExtensions::detect` when the running CPU has these instructions, which makes
+/// its compression functions safe to call. They go through the same rounds as `Sha160BitsState` and
+/// `Sha256BitsState`, which remain the fallback everywhere else.
+///
+/// On x86_64, the instructions are detected through CPUID, with or without `std`. On AArch64, they are detected at
+/// runtime with the `std` feature, and otherwise picked up only if the target was compiled with them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ShaExtensions(());
+
+impl ShaExtensions {
+    /// Returns a `ShaExtensions` if the running CPU has SHA-1 and SHA-256 instructions.
+    pub fn detect() -> Option<Self> {
+        let detected = match DETECTED.load(Ordering::Relaxed) {
+            UNKNOWN => {
+                let detected = if cpu_has_sha_extensions() {
+                    PRESENT
+                } else {
+                    ABSENT
+                };
+                DETECTED.store(detected, Ordering::Relaxed);
+                detected
+            }
+            detected => detected,
+        };
+
+        (detected == PRESENT).then_some(Self(()))
+    }
+
+    /// Compresses `block` into the SHA-1 `state`.
+    pub fn sha1_compress(&self, state: &mut [u32; 5], block: &[u8; 64]) {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: `self` is only built once the CPU is known to have the SHA extensions, SSSE3 and SSE4.1
+        unsafe {
+            x86_64::sha1_compress(state, block)
+        }
+        #[cfg(target_arch = "aarch64")]
+        // SAFETY: `self` is only built once the CPU is known to have the cryptographic extension
+        unsafe {
+            aarch64::sha1_compress(state, block)
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            let _ = (state, block);
+            unreachable!("ShaExtensions is never detected on this architecture")
+        }
+    }
+
+    /// Compresses `block` into the SHA-224 or SHA-256 `state`.
+    pub fn sha256_compress(&self, state: &mut [u32; 8], block: &[u8; 64]) {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: `self` is only built once the CPU is known to have the SHA extensions, SSSE3 and SSE4.1
+        unsafe {
+            x86_64::sha256_compress(state, block)
+        }
+        #[cfg(target_arch = "aarch64")]
+        // SAFETY: `self` is only built once the CPU is known to have the cryptographic extension
+        unsafe {
+            aarch64::sha256_compress(state, block)
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            let _ = (state, block);
+            unreachable!("ShaExtensions is never detected on this architecture")
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn cpu_has_sha_extensions() -> bool {
+    x86_64::cpu_has_sha_extensions()
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "std"))]
+fn cpu_has_sha_extensions() -> bool {
+    std::arch::is_aarch64_feature_detected!("sha2")
+}
+
+#[cfg(all(target_arch = "aarch64", not(feature = "std")))]
+fn cpu_has_sha_extensions() -> bool {
+    cfg!(target_feature = "sha2")
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn cpu_has_sha_extensions() -> bool {
+    false
+}
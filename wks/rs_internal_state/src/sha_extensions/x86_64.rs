@@ -0,0 +1,107 @@
+use crate::Sha256BitsState;
+use core::arch::x86_64::*;
+
+pub(super) fn cpu_has_sha_extensions() -> bool {
+    let leaf_1 = __cpuid(1);
+    if __get_cpuid_max(0).0 < 7 {
+        return false;
+    }
+    let leaf_7 = __cpuid_count(7, 0);
+
+    let has_ssse3 = leaf_1.ecx & (1 << 9) != 0;
+    let has_sse41 = leaf_1.ecx & (1 << 19) != 0;
+    let has_sha = leaf_7.ebx & (1 << 29) != 0;
+    has_ssse3 && has_sse41 && has_sha
+}
+
+/// Loads the 16 big-endian words of `block`, four to a register.
+#[target_feature(enable = "ssse3")]
+unsafe fn load_words(block: &[u8; 64], byte_order: __m128i) -> [__m128i; 4] {
+    core::array::from_fn(|i| _mm_shuffle_epi8(_mm_loadu_si128(block[16 * i..].as_ptr().cast()), byte_order))
+}
+
+/// Compresses `block` into `state` with SHA1RNDS4, SHA1NEXTE, SHA1MSG1 and SHA1MSG2.
+///
+/// ## Safety
+///
+/// The CPU must have the SHA extensions, SSSE3 and SSE4.1.
+#[target_feature(enable = "sha,ssse3,sse4.1")]
+pub(super) unsafe fn sha1_compress(state: &mut [u32; 5], block: &[u8; 64]) {
+    // Words are reversed, so that the first one lies in the highest lane, where the instructions expect it
+    let byte_order = _mm_set_epi64x(0x0001_0203_0405_0607, 0x0809_0A0B_0C0D_0E0F);
+    let abcd_save = _mm_shuffle_epi32(_mm_loadu_si128(state.as_ptr().cast()), 0x1B);
+    let e_save = _mm_set_epi32(state[4] as i32, 0, 0, 0);
+    let mut w = load_words(block, byte_order);
+
+    let mut abcd = abcd_save;
+    let mut e = _mm_add_epi32(e_save, w[0]);
+    sha1_rounds::<0>(&mut abcd, &mut e, &mut w, 0, e_save);
+    sha1_rounds::<1>(&mut abcd, &mut e, &mut w, 5, e_save);
+    sha1_rounds::<2>(&mut abcd, &mut e, &mut w, 10, e_save);
+    sha1_rounds::<3>(&mut abcd, &mut e, &mut w, 15, e_save);
+
+    abcd = _mm_shuffle_epi32(_mm_add_epi32(abcd, abcd_save), 0x1B);
+    _mm_storeu_si128(state.as_mut_ptr().cast(), abcd);
+    state[4] = _mm_extract_epi32::<3>(e) as u32;
+}
+
+/// Runs the five groups of four rounds from `first_group` on, which share the boolean function `FUNCTION`.
+#[inline]
+#[target_feature(enable = "sha,sse2")]
+unsafe fn sha1_rounds<const FUNCTION: i32>(
+    abcd: &mut __m128i,
+    e: &mut __m128i,
+    w: &mut [__m128i; 4],
+    first_group: usize,
+    e_save: __m128i,
+) {
+    for i in first_group..first_group + 5 {
+        let previous_abcd = *abcd;
+        *abcd = _mm_sha1rnds4_epu32::<FUNCTION>(*abcd, *e);
+
+        if i < 16 {
+            let w_16 = _mm_xor_si128(_mm_sha1msg1_epu32(w[i % 4], w[(i + 1) % 4]), w[(i + 2) % 4]);
+            w[i % 4] = _mm_sha1msg2_epu32(w_16, w[(i + 3) % 4]);
+        }
+
+        // The E of the next group of four rounds is the A of this one, rotated, and it ends up as the final E
+        *e = _mm_sha1nexte_epu32(previous_abcd, if i < 19 { w[(i + 1) % 4] } else { e_save });
+    }
+}
+
+/// Compresses `block` into `state` with SHA256RNDS2, SHA256MSG1 and SHA256MSG2.
+///
+/// ## Safety
+///
+/// The CPU must have the SHA extensions, SSSE3 and SSE4.1.
+#[target_feature(enable = "sha,ssse3,sse4.1")]
+pub(super) unsafe fn sha256_compress(state: &mut [u32; 8], block: &[u8; 64]) {
+    let byte_order = _mm_set_epi64x(0x0C0D_0E0F_0809_0A0B, 0x0405_0607_0001_0203);
+    let dcba = _mm_loadu_si128(state.as_ptr().cast());
+    let hgfe = _mm_loadu_si128(state[4..].as_ptr().cast());
+
+    // SHA256RNDS2 works on the working variables split as ABEF and CDGH
+    let cdab = _mm_shuffle_epi32(dcba, 0xB1);
+    let efgh = _mm_shuffle_epi32(hgfe, 0x1B);
+    let abef_save = _mm_alignr_epi8(cdab, efgh, 8);
+    let cdgh_save = _mm_blend_epi16(efgh, cdab, 0xF0);
+    let mut w = load_words(block, byte_order);
+
+    let (mut abef, mut cdgh) = (abef_save, cdgh_save);
+    for (i, k) in Sha256BitsState::K.chunks_exact(4).enumerate() {
+        let wk = _mm_add_epi32(w[i % 4], _mm_loadu_si128(k.as_ptr().cast()));
+        cdgh = _mm_sha256rnds2_epu32(cdgh, abef, wk);
+        abef = _mm_sha256rnds2_epu32(abef, cdgh, _mm_shuffle_epi32(wk, 0x0E));
+
+        if i < 12 {
+            let w_9 = _mm_alignr_epi8(w[(i + 3) % 4], w[(i + 2) % 4], 4);
+            let w_16 = _mm_add_epi32(_mm_sha256msg1_epu32(w[i % 4], w[(i + 1) % 4]), w_9);
+            w[i % 4] = _mm_sha256msg2_epu32(w_16, w[(i + 3) % 4]);
+        }
+    }
+
+    let feba = _mm_shuffle_epi32(_mm_add_epi32(abef, abef_save), 0x1B);
+    let dchg = _mm_shuffle_epi32(_mm_add_epi32(cdgh, cdgh_save), 0xB1);
+    _mm_storeu_si128(state.as_mut_ptr().cast(), _mm_blend_epi16(feba, dchg, 0xF0));
+    _mm_storeu_si128(state[4..].as_mut_ptr().cast(), _mm_alignr_epi8(dchg, feba, 8));
+}
@@ -0,0 +1,161 @@
+use crate::{ExtendedOutputFunction, KeccakDuplex, KeccakSponge};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
+use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
+
+const MSG: &[u8; 17] = b"duplexing sponges";
+
+/// A single duplexing call on a fresh object must match a sponge absorbing the same padded block.
+fn assert_duplexing_matches_sponge<T, const RATE: usize>()
+where
+    T: BitAnd
+        + BitAndAssign
+        + BitOr<NBitWord<T>, Output = NBitWord<T>>
+        + BitXor<Output = T>
+        + BitXorAssign
+        + Copy
+        + Default
+        + Not<Output = T>,
+    NBitWord<T>: From<u64> + LittleEndianBytes + Rotate + TSize<T>,
+    u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
+{
+    let mut block = [0u8; RATE];
+    block[..MSG.len()].clone_from_slice(MSG);
+    block[MSG.len()] = 0x1F;
+    block[RATE - 1] ^= 0x80;
+
+    let mut sponge = KeccakSponge::<T, RATE, RATE>::default();
+    sponge.absorb(&block);
+
+    let mut duplex = KeccakDuplex::<T, RATE>::default();
+    let mut output = [0u8; RATE];
+    duplex.duplexing(MSG, 0x1F, &mut output);
+
+    assert_eq!(output, sponge.squeeze());
+}
+
+#[test]
+fn assert_duplexing_matches_sponge_on_every_width() {
+    assert_duplexing_matches_sponge::<u8, 18>();
+    assert_duplexing_matches_sponge::<u16, 36>();
+    assert_duplexing_matches_sponge::<u32, 68>();
+    assert_duplexing_matches_sponge::<u64, 136>();
+}
+
+#[test]
+fn assert_duplexing_with_sha3_suffix_is_sha3_256() {
+    let mut duplex = KeccakDuplex::<u64, 136>::default();
+    let mut output = [0u8; 32];
+    duplex.duplexing(b"abc", 0x06, &mut output);
+
+    assert_eq!(
+        output,
+        [
+            0x3A, 0x98, 0x5D, 0xA7, 0x4F, 0xE2, 0x25, 0xB2, 0x04, 0x5C, 0x17, 0x2D, 0x6B, 0xD3, 0x90, 0xBD, 0x85, 0x5F,
+            0x08, 0x6E, 0x3E, 0x9D, 0x52, 0x5B, 0x46, 0xBF, 0xE2, 0x45, 0x11, 0x43, 0x15, 0x32
+        ]
+    );
+}
+
+#[test]
+fn assert_default_rounds_are_keccak_f200() {
+    let mut duplex = KeccakDuplex::<u8, 18>::default();
+    let mut full_rounds_duplex = KeccakDuplex::<u8, 18, 18>::default();
+    let mut output = [0u8; 18];
+    let mut full_rounds_output = [0u8; 18];
+    duplex.duplexing(&[], 0x01, &mut output);
+    full_rounds_duplex.duplexing(&[], 0x01, &mut full_rounds_output);
+
+    assert_eq!(
+        output,
+        [
+            0x8A, 0x1E, 0x0D, 0x38, 0xCA, 0x4E, 0x1E, 0xCF, 0xD6, 0x13, 0x95, 0xEB, 0x23, 0x3F, 0x7C, 0x35, 0xDA, 0xBB
+        ]
+    );
+    assert_eq!(output, full_rounds_output);
+}
+
+#[test]
+fn assert_default_rounds_are_keccak_f400() {
+    let mut duplex = KeccakDuplex::<u16, 36>::default();
+    let mut full_rounds_duplex = KeccakDuplex::<u16, 36, 20>::default();
+    let mut output = [0u8; 36];
+    let mut full_rounds_output = [0u8; 36];
+    duplex.duplexing(&[], 0x01, &mut output);
+    full_rounds_duplex.duplexing(&[], 0x01, &mut full_rounds_output);
+
+    assert_eq!(
+        output,
+        [
+            0x5B, 0xA2, 0x67, 0x54, 0x92, 0x2E, 0xEE, 0x90, 0xC9, 0x5B, 0x09, 0xBE, 0xBA, 0x0D, 0x79, 0x2D, 0x87, 0x6D,
+            0x0F, 0x46, 0xAF, 0xE0, 0xF4, 0x0F, 0x6D, 0x5F, 0x15, 0x2B, 0x48, 0x65, 0x47, 0xE4, 0x08, 0x26, 0x21, 0x55
+        ]
+    );
+    assert_eq!(output, full_rounds_output);
+}
+
+#[test]
+fn assert_default_rounds_are_keccak_f800() {
+    let mut duplex = KeccakDuplex::<u32, 68>::default();
+    let mut full_rounds_duplex = KeccakDuplex::<u32, 68, 22>::default();
+    let mut output = [0u8; 68];
+    let mut full_rounds_output = [0u8; 68];
+    duplex.duplexing(&[], 0x01, &mut output);
+    full_rounds_duplex.duplexing(&[], 0x01, &mut full_rounds_output);
+
+    assert_eq!(
+        output,
+        [
+            0xA3, 0xCE, 0xA5, 0x5C, 0xFD, 0x9F, 0x44, 0x32, 0xAD, 0x3F, 0x9A, 0xE3, 0x36, 0x73, 0xAE, 0x12, 0x66, 0x5F,
+            0x66, 0xD1, 0x50, 0xA1, 0x1A, 0xF5, 0x4E, 0x00, 0x7C, 0x7F, 0x26, 0xF7, 0xC9, 0xA6, 0xE6, 0x98, 0x62, 0xE1,
+            0x4A, 0x2B, 0xAD, 0x40, 0x04, 0x8D, 0x43, 0x9E, 0x26, 0xFB, 0x67, 0xB4, 0x08, 0x07, 0x41, 0x2B, 0xAE, 0x2E,
+            0xB4, 0x2B, 0x68, 0x96, 0xB1, 0xD4, 0xD6, 0x02, 0x75, 0x5B, 0x23, 0xEC, 0x19, 0xE4
+        ]
+    );
+    assert_eq!(output, full_rounds_output);
+}
+
+#[test]
+#[should_panic]
+fn assert_duplexing_rejects_more_rounds_than_keccak_f() {
+    KeccakDuplex::<u16, 36, 21>::default().duplexing(&[], 0x01, &mut []);
+}
+
+#[test]
+fn assert_outputs_depend_on_every_previous_input() {
+    let mut duplex1 = KeccakDuplex::<u32, 68>::default();
+    let mut duplex2 = KeccakDuplex::<u32, 68>::default();
+    let mut output1 = [0u8; 68];
+    let mut output2 = [0u8; 68];
+
+    duplex1.duplexing(b"first", 0x01, &mut []);
+    duplex2.duplexing(b"First", 0x01, &mut []);
+    duplex1.duplexing(b"second", 0x01, &mut output1);
+    duplex2.duplexing(b"second", 0x01, &mut output2);
+
+    assert_ne!(output1, output2);
+}
+
+#[test]
+fn assert_frame_bits_domain_separate_equal_inputs() {
+    let mut duplex1 = KeccakDuplex::<u16, 36>::default();
+    let mut duplex2 = KeccakDuplex::<u16, 36>::default();
+    let mut output1 = [0u8; 16];
+    let mut output2 = [0u8; 16];
+
+    duplex1.duplexing(MSG, 0x02, &mut output1);
+    duplex2.duplexing(MSG, 0x03, &mut output2);
+
+    assert_ne!(output1, output2);
+}
+
+#[test]
+#[should_panic]
+fn assert_duplexing_rejects_inputs_without_room_for_padding() {
+    KeccakDuplex::<u8, 18>::default().duplexing(&[0u8; 18], 0x01, &mut []);
+}
+
+#[test]
+#[should_panic]
+fn assert_duplexing_rejects_empty_delimited_suffix() {
+    KeccakDuplex::<u8, 18>::default().duplexing(&[], 0x00, &mut []);
+}
@@ -1,7 +1,11 @@
 use crate::{
+    keccak::state::KeccakStateIter,
     keccak::{chi::Chi, iota::Iota, pi::Pi, rho::Rho, theta::Theta, HEIGHT, WIDTH},
     KeccakState,
 };
+use alloc::vec::Vec;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitXor, BitXorAssign, Not, Sub};
+use rs_n_bit_words::{LittleEndianBytes, NBitWord, Rotate, TSize};
 
 // Test constants, based on examples from the Keccak reference
 const INITIAL_STATE: [[u64; WIDTH]; HEIGHT] = [
@@ -118,3 +122,111 @@ fn assert_iota_two_cycles_correctness() {
 
     assert_eq!(state, KeccakState::from(AFTER_IOTA_STATE_ON_SECOND_CYCLE));
 }
+
+#[test]
+fn assert_full_round_keccak_p_is_keccak_f() {
+    let mut keccak_f_state = KeccakState::from(INITIAL_STATE);
+    let mut keccak_p_state = KeccakState::from(INITIAL_STATE);
+
+    keccak_f_state.apply_f();
+    keccak_p_state.apply_p(24);
+
+    assert_eq!(keccak_f_state, keccak_p_state);
+}
+
+#[test]
+fn assert_round_reduced_keccak_p_runs_the_last_rounds() {
+    let mut expected_state = KeccakState::from(INITIAL_STATE);
+    let mut keccak_p_state = KeccakState::from(INITIAL_STATE);
+
+    for i in 12..24 {
+        expected_state.theta();
+        expected_state.rho();
+        expected_state.pi();
+        expected_state.chi();
+        expected_state.iota(i);
+    }
+    keccak_p_state.apply_p(12);
+
+    assert_eq!(expected_state, keccak_p_state);
+}
+
+#[test]
+fn assert_zero_round_keccak_p_is_the_identity() {
+    let mut state = KeccakState::from(INITIAL_STATE);
+    state.apply_p(0);
+
+    assert_eq!(state, KeccakState::from(INITIAL_STATE));
+}
+
+#[test]
+#[should_panic]
+fn assert_keccak_p_rejects_more_rounds_than_keccak_f() {
+    KeccakState::from(INITIAL_STATE).apply_p(25);
+}
+
+#[test]
+fn assert_keccak_f_rounds_depend_on_the_lane_width() {
+    assert_eq!(KeccakState::<u8>::F_ROUNDS, 18);
+    assert_eq!(KeccakState::<u16>::F_ROUNDS, 20);
+    assert_eq!(KeccakState::<u32>::F_ROUNDS, 22);
+    assert_eq!(KeccakState::<u64>::F_ROUNDS, 24);
+}
+
+#[test]
+#[should_panic]
+fn assert_narrow_keccak_p_rejects_more_rounds_than_its_keccak_f() {
+    KeccakState::<u8>::default().apply_p(19);
+}
+
+/// Applies Keccak-f to the all-zero state, returning the lanes in little-endian order.
+fn keccak_f_of_zero_state<T>() -> Vec<u8>
+where
+    T: BitAnd + BitAndAssign + BitOr<NBitWord<T>, Output = NBitWord<T>> + BitXor + BitXorAssign + Copy + Default + Not,
+    NBitWord<T>: From<u64> + LittleEndianBytes + Not<Output = NBitWord<T>> + Rotate + TSize<T>,
+    u32: Sub<NBitWord<T>, Output = NBitWord<T>>,
+{
+    let mut state = KeccakState::<T>::default();
+    state.apply_f();
+
+    KeccakStateIter::new(&state).flat_map(|lane| lane.to_le_bytes().as_ref().to_vec()).collect()
+}
+
+// Expected states of the narrower Keccak-f permutations, with their 12 + 2l rounds as specified in FIPS 202, section 3
+#[test]
+fn assert_keccak_f200_correctness() {
+    assert_eq!(
+        keccak_f_of_zero_state::<u8>(),
+        [
+            0x3C, 0x28, 0x26, 0x84, 0x1C, 0xB3, 0x5C, 0x17, 0x1E, 0xAA, 0xE9, 0xB8, 0x11, 0x13, 0x4C, 0xEA, 0xA3, 0x85,
+            0x2C, 0x69, 0xD2, 0xC5, 0xAB, 0xAF, 0xEA
+        ]
+    );
+}
+
+#[test]
+fn assert_keccak_f400_correctness() {
+    assert_eq!(
+        keccak_f_of_zero_state::<u16>(),
+        [
+            0xF5, 0x09, 0xAC, 0x40, 0xA9, 0x0F, 0xF5, 0x14, 0x9F, 0xE8, 0xA0, 0xEC, 0xD1, 0x5B, 0x70, 0x78, 0xF0, 0xEF,
+            0x8F, 0xBF, 0x37, 0x03, 0x52, 0x60, 0x75, 0xDC, 0xC9, 0x0E, 0x76, 0xE7, 0x46, 0x52, 0xA1, 0x59, 0x81, 0x5D,
+            0x95, 0x6D, 0x14, 0x6E, 0x3E, 0x63, 0xEE, 0x58, 0xFF, 0x71, 0x4C, 0x71, 0x8E, 0xB3
+        ]
+    );
+}
+
+#[test]
+fn assert_keccak_f800_correctness() {
+    assert_eq!(
+        keccak_f_of_zero_state::<u32>(),
+        [
+            0x5D, 0xD4, 0x31, 0xE5, 0xFB, 0xC6, 0x04, 0xF4, 0x99, 0xBF, 0xA0, 0x23, 0x2F, 0x45, 0xF8, 0xF1, 0x42, 0xD0,
+            0xFF, 0x51, 0x78, 0xF5, 0x39, 0xE5, 0xA7, 0x80, 0x0B, 0xF0, 0x64, 0x36, 0x97, 0xAF, 0x4C, 0xF3, 0x5A, 0xBF,
+            0x24, 0x24, 0x7A, 0x22, 0x15, 0x27, 0x17, 0x88, 0x84, 0x58, 0x68, 0x9F, 0x54, 0xD0, 0x5C, 0xB1, 0x0E, 0xFC,
+            0xF4, 0x1B, 0x91, 0xFA, 0x66, 0x61, 0x9A, 0x59, 0x9E, 0x1A, 0x1F, 0x0A, 0x97, 0xA3, 0x87, 0x96, 0x65, 0xAB,
+            0x68, 0x8D, 0xAB, 0xAF, 0x15, 0x10, 0x4B, 0xE7, 0x98, 0x1A, 0x00, 0x34, 0xF3, 0xEF, 0x19, 0x41, 0x76, 0x0E,
+            0x0A, 0x93, 0x70, 0x80, 0xB2, 0x87, 0x96, 0xE9, 0xEF, 0x11
+        ]
+    );
+}
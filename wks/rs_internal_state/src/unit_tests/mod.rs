@@ -1 +1,4 @@
+mod keccak_duplex;
 mod keccak_state;
+mod sha_extensions;
+mod zeroize;
@@ -0,0 +1,88 @@
+use crate::{DWords, GenericStateHasher, Sha160BitsState, Sha256BitsState, ShaExtensions};
+use rs_n_bit_words::NBitWord;
+
+fn scalar_sha1_compress(state: &mut [u32; 5], block: &[u8; 64]) {
+    let [a, b, c, d, e] = state.map(NBitWord::from);
+    let mut bits_state = Sha160BitsState(a, b, c, d, e, DWords::<u32>::from(block));
+    bits_state.block_00_15();
+    bits_state.block_16_31();
+    bits_state.block_32_47();
+    bits_state.block_48_63();
+    bits_state.block_64_79();
+
+    let words = [bits_state.0, bits_state.1, bits_state.2, bits_state.3, bits_state.4];
+    for (word, bits_word) in state.iter_mut().zip(words) {
+        *word = word.wrapping_add(bits_word.into());
+    }
+}
+
+fn scalar_sha256_compress(state: &mut [u32; 8], block: &[u8; 64]) {
+    let [a, b, c, d, e, f, g, h] = state.map(NBitWord::from);
+    let mut bits_state = Sha256BitsState(a, b, c, d, e, f, g, h, DWords::<u32>::from(block));
+    bits_state.block_00_15();
+    bits_state.block_16_31();
+    bits_state.block_32_47();
+    bits_state.block_48_63();
+
+    let words = [
+        bits_state.0,
+        bits_state.1,
+        bits_state.2,
+        bits_state.3,
+        bits_state.4,
+        bits_state.5,
+        bits_state.6,
+        bits_state.7,
+    ];
+    for (word, bits_word) in state.iter_mut().zip(words) {
+        *word = word.wrapping_add(bits_word.into());
+    }
+}
+
+/// Xorshift blocks, so that every bit of the state and of the message schedule gets exercised
+fn blocks() -> impl Iterator<Item = [u8; 64]> {
+    let mut seed = 0x0123_4567_89AB_CDEFu64;
+    (0..1000).map(move |i| {
+        core::array::from_fn(|j| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            match i {
+                0 => 0x00,
+                1 => 0xFF,
+                _ => (seed >> (j % 8)) as u8,
+            }
+        })
+    })
+}
+
+#[test]
+fn assert_sha_extensions_match_the_scalar_sha1_rounds() {
+    let Some(sha_extensions) = ShaExtensions::detect() else {
+        return;
+    };
+
+    let mut accelerated_state = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+    let mut scalar_state = accelerated_state;
+    for block in blocks() {
+        sha_extensions.sha1_compress(&mut accelerated_state, &block);
+        scalar_sha1_compress(&mut scalar_state, &block);
+        assert_eq!(accelerated_state, scalar_state);
+    }
+}
+
+#[test]
+fn assert_sha_extensions_match_the_scalar_sha256_rounds() {
+    let Some(sha_extensions) = ShaExtensions::detect() else {
+        return;
+    };
+
+    let mut accelerated_state =
+        [0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19];
+    let mut scalar_state = accelerated_state;
+    for block in blocks() {
+        sha_extensions.sha256_compress(&mut accelerated_state, &block);
+        scalar_sha256_compress(&mut scalar_state, &block);
+        assert_eq!(accelerated_state, scalar_state);
+    }
+}
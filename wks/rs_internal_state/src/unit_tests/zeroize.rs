@@ -0,0 +1,28 @@
+use crate::{KeccakDuplex, Zeroize};
+use rs_n_bit_words::NBitWord;
+
+#[test]
+fn assert_zeroize_wipes_words_and_arrays() {
+    let mut word = NBitWord::<u64>::from(0x0123_4567_89AB_CDEF);
+    word.zeroize();
+    assert_eq!(word, NBitWord::default());
+
+    let mut bytes = [0xA5u8; 200];
+    bytes[..100].zeroize();
+    assert_eq!(bytes[..100], [0u8; 100]);
+    assert_eq!(bytes[100..], [0xA5u8; 100]);
+
+    let mut words = [u128::MAX; 4];
+    words.zeroize();
+    assert_eq!(words, [0u128; 4]);
+}
+
+#[test]
+fn assert_zeroize_resets_keccak_duplex() {
+    let mut duplex = KeccakDuplex::<u64, 168>::default();
+    duplex.duplexing(b"secret key", 0x1F, &mut [0u8; 32]);
+    assert_ne!(duplex, KeccakDuplex::default());
+
+    duplex.zeroize();
+    assert_eq!(duplex, KeccakDuplex::default());
+}
@@ -0,0 +1,61 @@
+use core::sync::atomic::{compiler_fence, Ordering};
+use rs_n_bit_words::NBitWord;
+
+/// `Zeroize` wipes secret material out of memory.
+///
+/// The writes are volatile and followed by a compiler fence, so that they are not elided, even when the value is
+/// about to be dropped, nor reordered after later operations. Key-bearing types, such as the HMAC contexts, the key
+/// schedules of ciphers and the intermediate values of key derivation functions, implement it and call it on drop.
+///
+/// ## Examples
+///
+///```rust
+/// # use rs_internal_state::Zeroize;
+/// let mut key = [0x2Au8; 32];
+/// key.zeroize();
+/// assert_eq!(key, [0u8; 32]);
+///```
+pub trait Zeroize {
+    /// Overwrites the secret material with zeros.
+    fn zeroize(&mut self);
+}
+
+/// Overwrites `target` with its default value through a volatile write.
+fn volatile_reset<T: Copy + Default>(target: &mut T) {
+    // SAFETY: `target` is a valid, aligned and exclusive reference, and a `Copy` value has no destructor to skip.
+    unsafe { core::ptr::write_volatile(target, T::default()) };
+}
+
+macro_rules! impl_zeroize_for_copy_types {
+    ($($t:ty),*) => {
+        $(
+            impl Zeroize for $t {
+                fn zeroize(&mut self) {
+                    volatile_reset(self);
+                    compiler_fence(Ordering::SeqCst);
+                }
+            }
+        )*
+    };
+}
+
+impl_zeroize_for_copy_types!(u8, u16, u32, u64, u128, usize);
+
+impl<T: Copy + Default> Zeroize for NBitWord<T> {
+    fn zeroize(&mut self) {
+        volatile_reset(self);
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl<T: Zeroize> Zeroize for [T] {
+    fn zeroize(&mut self) {
+        self.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
+impl<T: Zeroize, const N: usize> Zeroize for [T; N] {
+    fn zeroize(&mut self) {
+        self.as_mut_slice().zeroize();
+    }
+}
@@ -7,8 +7,9 @@ use core::{
     hash::{Hash, Hasher},
     num::Wrapping,
     ops::{Add, AddAssign, BitAnd, BitOr, BitXor, Shl, Shr, Sub},
-    ops::{BitAndAssign, BitXorAssign, Not},
+    ops::{BitAndAssign, BitXorAssign, Neg, Not},
 };
+use rs_constant_time::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 mod little_endian;
 mod rotate;
@@ -170,6 +171,24 @@ where
     }
 }
 
+impl<T> ConditionallySelectable for NBitWord<T>
+where
+    T: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(Wrapping(T::conditional_select(&a.0 .0, &b.0 .0, choice)))
+    }
+}
+
+impl<T> ConstantTimeEq for NBitWord<T>
+where
+    T: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0 .0.ct_eq(&other.0 .0)
+    }
+}
+
 impl<T: Default> Default for NBitWord<T> {
     fn default() -> Self {
         Self(Wrapping(T::default()))
@@ -308,6 +327,28 @@ where
     }
 }
 
+impl<T> Neg for NBitWord<T>
+where
+    Wrapping<T>: Neg<Output = Wrapping<T>>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<T: Copy> Neg for &NBitWord<T>
+where
+    Wrapping<T>: Neg<Output = Wrapping<T>>,
+{
+    type Output = NBitWord<T>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
 impl<T> Not for NBitWord<T>
 where
     T: Not<Output = T>,
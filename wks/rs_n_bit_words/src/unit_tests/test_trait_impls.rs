@@ -65,3 +65,20 @@ fn assert_maj_consistency() {
     let maj2 = U32Word::maj(1000u32.into(), 2001u32.into(), 3002u32.into());
     assert_eq!(maj2, 1016);
 }
+
+#[test]
+fn assert_constant_time_traits_follow_inner_word() {
+    use rs_constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+    let (a, b) = (U32Word::from(0xDEAD_BEEFu32), U32Word::from(0x0BAD_F00Du32));
+    assert!(bool::from(a.ct_eq(&a)));
+    assert!(bool::from(a.ct_ne(&b)));
+    assert_eq!(U32Word::conditional_select(&a, &b, Choice::from(0)), a);
+    assert_eq!(U32Word::conditional_select(&a, &b, Choice::from(1)), b);
+
+    let mut word = U32Word::from(1u32);
+    word.conditional_negate(Choice::from(1));
+    assert_eq!(word, u32::MAX);
+    word.conditional_negate(Choice::from(0));
+    assert_eq!(word, u32::MAX);
+}